@@ -345,6 +345,27 @@ impl Color32 {
         )
     }
 
+    /// Lerp this color towards `other` by `t` in linear space.
+    ///
+    /// This converts to linear space (using the sRGB lookup tables),
+    /// interpolates there, and converts back.
+    /// Linear interpolation avoids the muddy midpoints that gamma-space
+    /// interpolation can produce, which matters for smooth color ramps
+    /// and heatmaps. See also [`Self::lerp_to_gamma`].
+    pub fn lerp_to_linear(&self, other: Self, t: f32) -> Self {
+        use emath::lerp;
+
+        let a = Rgba::from(*self);
+        let b = Rgba::from(other);
+        Rgba::from_rgba_premultiplied(
+            lerp(a.r()..=b.r(), t),
+            lerp(a.g()..=b.g(), t),
+            lerp(a.b()..=b.b(), t),
+            lerp(a.a()..=b.a(), t),
+        )
+        .into()
+    }
+
     /// Blend two colors in gamma space, so that `self` is behind the argument.
     pub fn blend(self, on_top: Self) -> Self {
         self.gamma_multiply_u8(255 - on_top.a()) + on_top
@@ -357,6 +378,32 @@ impl Color32 {
     pub fn intensity(&self) -> f32 {
         (self.r() as f32 * 0.299 + self.g() as f32 * 0.587 + self.b() as f32 * 0.114) / 255.0
     }
+
+    /// Rotate the hue by this many degrees, wrapping around.
+    ///
+    /// This round-trips through [`crate::Hsva`], preserving alpha.
+    /// Gray colors have no hue, so they are unaffected.
+    ///
+    /// Useful for deriving a palette (e.g. hover and active variants)
+    /// from a single accent color.
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        crate::Hsva::from(self).rotate_hue(degrees).into()
+    }
+
+    /// Multiply the saturation by the given factor, clamping the result to the valid range.
+    ///
+    /// This round-trips through [`crate::Hsva`], preserving alpha.
+    /// A factor of `0.0` makes the color gray; `1.0` leaves it unchanged.
+    pub fn scale_saturation(self, factor: f32) -> Self {
+        crate::Hsva::from(self).scale_saturation(factor).into()
+    }
+
+    /// Replace the lightness (the HSV "value") with the given one, in the 0-1 range.
+    ///
+    /// This round-trips through [`crate::Hsva`], preserving hue, saturation and alpha.
+    pub fn with_lightness(self, lightness: f32) -> Self {
+        crate::Hsva::from(self).with_value(lightness).into()
+    }
 }
 
 impl std::ops::Mul for Color32 {
@@ -477,6 +524,38 @@ mod test {
         }
     }
 
+    #[test]
+    fn hue_rotation_and_saturation() {
+        let accent = Color32::from_rgba_unmultiplied(10, 100, 200, 150);
+
+        // A full rotation should round-trip (up to rounding errors):
+        let rotated = accent.rotate_hue(360.0);
+        for (&a, &b) in accent.to_array().iter().zip(rotated.to_array().iter()) {
+            assert!(a.abs_diff(b) <= 3, "{accent:?} != {rotated:?}");
+        }
+
+        // Alpha is preserved:
+        assert_eq!(accent.rotate_hue(90.0).a(), accent.a());
+        assert_eq!(accent.scale_saturation(0.5).a(), accent.a());
+        assert_eq!(accent.with_lightness(0.5).a(), accent.a());
+
+        // Gray colors have no hue, so rotating it has no effect:
+        let gray = Color32::from_gray(128);
+        assert_eq!(gray.rotate_hue(123.0), gray);
+        assert_eq!(gray.scale_saturation(2.0), gray);
+
+        // Removing all saturation makes the color gray:
+        let desaturated = Color32::RED.scale_saturation(0.0);
+        assert_eq!(desaturated.r(), desaturated.g());
+        assert_eq!(desaturated.g(), desaturated.b());
+
+        assert_eq!(
+            Color32::from_rgb(80, 90, 100).with_lightness(0.0),
+            Color32::BLACK
+        );
+        assert_eq!(Color32::from_gray(77).with_lightness(1.0), Color32::WHITE);
+    }
+
     #[test]
     fn from_black_white_alpha() {
         for a in 0..=255 {
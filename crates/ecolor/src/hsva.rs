@@ -103,6 +103,37 @@ impl Hsva {
         Self { a: 1.0, ..self }
     }
 
+    /// Rotate the hue by this many degrees, wrapping around.
+    ///
+    /// Gray colors (`s == 0`) have no hue, so this has no visible effect on them.
+    #[inline]
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        Self {
+            h: (self.h + degrees / 360.0).rem_euclid(1.0),
+            ..self
+        }
+    }
+
+    /// Multiply the saturation by the given factor, clamping the result to the 0-1 range.
+    ///
+    /// A factor of `0.0` makes the color gray; `1.0` leaves it unchanged.
+    #[inline]
+    pub fn scale_saturation(self, factor: f32) -> Self {
+        Self {
+            s: (self.s * factor).clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Replace the value (brightness) with the given one, clamped to the 0-1 range.
+    #[inline]
+    pub fn with_value(self, v: f32) -> Self {
+        Self {
+            v: v.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
     #[inline]
     pub fn to_rgb(&self) -> [f32; 3] {
         rgb_from_hsv((self.h, self.s, self.v))
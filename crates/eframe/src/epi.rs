@@ -180,8 +180,15 @@ pub trait App {
 
     /// Called once on shutdown, after [`Self::save`].
     ///
-    /// If you need to abort an exit check `ctx.input(|i| i.viewport().close_requested())`
-    /// and respond with [`egui::ViewportCommand::CancelClose`].
+    /// By this point the close can no longer be cancelled.
+    /// If you need to veto an exit (e.g. to show an "unsaved changes" dialog),
+    /// check `ctx.input(|i| i.viewport().close_requested())` in [`Self::update`]
+    /// and respond with [`egui::ViewportCommand::CancelClose`] —
+    /// the window then stays open, and you can later send
+    /// [`egui::ViewportCommand::Close`] to actually close.
+    /// See the `confirm_exit` example for a full unsaved-changes dialog.
+    ///
+    /// On web there is no veto: the browser only lets us save state in `beforeunload`.
     ///
     /// To get a [`glow`] context you need to compile with the `glow` feature flag,
     /// and run eframe with the glow backend.
@@ -190,7 +197,15 @@ pub trait App {
 
     /// Called once on shutdown, after [`Self::save`].
     ///
-    /// If you need to abort an exit use [`Self::on_close_event`].
+    /// By this point the close can no longer be cancelled.
+    /// If you need to veto an exit (e.g. to show an "unsaved changes" dialog),
+    /// check `ctx.input(|i| i.viewport().close_requested())` in [`Self::update`]
+    /// and respond with [`egui::ViewportCommand::CancelClose`] —
+    /// the window then stays open, and you can later send
+    /// [`egui::ViewportCommand::Close`] to actually close.
+    /// See the `confirm_exit` example for a full unsaved-changes dialog.
+    ///
+    /// On web there is no veto: the browser only lets us save state in `beforeunload`.
     #[cfg(not(feature = "glow"))]
     fn on_exit(&mut self) {}
 
@@ -372,6 +387,13 @@ pub struct NativeOptions {
 
     /// Controls whether or not the native window position and size will be
     /// persisted (only if the "persistence" feature is enabled).
+    ///
+    /// The outer position, inner size, and fullscreen/maximized state are
+    /// stored in [`crate::Storage`] on shutdown and restored on the next launch.
+    /// The restored geometry is clamped to the currently connected monitors,
+    /// so a window last shown on a since-disconnected monitor still appears on screen.
+    ///
+    /// Defaults to `true`.
     pub persist_window: bool,
 
     /// The folder where `eframe` will store the app state. If not set, eframe will use a default
@@ -483,6 +505,29 @@ pub struct WebOptions {
     #[cfg(feature = "glow")]
     pub webgl_context_option: WebGlContextOption,
 
+    /// Which GPU the browser should pick for the WebGL context.
+    ///
+    /// Default: [`WebGlPowerPreference::Default`].
+    #[cfg(feature = "glow")]
+    pub webgl_power_preference: WebGlPowerPreference,
+
+    /// Overrides the webkitGTK brightening workaround for WebGL1
+    /// (see <https://github.com/emilk/egui/issues/794>).
+    ///
+    /// The workaround is normally enabled based on user-agent sniffing, which can
+    /// misfire on some embedded WebKit builds. `Some(true)` forces the workaround on,
+    /// `Some(false)` forces it off.
+    ///
+    /// Default: `None` (auto-detect).
+    #[cfg(feature = "glow")]
+    pub webgl1_brightening: Option<bool>,
+
+    /// How the canvas composites with the page behind it.
+    ///
+    /// Default: [`WebGlAlphaMode::Premultiplied`].
+    #[cfg(feature = "glow")]
+    pub webgl_alpha_mode: WebGlAlphaMode,
+
     /// Configures wgpu instance/device/adapter/surface creation and renderloop.
     #[cfg(feature = "wgpu")]
     pub wgpu_options: egui_wgpu::WgpuConfiguration,
@@ -520,6 +565,15 @@ impl Default for WebOptions {
             #[cfg(feature = "glow")]
             webgl_context_option: WebGlContextOption::BestFirst,
 
+            #[cfg(feature = "glow")]
+            webgl_power_preference: WebGlPowerPreference::Default,
+
+            #[cfg(feature = "glow")]
+            webgl1_brightening: None,
+
+            #[cfg(feature = "glow")]
+            webgl_alpha_mode: WebGlAlphaMode::Premultiplied,
+
             #[cfg(feature = "wgpu")]
             wgpu_options: egui_wgpu::WgpuConfiguration::default(),
 
@@ -550,6 +604,46 @@ pub enum WebGlContextOption {
     CompatibilityFirst,
 }
 
+/// Which GPU the browser should pick for a WebGL context.
+///
+/// Mirrors the [`powerPreference`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLCanvasElement/getContext#powerpreference)
+/// WebGL context attribute.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WebGlPowerPreference {
+    /// Let the browser decide which GPU to use.
+    #[default]
+    Default,
+
+    /// Prefer the more power-efficient GPU (usually the integrated one).
+    LowPower,
+
+    /// Prefer the higher-performance GPU (usually the discrete one).
+    HighPerformance,
+}
+
+/// How a WebGL canvas composites with the page behind it.
+///
+/// Maps to the `alpha` and `premultipliedAlpha` WebGL context attributes,
+/// which are supported by both WebGL1 and WebGL2.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WebGlAlphaMode {
+    /// An opaque canvas (`alpha: false`); the page behind the canvas is never visible.
+    Opaque,
+
+    /// `alpha: true, premultipliedAlpha: true`:
+    /// the browser treats the canvas pixels as having premultiplied alpha.
+    ///
+    /// This is the browser default, and matches how egui blends colors.
+    #[default]
+    Premultiplied,
+
+    /// `alpha: true, premultipliedAlpha: false`:
+    /// the browser treats the canvas pixels as straight (non-premultiplied) alpha.
+    Straight,
+}
+
 // ----------------------------------------------------------------------------
 
 /// What rendering backend to use.
@@ -1,4 +1,4 @@
-use egui::{TexturesDelta, UserData, ViewportCommand};
+use egui::{TexturesDelta, UserData, ViewportCommand, ViewportId};
 
 use crate::{epi, App};
 
@@ -18,7 +18,7 @@ pub struct AppRunner {
 
     // If not empty, the painter should capture n frames from now.
     // zero means capture the exact next frame.
-    screenshot_commands_with_frame_delay: Vec<(UserData, usize)>,
+    screenshot_commands_with_frame_delay: Vec<(ViewportId, UserData, usize)>,
 
     // Output for the last run:
     textures_delta: TexturesDelta,
@@ -42,6 +42,7 @@ impl AppRunner {
     ) -> Result<Self, String> {
         let egui_ctx = egui::Context::default();
         let painter = super::ActiveWebPainter::new(egui_ctx.clone(), canvas, &web_options).await?;
+        log::debug!("Graphics: {:?}", painter.graphics_info());
 
         let info = epi::IntegrationInfo {
             web_info: epi::WebInfo {
@@ -213,6 +214,12 @@ impl AppRunner {
         // We might have received a screenshot
         self.painter.handle_screenshots(&mut self.input.raw.events);
 
+        if self.painter.take_context_restored() {
+            // All GPU resources were lost with the old context,
+            // so everything needs to be painted again.
+            self.egui_ctx.request_repaint();
+        }
+
         let canvas_size = super::canvas_size_in_points(self.canvas(), self.egui_ctx());
         let mut raw_input = self.input.new_frame(canvas_size);
 
@@ -244,12 +251,12 @@ impl AppRunner {
         if viewport_output.len() > 1 {
             log::warn!("Multiple viewports not yet supported on the web");
         }
-        for (_viewport_id, viewport_output) in viewport_output {
+        for (viewport_id, viewport_output) in viewport_output {
             for command in viewport_output.commands {
                 match command {
                     ViewportCommand::Screenshot(user_data) => {
                         self.screenshot_commands_with_frame_delay
-                            .push((user_data, 1));
+                            .push((viewport_id, user_data, 1));
                     }
                     _ => {
                         // TODO(emilk): handle some of the commands
@@ -268,27 +275,33 @@ impl AppRunner {
 
     /// Paint the results of the last call to [`Self::logic`].
     pub fn paint(&mut self) {
+        if self.painter.is_context_lost() {
+            // Painting would fail; wait for the context to be restored.
+            return;
+        }
+
         let textures_delta = std::mem::take(&mut self.textures_delta);
         let clipped_primitives = std::mem::take(&mut self.clipped_primitives);
 
         if let Some(clipped_primitives) = clipped_primitives {
             let mut screenshot_commands = vec![];
-            self.screenshot_commands_with_frame_delay
-                .retain_mut(|(user_data, frame_delay)| {
+            self.screenshot_commands_with_frame_delay.retain_mut(
+                |(viewport_id, user_data, frame_delay)| {
                     if *frame_delay == 0 {
-                        screenshot_commands.push(user_data.clone());
+                        screenshot_commands.push((*viewport_id, user_data.clone()));
                         false
                     } else {
                         *frame_delay -= 1;
                         true
                     }
-                });
+                },
+            );
             if !self.screenshot_commands_with_frame_delay.is_empty() {
                 self.egui_ctx().request_repaint();
             }
 
             if let Err(err) = self.painter.paint_and_update_textures(
-                self.app.clear_color(&self.egui_ctx.style().visuals),
+                super::ClearOp::Color(self.app.clear_color(&self.egui_ctx.style().visuals)),
                 &clipped_primitives,
                 self.egui_ctx.pixels_per_point(),
                 &textures_delta,
@@ -314,6 +327,7 @@ impl AppRunner {
         let egui::PlatformOutput {
             commands,
             cursor_icon,
+            cursor_icon_priority: _, // already resolved into `cursor_icon`
             open_url,
             copied_text,
             events: _,                    // already handled
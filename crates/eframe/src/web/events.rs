@@ -310,30 +310,87 @@ pub(crate) fn on_keyup(event: web_sys::KeyboardEvent, runner: &mut AppRunner) {
 }
 
 fn install_copy_cut_paste(runner_ref: &WebRunner, target: &EventTarget) -> Result<(), JsValue> {
-    runner_ref.add_event_listener(target, "paste", |event: web_sys::ClipboardEvent, runner| {
-        if let Some(data) = event.clipboard_data() {
-            if let Ok(text) = data.get_data("text") {
-                let text = text.replace("\r\n", "\n");
+    runner_ref.add_event_listener(target, "paste", {
+        let runner_ref = runner_ref.clone();
 
-                let mut should_stop_propagation = true;
-                let mut should_prevent_default = true;
-                if !text.is_empty() && runner.input.raw.focused {
-                    let egui_event = egui::Event::Paste(text);
-                    should_stop_propagation =
-                        (runner.web_options.should_stop_propagation)(&egui_event);
-                    should_prevent_default =
-                        (runner.web_options.should_prevent_default)(&egui_event);
-                    runner.input.raw.events.push(egui_event);
-                    runner.needs_repaint.repaint_asap();
+        move |event: web_sys::ClipboardEvent, runner| {
+            if let Some(data) = event.clipboard_data() {
+                // Images (e.g. pasted screenshots) arrive as files:
+                let mut has_image = false;
+                if let Some(files) = data.files() {
+                    for i in 0..files.length() {
+                        if let Some(file) = files.get(i) {
+                            if file.type_().starts_with("image/") {
+                                has_image = true;
+
+                                let future =
+                                    wasm_bindgen_futures::JsFuture::from(file.array_buffer());
+
+                                let runner_ref = runner_ref.clone();
+                                let future = async move {
+                                    match future.await {
+                                        Ok(array_buffer) => {
+                                            let bytes =
+                                                js_sys::Uint8Array::new(&array_buffer).to_vec();
+                                            match super::decode_image(&bytes) {
+                                                Ok(image) => {
+                                                    if let Some(mut runner_lock) =
+                                                        runner_ref.try_lock()
+                                                    {
+                                                        runner_lock
+                                                            .input
+                                                            .raw
+                                                            .events
+                                                            .push(egui::Event::PasteImage(image));
+                                                        runner_lock.needs_repaint.repaint_asap();
+                                                    }
+                                                }
+                                                Err(err) => {
+                                                    log::error!(
+                                                        "Failed to decode pasted image: {err}"
+                                                    );
+                                                }
+                                            }
+                                        }
+                                        Err(err) => {
+                                            log::error!("Failed to read pasted image: {:?}", err);
+                                        }
+                                    }
+                                };
+                                wasm_bindgen_futures::spawn_local(future);
+                            }
+                        }
+                    }
                 }
 
-                // Use web options to tell if the web event should be propagated to parent elements based on the egui event.
-                if should_stop_propagation {
-                    event.stop_propagation();
-                }
+                if has_image {
+                    if runner.input.raw.focused {
+                        event.stop_propagation();
+                        event.prevent_default();
+                    }
+                } else if let Ok(text) = data.get_data("text") {
+                    let text = text.replace("\r\n", "\n");
+
+                    let mut should_stop_propagation = true;
+                    let mut should_prevent_default = true;
+                    if !text.is_empty() && runner.input.raw.focused {
+                        let egui_event = egui::Event::Paste(text);
+                        should_stop_propagation =
+                            (runner.web_options.should_stop_propagation)(&egui_event);
+                        should_prevent_default =
+                            (runner.web_options.should_prevent_default)(&egui_event);
+                        runner.input.raw.events.push(egui_event);
+                        runner.needs_repaint.repaint_asap();
+                    }
 
-                if should_prevent_default {
-                    event.prevent_default();
+                    // Use web options to tell if the web event should be propagated to parent elements based on the egui event.
+                    if should_stop_propagation {
+                        event.stop_propagation();
+                    }
+
+                    if should_prevent_default {
+                        event.prevent_default();
+                    }
                 }
             }
         }
@@ -840,6 +897,12 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
         if let Some(data_transfer) = event.data_transfer() {
             runner.input.raw.hovered_files.clear();
 
+            let position = Some(pos_from_mouse_event(
+                runner.canvas(),
+                &event,
+                runner.egui_ctx(),
+            ));
+
             // NOTE: data_transfer.files() is always empty in dragover
 
             let items = data_transfer.items();
@@ -847,6 +910,7 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
                 if let Some(item) = items.get(i) {
                     runner.input.raw.hovered_files.push(egui::HoveredFile {
                         mime: item.type_(),
+                        position,
                         ..Default::default()
                     });
                 }
@@ -854,11 +918,10 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
 
             if runner.input.raw.hovered_files.is_empty() {
                 // Fallback: just preview anything. Needed on Desktop Safari.
-                runner
-                    .input
-                    .raw
-                    .hovered_files
-                    .push(egui::HoveredFile::default());
+                runner.input.raw.hovered_files.push(egui::HoveredFile {
+                    position,
+                    ..Default::default()
+                });
             }
 
             runner.needs_repaint.repaint_asap();
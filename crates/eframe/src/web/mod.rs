@@ -27,10 +27,13 @@ pub use web_runner::WebRunner;
 compile_error!("You must enable either the 'glow' or 'wgpu' feature");
 
 mod web_painter;
+pub use web_painter::{ClearOp, WebGraphicsInfo};
 
 #[cfg(feature = "glow")]
 mod web_painter_glow;
 #[cfg(feature = "glow")]
+pub use web_painter_glow::WebPainterError;
+#[cfg(feature = "glow")]
 pub(crate) type ActiveWebPainter = web_painter_glow::WebPainterGlow;
 
 #[cfg(feature = "wgpu")]
@@ -256,6 +259,19 @@ fn to_image(image: &egui::ColorImage) -> Result<image::RgbaImage, String> {
     .ok_or_else(|| "Invalid IconData".to_owned())
 }
 
+/// Decode a clipboard image (e.g. a pasted screenshot) into a [`egui::ColorImage`].
+fn decode_image(bytes: &[u8]) -> Result<egui::ColorImage, String> {
+    profiling::function_scope!();
+    let image = image::load_from_memory(bytes)
+        .map_err(|err| err.to_string())?
+        .to_rgba8();
+    let size = [image.width() as usize, image.height() as usize];
+    Ok(egui::ColorImage::from_rgba_unmultiplied(
+        size,
+        image.as_raw(),
+    ))
+}
+
 fn to_png_bytes(image: &image::RgbaImage) -> Result<Vec<u8>, String> {
     profiling::function_scope!();
     let mut png_bytes: Vec<u8> = Vec::new();
@@ -1,6 +1,36 @@
-use egui::{Event, UserData};
+use egui::{Event, UserData, ViewportId};
 use wasm_bindgen::JsValue;
 
+/// How the painter should treat the previous contents of the canvas before painting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClearOp {
+    /// Clear the canvas with the given color before painting.
+    Color([f32; 4]),
+
+    /// Don't clear: paint the UI over whatever is already in the canvas/framebuffer.
+    ///
+    /// Useful when egui is drawn as a HUD over a scene rendered into the same context.
+    None,
+}
+
+/// Information about the graphics context a [`WebPainter`] ended up with.
+///
+/// Useful for logging and for including in bug reports.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WebGraphicsInfo {
+    /// The WebGL major version (1 or 2), if a WebGL backend is in use.
+    pub webgl_version: Option<u8>,
+
+    /// The `UNMASKED_VENDOR_WEBGL` string, if available.
+    pub vendor: Option<String>,
+
+    /// The `UNMASKED_RENDERER_WEBGL` string (or wgpu adapter name), if available.
+    pub renderer: Option<String>,
+
+    /// Maximum size of a texture in one direction.
+    pub max_texture_side: usize,
+}
+
 /// Renderer for a browser canvas.
 /// As of writing we're not allowing to decide on the painter at runtime,
 /// therefore this trait is merely there for specifying and documenting the interface.
@@ -16,20 +46,43 @@ pub(crate) trait WebPainter {
     /// Maximum size of a texture in one direction.
     fn max_texture_side(&self) -> usize;
 
+    /// Information about the rendering backend, for logging and bug reports.
+    fn graphics_info(&self) -> WebGraphicsInfo {
+        WebGraphicsInfo {
+            max_texture_side: self.max_texture_side(),
+            ..Default::default()
+        }
+    }
+
     /// Update all internal textures and paint gui.
-    /// When `capture` isn't empty, the rendered screen should be captured.
+    /// When `capture` isn't empty, the rendered screen should be captured,
+    /// once per requesting viewport.
     /// Once the screenshot is ready, the screenshot should be returned via [`Self::handle_screenshots`].
     fn paint_and_update_textures(
         &mut self,
-        clear_color: [f32; 4],
+        clear: ClearOp,
         clipped_primitives: &[egui::ClippedPrimitive],
         pixels_per_point: f32,
         textures_delta: &egui::TexturesDelta,
-        capture: Vec<UserData>,
+        capture: Vec<(ViewportId, UserData)>,
     ) -> Result<(), JsValue>;
 
     fn handle_screenshots(&mut self, events: &mut Vec<Event>);
 
+    /// Is the underlying rendering context currently lost
+    /// (e.g. after a `webglcontextlost` event)?
+    ///
+    /// Painting should be skipped while the context is lost.
+    fn is_context_lost(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` once after a lost rendering context has been restored,
+    /// so that the caller can request a full repaint.
+    fn take_context_restored(&mut self) -> bool {
+        false
+    }
+
     /// Destroy all resources.
     fn destroy(&mut self);
 }
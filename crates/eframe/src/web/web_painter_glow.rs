@@ -1,18 +1,113 @@
 use egui::{Event, UserData, ViewportId};
 use egui_glow::glow;
+use std::cell::Cell;
+use std::rc::Rc;
 use std::sync::Arc;
+use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast as _;
 use wasm_bindgen::JsValue;
 use web_sys::HtmlCanvasElement;
 
-use crate::{WebGlContextOption, WebOptions};
+use crate::{WebGlAlphaMode, WebGlContextOption, WebGlPowerPreference, WebOptions};
 
-use super::web_painter::WebPainter;
+use super::web_painter::{ClearOp, WebGraphicsInfo, WebPainter};
+
+/// An error that can happen when setting up the glow web painter.
+///
+/// This distinguishes "this browser doesn't support WebGL at all" from
+/// failures that happen after a context was successfully created.
+#[derive(Debug)]
+pub enum WebPainterError {
+    /// The browser failed to hand out a WebGL context for the canvas.
+    ContextCreationFailed(String),
+
+    /// Neither WebGL1 nor WebGL2 is supported by this browser.
+    WebGlUnsupported,
+
+    /// The glow painter failed to initialize (e.g. shader compilation failed).
+    PainterInit(egui_glow::PainterError),
+}
+
+impl std::fmt::Display for WebPainterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ContextCreationFailed(err) => {
+                write!(f, "Failed to create WebGL context: {err}")
+            }
+            Self::WebGlUnsupported => write!(f, "WebGL isn't supported"),
+            Self::PainterInit(err) => write!(f, "Error starting glow painter: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for WebPainterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ContextCreationFailed(_) | Self::WebGlUnsupported => None,
+            Self::PainterInit(err) => Some(err),
+        }
+    }
+}
+
+impl From<WebPainterError> for String {
+    fn from(err: WebPainterError) -> Self {
+        err.to_string()
+    }
+}
+
+/// The subset of [`WebOptions`] we need to recreate the glow context
+/// after a `webglcontextlost`/`webglcontextrestored` cycle.
+#[derive(Clone, Copy)]
+struct GlowInitOptions {
+    webgl_context_option: WebGlContextOption,
+    webgl_power_preference: WebGlPowerPreference,
+    webgl1_brightening: Option<bool>,
+    webgl_alpha_mode: WebGlAlphaMode,
+    dithering: bool,
+}
+
+impl GlowInitOptions {
+    fn from_web_options(options: &WebOptions) -> Self {
+        Self {
+            webgl_context_option: options.webgl_context_option,
+            webgl_power_preference: options.webgl_power_preference,
+            webgl1_brightening: options.webgl1_brightening,
+            webgl_alpha_mode: options.webgl_alpha_mode,
+            dithering: options.dithering,
+        }
+    }
+}
+
+/// Give up on a screenshot if it still reads back empty after this many frames.
+const MAX_SCREENSHOT_RETRIES: usize = 3;
 
 pub(crate) struct WebPainterGlow {
     canvas: HtmlCanvasElement,
     painter: egui_glow::Painter,
-    screenshots: Vec<(egui::ColorImage, Vec<UserData>)>,
+    screenshots: Vec<(egui::ColorImage, Vec<(ViewportId, UserData)>)>,
+
+    /// Screenshot requests that produced an empty read-back and will be
+    /// retried on the next frame. The `usize` counts the attempts so far.
+    retry_captures: Vec<(ViewportId, UserData, usize)>,
+
+    /// Needed to rebuild the painter after a context loss.
+    init_options: GlowInitOptions,
+
+    /// Set by the `webglcontextlost` listener; painting is skipped while `true`.
+    context_lost: Rc<Cell<bool>>,
+
+    /// Set by the `webglcontextrestored` listener;
+    /// tells us to rebuild the painter before the next paint.
+    needs_rebuild: Rc<Cell<bool>>,
+
+    /// Set by the `webglcontextrestored` listener; consumed by [`WebPainter::take_context_restored`].
+    restored_pending_repaint: Rc<Cell<bool>>,
+
+    /// Keep the context-loss event listeners alive for as long as the painter.
+    event_listeners: Vec<(&'static str, Closure<dyn FnMut(web_sys::Event)>)>,
+
+    /// Which WebGL version and GPU we ended up with.
+    graphics_info: WebGraphicsInfo,
 }
 
 impl WebPainterGlow {
@@ -24,22 +119,98 @@ impl WebPainterGlow {
         _ctx: egui::Context,
         canvas: HtmlCanvasElement,
         options: &WebOptions,
-    ) -> Result<Self, String> {
-        let (gl, shader_prefix) =
-            init_glow_context_from_canvas(&canvas, options.webgl_context_option)?;
+    ) -> Result<Self, WebPainterError> {
+        let init_options = GlowInitOptions::from_web_options(options);
+        let (gl, shader_prefix, graphics_info) =
+            init_glow_context_from_canvas(&canvas, &init_options)?;
 
         #[allow(clippy::arc_with_non_send_sync, clippy::allow_attributes)] // For wasm
         let gl = std::sync::Arc::new(gl);
 
         let painter = egui_glow::Painter::new(gl, shader_prefix, None, options.dithering)
-            .map_err(|err| format!("Error starting glow painter: {err}"))?;
+            .map_err(WebPainterError::PainterInit)?;
+
+        let context_lost = Rc::new(Cell::new(false));
+        let needs_rebuild = Rc::new(Cell::new(false));
+        let restored_pending_repaint = Rc::new(Cell::new(false));
+
+        let mut event_listeners = Vec::new();
+
+        {
+            let context_lost = context_lost.clone();
+            let closure = Closure::wrap(Box::new(move |event: web_sys::Event| {
+                log::warn!("WebGL context lost");
+                // `preventDefault` signals the browser that we intend to restore
+                // the context, so that `webglcontextrestored` will fire.
+                event.prevent_default();
+                context_lost.set(true);
+            }) as Box<dyn FnMut(web_sys::Event)>);
+            canvas
+                .add_event_listener_with_callback(
+                    "webglcontextlost",
+                    closure.as_ref().unchecked_ref(),
+                )
+                .map_err(|err| {
+                    WebPainterError::ContextCreationFailed(super::string_from_js_value(&err))
+                })?;
+            event_listeners.push(("webglcontextlost", closure));
+        }
+
+        {
+            let context_lost = context_lost.clone();
+            let needs_rebuild = needs_rebuild.clone();
+            let restored_pending_repaint = restored_pending_repaint.clone();
+            let closure = Closure::wrap(Box::new(move |_event: web_sys::Event| {
+                log::info!("WebGL context restored");
+                context_lost.set(false);
+                needs_rebuild.set(true);
+                restored_pending_repaint.set(true);
+            }) as Box<dyn FnMut(web_sys::Event)>);
+            canvas
+                .add_event_listener_with_callback(
+                    "webglcontextrestored",
+                    closure.as_ref().unchecked_ref(),
+                )
+                .map_err(|err| {
+                    WebPainterError::ContextCreationFailed(super::string_from_js_value(&err))
+                })?;
+            event_listeners.push(("webglcontextrestored", closure));
+        }
 
         Ok(Self {
             canvas,
             painter,
             screenshots: Vec::new(),
+            retry_captures: Vec::new(),
+            init_options,
+            context_lost,
+            needs_rebuild,
+            restored_pending_repaint,
+            event_listeners,
+            graphics_info,
         })
     }
+
+    /// Rebuild the painter after the context has been restored.
+    ///
+    /// All GPU resources were lost with the old context; textures will be
+    /// re-uploaded from the coming [`egui::TexturesDelta`]s.
+    fn rebuild_painter(&mut self) -> Result<(), JsValue> {
+        let (gl, shader_prefix, graphics_info) =
+            init_glow_context_from_canvas(&self.canvas, &self.init_options)
+                .map_err(|err| JsValue::from_str(&err.to_string()))?;
+        self.graphics_info = graphics_info;
+
+        #[allow(clippy::arc_with_non_send_sync, clippy::allow_attributes)] // For wasm
+        let gl = std::sync::Arc::new(gl);
+
+        self.painter =
+            egui_glow::Painter::new(gl, shader_prefix, None, self.init_options.dithering).map_err(
+                |err| JsValue::from_str(&format!("Error restarting glow painter: {err}")),
+            )?;
+
+        Ok(())
+    }
 }
 
 impl WebPainter for WebPainterGlow {
@@ -51,27 +222,99 @@ impl WebPainter for WebPainterGlow {
         &self.canvas
     }
 
+    fn graphics_info(&self) -> WebGraphicsInfo {
+        WebGraphicsInfo {
+            max_texture_side: self.painter.max_texture_side(),
+            ..self.graphics_info.clone()
+        }
+    }
+
     fn paint_and_update_textures(
         &mut self,
-        clear_color: [f32; 4],
+        clear: ClearOp,
         clipped_primitives: &[egui::ClippedPrimitive],
         pixels_per_point: f32,
         textures_delta: &egui::TexturesDelta,
-        capture: Vec<UserData>,
+        capture: Vec<(ViewportId, UserData)>,
     ) -> Result<(), JsValue> {
+        if self.context_lost.get() {
+            // The GL context is gone, and any pending screenshots with it.
+            self.screenshots.clear();
+            self.retry_captures.clear();
+            return Ok(());
+        }
+
+        if self.needs_rebuild.take() {
+            log::debug!("Rebuilding glow painter after context restore");
+            self.rebuild_painter()?;
+        }
+
         let canvas_dimension = [self.canvas.width(), self.canvas.height()];
 
         for (id, image_delta) in &textures_delta.set {
             self.painter.set_texture(*id, image_delta);
         }
 
-        egui_glow::painter::clear(self.painter.gl(), canvas_dimension, clear_color);
+        if let ClearOp::Color(clear_color) = clear {
+            // `clear_color` is premultiplied (like all egui colors);
+            // convert it to match how the browser interprets the canvas pixels:
+            let clear_color = match self.init_options.webgl_alpha_mode {
+                WebGlAlphaMode::Opaque => [clear_color[0], clear_color[1], clear_color[2], 1.0],
+                WebGlAlphaMode::Premultiplied => clear_color,
+                WebGlAlphaMode::Straight => {
+                    let [r, g, b, a] = clear_color;
+                    if a > 0.0 {
+                        [r / a, g / a, b / a, a]
+                    } else {
+                        [0.0; 4]
+                    }
+                }
+            };
+
+            egui_glow::painter::clear(self.painter.gl(), canvas_dimension, clear_color);
+        }
+
+        // `paint_primitives` sets up its own scissor/blend state each frame,
+        // so skipping the clear leaves no stale GL state behind.
         self.painter
             .paint_primitives(canvas_dimension, pixels_per_point, clipped_primitives);
 
+        let mut capture: Vec<(ViewportId, UserData, usize)> = capture
+            .into_iter()
+            .map(|(viewport_id, data)| (viewport_id, data, 0))
+            .collect();
+        capture.append(&mut self.retry_captures);
+
         if !capture.is_empty() {
-            let image = self.painter.read_screen_rgba(canvas_dimension);
-            self.screenshots.push((image, capture));
+            // Some drivers return a zero-sized or fully transparent image if we
+            // read back before the first real paint. Retry next frame instead of
+            // emitting a blank screenshot.
+            let image = if canvas_dimension[0] == 0 || canvas_dimension[1] == 0 {
+                None
+            } else {
+                let image = self.painter.read_screen_rgba(canvas_dimension);
+                let is_empty =
+                    image.pixels.is_empty() || image.pixels.iter().all(|color| color.a() == 0);
+                (!is_empty).then_some(image)
+            };
+
+            if let Some(image) = image {
+                let capture = capture
+                    .into_iter()
+                    .map(|(viewport_id, data, _)| (viewport_id, data))
+                    .collect();
+                self.screenshots.push((image, capture));
+            } else {
+                for (viewport_id, data, attempts) in capture {
+                    if attempts < MAX_SCREENSHOT_RETRIES {
+                        self.retry_captures.push((viewport_id, data, attempts + 1));
+                    } else {
+                        log::warn!(
+                            "Giving up on screenshot after {MAX_SCREENSHOT_RETRIES} empty read-backs"
+                        );
+                    }
+                }
+            }
         }
 
         for &id in &textures_delta.free {
@@ -81,16 +324,35 @@ impl WebPainter for WebPainterGlow {
         Ok(())
     }
 
+    fn is_context_lost(&self) -> bool {
+        self.context_lost.get()
+    }
+
+    fn take_context_restored(&mut self) -> bool {
+        self.restored_pending_repaint.take()
+    }
+
     fn destroy(&mut self) {
+        for (event_name, closure) in self.event_listeners.drain(..) {
+            if let Err(err) = self
+                .canvas
+                .remove_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())
+            {
+                log::warn!(
+                    "Failed to remove {event_name:?} listener: {}",
+                    super::string_from_js_value(&err)
+                );
+            }
+        }
         self.painter.destroy();
     }
 
     fn handle_screenshots(&mut self, events: &mut Vec<Event>) {
         for (image, data) in self.screenshots.drain(..) {
             let image = Arc::new(image);
-            for data in data {
+            for (viewport_id, data) in data {
                 events.push(Event::Screenshot {
-                    viewport_id: ViewportId::default(),
+                    viewport_id,
                     image: image.clone(),
                     user_data: data,
                 });
@@ -99,44 +361,89 @@ impl WebPainter for WebPainterGlow {
     }
 }
 
-/// Returns glow context and shader prefix.
+/// Returns glow context, shader prefix, and info about the chosen context.
 fn init_glow_context_from_canvas(
     canvas: &HtmlCanvasElement,
-    options: WebGlContextOption,
-) -> Result<(glow::Context, &'static str), String> {
-    let result = match options {
+    options: &GlowInitOptions,
+) -> Result<(glow::Context, &'static str, WebGraphicsInfo), WebPainterError> {
+    match options.webgl_context_option {
         // Force use WebGl1
-        WebGlContextOption::WebGl1 => init_webgl1(canvas),
+        WebGlContextOption::WebGl1 => init_webgl1(canvas, options),
         // Force use WebGl2
-        WebGlContextOption::WebGl2 => init_webgl2(canvas),
+        WebGlContextOption::WebGl2 => init_webgl2(canvas, options),
         // Trying WebGl2 first
-        WebGlContextOption::BestFirst => init_webgl2(canvas).or_else(|| init_webgl1(canvas)),
+        WebGlContextOption::BestFirst => {
+            init_webgl2(canvas, options).or_else(|_| init_webgl1(canvas, options))
+        }
         // Trying WebGl1 first (useful for testing).
         WebGlContextOption::CompatibilityFirst => {
-            init_webgl1(canvas).or_else(|| init_webgl2(canvas))
+            init_webgl1(canvas, options).or_else(|_| init_webgl2(canvas, options))
         }
-    };
+    }
+}
 
-    if let Some(result) = result {
-        Ok(result)
-    } else {
-        Err("WebGL isn't supported".into())
+fn webgl_context_attributes(options: &GlowInitOptions) -> web_sys::WebGlContextAttributes {
+    let attributes = web_sys::WebGlContextAttributes::new();
+    attributes.set_power_preference(match options.webgl_power_preference {
+        WebGlPowerPreference::Default => web_sys::WebGlPowerPreference::Default,
+        WebGlPowerPreference::LowPower => web_sys::WebGlPowerPreference::LowPower,
+        WebGlPowerPreference::HighPerformance => web_sys::WebGlPowerPreference::HighPerformance,
+    });
+    match options.webgl_alpha_mode {
+        WebGlAlphaMode::Opaque => {
+            attributes.set_alpha(false);
+        }
+        WebGlAlphaMode::Premultiplied => {
+            attributes.set_alpha(true);
+            attributes.set_premultiplied_alpha(true);
+        }
+        WebGlAlphaMode::Straight => {
+            attributes.set_alpha(true);
+            attributes.set_premultiplied_alpha(false);
+        }
     }
+    attributes
 }
 
-fn init_webgl1(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static str)> {
-    let gl1_ctx = canvas
-        .get_context("webgl")
-        .expect("Failed to query about WebGL2 context");
+fn log_honored_power_preference(actual: Option<web_sys::WebGlContextAttributes>) {
+    if let Some(attributes) = actual {
+        log::debug!(
+            "WebGL power preference: {:?}",
+            attributes.get_power_preference()
+        );
+    }
+}
 
-    let gl1_ctx = gl1_ctx?;
+fn init_webgl1(
+    canvas: &HtmlCanvasElement,
+    options: &GlowInitOptions,
+) -> Result<(glow::Context, &'static str, WebGraphicsInfo), WebPainterError> {
+    let attributes = webgl_context_attributes(options);
+    let gl1_ctx = canvas
+        .get_context_with_context_options("webgl", attributes.as_ref())
+        .map_err(|err| WebPainterError::ContextCreationFailed(super::string_from_js_value(&err)))?
+        .ok_or(WebPainterError::WebGlUnsupported)?;
     log::debug!("WebGL1 selected.");
 
     let gl1_ctx = gl1_ctx
         .dyn_into::<web_sys::WebGlRenderingContext>()
         .unwrap();
 
-    let shader_prefix = if webgl1_requires_brightening(&gl1_ctx) {
+    log_honored_power_preference(gl1_ctx.get_context_attributes());
+
+    let (vendor, renderer) = unmasked_vendor_renderer(&gl1_ctx);
+    let graphics_info = WebGraphicsInfo {
+        webgl_version: Some(1),
+        vendor,
+        renderer,
+        max_texture_side: 0, // Filled in by the painter.
+    };
+
+    let requires_brightening = options
+        .webgl1_brightening
+        .unwrap_or_else(|| webgl1_requires_brightening(&gl1_ctx));
+
+    let shader_prefix = if requires_brightening {
         log::debug!("Enabling webkitGTK brightening workaround.");
         "#define APPLY_BRIGHTENING_GAMMA"
     } else {
@@ -145,24 +452,87 @@ fn init_webgl1(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static st
 
     let gl = glow::Context::from_webgl1_context(gl1_ctx);
 
-    Some((gl, shader_prefix))
+    Ok((gl, shader_prefix, graphics_info))
 }
 
-fn init_webgl2(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static str)> {
+fn init_webgl2(
+    canvas: &HtmlCanvasElement,
+    options: &GlowInitOptions,
+) -> Result<(glow::Context, &'static str, WebGraphicsInfo), WebPainterError> {
+    let attributes = webgl_context_attributes(options);
     let gl2_ctx = canvas
-        .get_context("webgl2")
-        .expect("Failed to query about WebGL2 context");
-
-    let gl2_ctx = gl2_ctx?;
+        .get_context_with_context_options("webgl2", attributes.as_ref())
+        .map_err(|err| WebPainterError::ContextCreationFailed(super::string_from_js_value(&err)))?
+        .ok_or(WebPainterError::WebGlUnsupported)?;
     log::debug!("WebGL2 selected.");
 
     let gl2_ctx = gl2_ctx
         .dyn_into::<web_sys::WebGl2RenderingContext>()
         .unwrap();
+
+    log_honored_power_preference(gl2_ctx.get_context_attributes());
+
+    let (vendor, renderer) = unmasked_vendor_renderer_webgl2(&gl2_ctx);
+    let graphics_info = WebGraphicsInfo {
+        webgl_version: Some(2),
+        vendor,
+        renderer,
+        max_texture_side: 0, // Filled in by the painter.
+    };
+
     let gl = glow::Context::from_webgl2_context(gl2_ctx);
     let shader_prefix = "";
 
-    Some((gl, shader_prefix))
+    Ok((gl, shader_prefix, graphics_info))
+}
+
+/// Query the `WEBGL_debug_renderer_info` extension for the unmasked vendor and renderer.
+fn unmasked_vendor_renderer(
+    gl: &web_sys::WebGlRenderingContext,
+) -> (Option<String>, Option<String>) {
+    // This call produces a warning in Firefox ("WEBGL_debug_renderer_info is deprecated in Firefox and will be removed.")
+    // but unless we call it we get errors in Chrome when we call `get_parameter` below.
+    // TODO(emilk): do something smart based on user agent?
+    if gl
+        .get_extension("WEBGL_debug_renderer_info")
+        .ok()
+        .flatten()
+        .is_none()
+    {
+        return (None, None);
+    }
+    let get = |pname: u32| {
+        gl.get_parameter(pname)
+            .ok()
+            .and_then(|value| value.as_string())
+    };
+    (
+        get(web_sys::WebglDebugRendererInfo::UNMASKED_VENDOR_WEBGL),
+        get(web_sys::WebglDebugRendererInfo::UNMASKED_RENDERER_WEBGL),
+    )
+}
+
+/// Same as [`unmasked_vendor_renderer`], but for a WebGL2 context.
+fn unmasked_vendor_renderer_webgl2(
+    gl: &web_sys::WebGl2RenderingContext,
+) -> (Option<String>, Option<String>) {
+    if gl
+        .get_extension("WEBGL_debug_renderer_info")
+        .ok()
+        .flatten()
+        .is_none()
+    {
+        return (None, None);
+    }
+    let get = |pname: u32| {
+        gl.get_parameter(pname)
+            .ok()
+            .and_then(|value| value.as_string())
+    };
+    (
+        get(web_sys::WebglDebugRendererInfo::UNMASKED_VENDOR_WEBGL),
+        get(web_sys::WebglDebugRendererInfo::UNMASKED_RENDERER_WEBGL),
+    )
 }
 
 fn webgl1_requires_brightening(gl: &web_sys::WebGlRenderingContext) -> bool {
@@ -185,24 +555,6 @@ fn webgl1_requires_brightening(gl: &web_sys::WebGlRenderingContext) -> bool {
 ///
 /// This function used to avoid displaying linear color with `sRGB` supported systems.
 fn is_safari_and_webkit_gtk(gl: &web_sys::WebGlRenderingContext) -> bool {
-    // This call produces a warning in Firefox ("WEBGL_debug_renderer_info is deprecated in Firefox and will be removed.")
-    // but unless we call it we get errors in Chrome when we call `get_parameter` below.
-    // TODO(emilk): do something smart based on user agent?
-    if gl
-        .get_extension("WEBGL_debug_renderer_info")
-        .unwrap()
-        .is_some()
-    {
-        if let Ok(renderer) =
-            gl.get_parameter(web_sys::WebglDebugRendererInfo::UNMASKED_RENDERER_WEBGL)
-        {
-            if let Some(renderer) = renderer.as_string() {
-                if renderer.contains("Apple") {
-                    return true;
-                }
-            }
-        }
-    }
-
-    false
+    let (_vendor, renderer) = unmasked_vendor_renderer(gl);
+    renderer.is_some_and(|renderer| renderer.contains("Apple"))
 }
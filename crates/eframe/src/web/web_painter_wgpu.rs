@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use super::web_painter::WebPainter;
+use super::web_painter::{ClearOp, WebGraphicsInfo, WebPainter};
 use crate::WebOptions;
 use egui::{Event, UserData, ViewportId};
 use egui_wgpu::capture::{capture_channel, CaptureReceiver, CaptureSender, CaptureState};
@@ -123,13 +123,25 @@ impl WebPainter for WebPainterWgpu {
         })
     }
 
+    fn graphics_info(&self) -> WebGraphicsInfo {
+        WebGraphicsInfo {
+            webgl_version: None,
+            vendor: None,
+            renderer: self
+                .render_state
+                .as_ref()
+                .map(|state| state.adapter.get_info().name),
+            max_texture_side: self.max_texture_side(),
+        }
+    }
+
     fn paint_and_update_textures(
         &mut self,
-        clear_color: [f32; 4],
+        clear: ClearOp,
         clipped_primitives: &[egui::ClippedPrimitive],
         pixels_per_point: f32,
         textures_delta: &egui::TexturesDelta,
-        capture_data: Vec<UserData>,
+        capture_data: Vec<(ViewportId, UserData)>,
     ) -> Result<(), JsValue> {
         let capture = !capture_data.is_empty();
 
@@ -228,12 +240,15 @@ impl WebPainter for WebPainterWgpu {
                         view: &target_view,
                         resolve_target: None,
                         ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color {
-                                r: clear_color[0] as f64,
-                                g: clear_color[1] as f64,
-                                b: clear_color[2] as f64,
-                                a: clear_color[3] as f64,
-                            }),
+                            load: match clear {
+                                ClearOp::Color(clear_color) => wgpu::LoadOp::Clear(wgpu::Color {
+                                    r: clear_color[0] as f64,
+                                    g: clear_color[1] as f64,
+                                    b: clear_color[2] as f64,
+                                    a: clear_color[3] as f64,
+                                }),
+                                ClearOp::None => wgpu::LoadOp::Load,
+                            },
                             store: wgpu::StoreOp::Store,
                         },
                     })],
@@ -294,12 +309,18 @@ impl WebPainter for WebPainterWgpu {
         if let Some((frame, capture_buffer)) = frame_and_capture_buffer {
             if let Some(capture_buffer) = capture_buffer {
                 if let Some(capture_state) = &self.screen_capture_state {
+                    // We only render a single canvas, so all captures of one frame
+                    // belong to the same viewport.
+                    let viewport_id = capture_data
+                        .first()
+                        .map_or(ViewportId::ROOT, |(viewport_id, _)| *viewport_id);
+                    let user_data = capture_data.into_iter().map(|(_, data)| data).collect();
                     capture_state.read_screen_rgba(
                         self.ctx.clone(),
                         capture_buffer,
-                        capture_data,
+                        user_data,
                         self.capture_tx.clone(),
-                        ViewportId::ROOT,
+                        viewport_id,
                     );
                 }
             }
@@ -311,7 +332,21 @@ impl WebPainter for WebPainterWgpu {
     }
 
     fn handle_screenshots(&mut self, events: &mut Vec<Event>) {
+        let canvas_size = [self.canvas.width() as usize, self.canvas.height() as usize];
         for (viewport_id, user_data, screenshot) in self.capture_rx.try_iter() {
+            // The buffer mapping resolves asynchronously, so the canvas may have been
+            // resized after the capture was requested. Drop such stale captures instead
+            // of reporting a screenshot whose size doesn't match the canvas.
+            if screenshot.size != canvas_size {
+                log::warn!(
+                    "Discarding stale screenshot of size {}x{} (canvas is now {}x{})",
+                    screenshot.size[0],
+                    screenshot.size[1],
+                    canvas_size[0],
+                    canvas_size[1]
+                );
+                continue;
+            }
             let screenshot = Arc::new(screenshot);
             for data in user_data {
                 events.push(Event::Screenshot {
@@ -109,6 +109,29 @@ impl Clipboard {
         self.clipboard = text;
     }
 
+    /// Get an image (e.g. a screenshot) from the clipboard, if it holds one.
+    ///
+    /// Returns `None` if the clipboard holds no image,
+    /// or if the platform clipboard doesn't support images.
+    pub fn get_image(&mut self) -> Option<egui::ColorImage> {
+        #[cfg(all(feature = "arboard", not(target_os = "android")))]
+        if let Some(clipboard) = &mut self.arboard {
+            return match clipboard.get_image() {
+                Ok(image) => Some(egui::ColorImage::from_rgba_unmultiplied(
+                    [image.width, image.height],
+                    &image.bytes,
+                )),
+                Err(arboard::Error::ContentNotAvailable) => None, // No image on the clipboard
+                Err(err) => {
+                    log::error!("arboard image paste error: {err}");
+                    None
+                }
+            };
+        }
+
+        None
+    }
+
     pub fn set_image(&mut self, image: &egui::ColorImage) {
         #[cfg(all(feature = "arboard", not(target_os = "android")))]
         if let Some(clipboard) = &mut self.arboard {
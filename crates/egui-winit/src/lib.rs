@@ -44,7 +44,13 @@ pub fn screen_size_in_pixels(window: &Window) -> egui::Vec2 {
     egui::vec2(size.width as f32, size.height as f32)
 }
 
-/// Calculate the `pixels_per_point` for a given window, given the current egui zoom factor
+/// Calculate the `pixels_per_point` for a given window, given the current egui zoom factor.
+///
+/// This composes the OS scale factor of the monitor the window is currently on
+/// with [`egui::Context::zoom_factor`], so a custom zoom survives moving the window
+/// between monitors with different DPI.
+/// [`State::on_window_event`] forwards `ScaleFactorChanged` to egui,
+/// which re-derives its `pixels_per_point` (and rebuilds the font atlas) the next frame.
 pub fn pixels_per_point(egui_ctx: &egui::Context, window: &Window) -> f32 {
     let native_pixels_per_point = window.scale_factor() as f32;
     let egui_zoom_factor = egui_ctx.zoom_factor();
@@ -427,6 +433,7 @@ impl State {
             WindowEvent::HoveredFile(path) => {
                 self.egui_input.hovered_files.push(egui::HoveredFile {
                     path: Some(path.clone()),
+                    position: self.pointer_pos_in_points,
                     ..Default::default()
                 });
                 EventResponse {
@@ -772,7 +779,9 @@ impl State {
                     self.egui_input.events.push(egui::Event::Copy);
                     return;
                 } else if is_paste_command(self.egui_input.modifiers, active_key) {
-                    if let Some(contents) = self.clipboard.get() {
+                    if let Some(image) = self.clipboard.get_image() {
+                        self.egui_input.events.push(egui::Event::PasteImage(image));
+                    } else if let Some(contents) = self.clipboard.get() {
                         let contents = contents.replace("\r\n", "\n");
                         if !contents.is_empty() {
                             self.egui_input.events.push(egui::Event::Paste(contents));
@@ -834,6 +843,7 @@ impl State {
         let egui::PlatformOutput {
             commands,
             cursor_icon,
+            cursor_icon_priority: _, // already resolved into `cursor_icon`
             open_url,
             copied_text,
             events: _,                    // handled elsewhere
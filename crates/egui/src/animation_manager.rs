@@ -1,5 +1,5 @@
 use crate::{
-    emath::{remap_clamp, NumExt as _},
+    emath::{lerp, NumExt as _},
     Id, IdMap, InputState,
 };
 
@@ -71,6 +71,7 @@ impl AnimationManager {
         animation_time: f32,
         id: Id,
         value: f32,
+        easing: fn(f32) -> f32,
     ) -> f32 {
         match self.values.get_mut(&id) {
             None => {
@@ -89,11 +90,12 @@ impl AnimationManager {
                 // On the frame we toggle we don't want to return the old value,
                 // so we extrapolate forwards by half a frame:
                 let time_since_toggle = time_since_toggle + input.predicted_dt / 2.0;
-                let current_value = remap_clamp(
-                    time_since_toggle,
-                    0.0..=animation_time,
-                    anim.from_value..=anim.to_value,
-                );
+                let t = if animation_time <= 0.0 {
+                    1.0
+                } else {
+                    (time_since_toggle / animation_time).clamp(0.0, 1.0)
+                };
+                let current_value = lerp(anim.from_value..=anim.to_value, easing(t));
                 if anim.to_value != value {
                     anim.from_value = current_value; //start new animation from current position of playing animation
                     anim.to_value = value;
@@ -114,6 +114,7 @@ pub struct Area {
     constrain: bool,
     constrain_rect: Option<Rect>,
     order: Order,
+    order_bias: i32,
     default_pos: Option<Pos2>,
     default_size: Vec2,
     pivot: Align2,
@@ -140,6 +141,7 @@ impl Area {
             constrain_rect: None,
             enabled: true,
             order: Order::Middle,
+            order_bias: 0,
             default_pos: None,
             default_size: Vec2::NAN,
             new_pos: None,
@@ -235,6 +237,23 @@ impl Area {
         self
     }
 
+    /// Fine-grained stacking within an [`Order`] band.
+    ///
+    /// Areas with the same [`Self::order`] are stacked by this bias first
+    /// (lower bias is further back), and only reordered by interaction
+    /// among areas with the same bias.
+    /// Ties keep their relative order from the previous frame.
+    ///
+    /// Useful when several overlay systems (e.g. notifications and modals)
+    /// share an [`Order`] band but need deterministic stacking.
+    ///
+    /// Default: `0`.
+    #[inline]
+    pub fn order_bias(mut self, order_bias: i32) -> Self {
+        self.order_bias = order_bias;
+        self
+    }
+
     #[inline]
     pub fn default_pos(mut self, default_pos: impl Into<Pos2>) -> Self {
         self.default_pos = Some(default_pos.into());
@@ -399,6 +418,7 @@ impl Area {
             sense,
             movable,
             order,
+            order_bias,
             interactable,
             enabled,
             default_pos,
@@ -416,6 +436,8 @@ impl Area {
 
         let layer_id = LayerId::new(order, id);
 
+        ctx.memory_mut(|mem| mem.areas_mut().set_order_bias(layer_id, order_bias));
+
         let state = AreaState::load(ctx, id);
         let mut sizing_pass = state.is_none();
         let mut state = state.unwrap_or(AreaState {
@@ -27,13 +27,17 @@ pub(crate) struct InnerState {
 pub struct CollapsingState {
     id: Id,
     state: InnerState,
+    animated: bool,
 }
 
 impl CollapsingState {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
         ctx.data_mut(|d| {
-            d.get_persisted::<InnerState>(id)
-                .map(|state| Self { id, state })
+            d.get_persisted::<InnerState>(id).map(|state| Self {
+                id,
+                state,
+                animated: true,
+            })
         })
     }
 
@@ -56,9 +60,17 @@ impl CollapsingState {
                 open: default_open,
                 open_height: None,
             },
+            animated: true,
         })
     }
 
+    /// Should toggling the open state animate the body height? Default: `true`.
+    ///
+    /// When `false`, the body snaps open/closed instantly.
+    pub fn set_animated(&mut self, animated: bool) {
+        self.animated = animated;
+    }
+
     pub fn is_open(&self) -> bool {
         self.state.open
     }
@@ -76,8 +88,11 @@ impl CollapsingState {
     pub fn openness(&self, ctx: &Context) -> f32 {
         if ctx.memory(|mem| mem.everything_is_visible()) {
             1.0
-        } else {
+        } else if self.animated {
             ctx.animate_bool_responsive(self.id, self.state.open)
+        } else {
+            // Snap any in-progress animation to its end state:
+            ctx.animate_bool_with_time(self.id, self.state.open, 0.0)
         }
     }
 
@@ -399,6 +414,7 @@ pub struct CollapsingHeader {
     selected: bool,
     show_background: bool,
     icon: Option<IconPainter>,
+    animated: bool,
 }
 
 impl CollapsingHeader {
@@ -421,6 +437,7 @@ impl CollapsingHeader {
             selected: false,
             show_background: false,
             icon: None,
+            animated: true,
         }
     }
 
@@ -505,6 +522,16 @@ impl CollapsingHeader {
         self.icon = Some(Box::new(icon_fn));
         self
     }
+
+    /// Should toggling the header animate the body height? Default: `true`.
+    ///
+    /// Call `.animated(false)` to make the body snap open/closed instantly,
+    /// regardless of [`crate::Style::animation_time`].
+    #[inline]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
 }
 
 struct Prepared {
@@ -529,6 +556,7 @@ impl CollapsingHeader {
             selectable,
             selected,
             show_background,
+            animated,
         } = self;
 
         // TODO(emilk): horizontal layout, with icon and text as labels. Insert background behind using Frame.
@@ -563,6 +591,7 @@ impl CollapsingHeader {
         );
 
         let mut state = CollapsingState::load_with_default_open(ui.ctx(), id, default_open);
+        state.set_animated(animated);
         if let Some(open) = open {
             if open != state.is_open() {
                 state.toggle(ui);
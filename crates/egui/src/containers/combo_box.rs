@@ -2,8 +2,9 @@ use epaint::Shape;
 
 use crate::{
     epaint, style::StyleModifier, style::WidgetVisuals, vec2, Align2, Context, Id, InnerResponse,
-    NumExt as _, Painter, Popup, PopupCloseBehavior, Rect, Response, ScrollArea, Sense, Stroke,
-    TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo, WidgetText, WidgetType,
+    Key, NumExt as _, Painter, Popup, PopupCloseBehavior, Rect, Response, ScrollArea, Sense,
+    Stroke, TextEdit, TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo, WidgetText,
+    WidgetType,
 };
 
 #[expect(unused_imports)] // Documentation
@@ -39,6 +40,7 @@ pub struct ComboBox {
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
+    searchable: bool,
 }
 
 impl ComboBox {
@@ -53,6 +55,7 @@ impl ComboBox {
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            searchable: false,
         }
     }
 
@@ -68,6 +71,7 @@ impl ComboBox {
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            searchable: false,
         }
     }
 
@@ -82,6 +86,7 @@ impl ComboBox {
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            searchable: false,
         }
     }
 
@@ -186,6 +191,26 @@ impl ComboBox {
         self
     }
 
+    /// Add a search field to the top of the popup,
+    /// letting the user filter the alternatives with a case-insensitive substring match.
+    ///
+    /// The filter is stored in the combo box [`Id`] while the popup is open,
+    /// and is reset when it closes.
+    ///
+    /// [`Self::show_index`] does the filtering for you:
+    /// arrow keys navigate the matching alternatives and enter selects the highlighted one.
+    /// With [`Self::show_ui`] you populate the popup yourself,
+    /// so you also have to do the filtering yourself, using [`Self::search_text`].
+    ///
+    /// Unless overridden with [`Self::close_behavior`], a searchable combo box uses
+    /// [`PopupCloseBehavior::CloseOnClickOutside`] so that clicking the search field
+    /// doesn't close the popup. Close it with [`Ui::close`] when an alternative is picked.
+    #[inline]
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
     /// Show the combo box, with the given ui code for the menu contents.
     ///
     /// Returns `InnerResponse { inner: None }` if the combo box is closed.
@@ -211,6 +236,7 @@ impl ComboBox {
             icon,
             wrap_mode,
             close_behavior,
+            searchable,
         } = self;
 
         let button_id = ui.make_persistent_id(id_salt);
@@ -225,6 +251,7 @@ impl ComboBox {
                 wrap_mode,
                 close_behavior,
                 (width, height),
+                searchable,
             );
             if let Some(label) = label {
                 ir.response.widget_info(|| {
@@ -265,16 +292,72 @@ impl ComboBox {
         len: usize,
         get: impl Fn(usize) -> Text,
     ) -> Response {
+        let popup_id = Self::widget_to_popup_id(ui.make_persistent_id(self.id_salt));
+        let searchable = self.searchable;
         let slf = self.selected_text(get(*selected));
 
         let mut changed = false;
 
         let mut response = slf
             .show_ui(ui, |ui| {
-                for i in 0..len {
-                    if ui.selectable_label(i == *selected, get(i)).clicked() {
+                let mut search_state = searchable
+                    .then(|| ui.data(|d| d.get_temp::<SearchState>(SearchState::id(popup_id))))
+                    .flatten();
+
+                let filter = search_state
+                    .as_ref()
+                    .map(|state| state.text.to_lowercase())
+                    .unwrap_or_default();
+
+                let matches: Vec<(usize, WidgetText)> = (0..len)
+                    .filter_map(|i| {
+                        let text: WidgetText = get(i).into();
+                        (filter.is_empty() || text.text().to_lowercase().contains(&filter))
+                            .then_some((i, text))
+                    })
+                    .collect();
+
+                if let Some(state) = &mut search_state {
+                    state.highlighted = state.highlighted.min(matches.len().saturating_sub(1));
+                    ui.data_mut(|d| d.insert_temp(SearchState::id(popup_id), state.clone()));
+                }
+
+                let (select_highlighted, navigated) = if searchable {
+                    ui.input(|i| {
+                        (
+                            i.key_pressed(Key::Enter),
+                            i.key_pressed(Key::ArrowDown) || i.key_pressed(Key::ArrowUp),
+                        )
+                    })
+                } else {
+                    (false, false)
+                };
+
+                for (position, (i, text)) in matches.into_iter().enumerate() {
+                    let mut item = ui.selectable_label(i == *selected, text);
+
+                    let is_highlighted = search_state
+                        .as_ref()
+                        .is_some_and(|state| position == state.highlighted);
+                    if is_highlighted {
+                        item = item.highlight();
+                        if navigated {
+                            item.scroll_to_me(None);
+                        }
+                        if select_highlighted {
+                            *selected = i;
+                            changed = true;
+                            ui.close();
+                        }
+                    }
+
+                    if item.clicked() {
                         *selected = i;
                         changed = true;
+                        if searchable {
+                            // `PopupCloseBehavior::CloseOnClickOutside` won't close for us:
+                            ui.close();
+                        }
                     }
                 }
             })
@@ -291,12 +374,69 @@ impl ComboBox {
         ctx.memory(|m| m.is_popup_open(Self::widget_to_popup_id(id)))
     }
 
+    /// The current text of the search field of a [`Self::searchable`] combo box,
+    /// or `None` if the popup is closed.
+    ///
+    /// `id` is the same id as in [`Self::is_open`].
+    pub fn search_text(ctx: &Context, id: Id) -> Option<String> {
+        ctx.data(|d| d.get_temp::<SearchState>(SearchState::id(Self::widget_to_popup_id(id))))
+            .map(|state| state.text)
+    }
+
     /// Convert a [`ComboBox`] id to the id used to store it's popup state.
     fn widget_to_popup_id(widget_id: Id) -> Id {
         widget_id.with("popup")
     }
 }
 
+/// State of the search field of a [`ComboBox::searchable`] combo box,
+/// kept in temporary memory while the popup is open.
+#[derive(Clone, Default)]
+struct SearchState {
+    /// Contents of the search field.
+    text: String,
+
+    /// Which of the alternatives matching the filter is highlighted,
+    /// for keyboard navigation.
+    highlighted: usize,
+}
+
+impl SearchState {
+    fn id(popup_id: Id) -> Id {
+        popup_id.with("search")
+    }
+}
+
+/// Show the search field at the top of a [`ComboBox::searchable`] popup.
+fn search_field(ui: &mut Ui, popup_id: Id) {
+    let state_id = SearchState::id(popup_id);
+    let state: Option<SearchState> = ui.data(|d| d.get_temp(state_id));
+    let just_opened = state.is_none();
+    let mut state = state.unwrap_or_default();
+
+    let response = ui.add(TextEdit::singleline(&mut state.text).hint_text("Search…"));
+
+    if just_opened {
+        response.request_focus();
+    }
+    if response.changed() {
+        // Highlight the first match whenever the filter changes:
+        state.highlighted = 0;
+    }
+
+    ui.input(|i| {
+        if i.key_pressed(Key::ArrowDown) {
+            state.highlighted = state.highlighted.saturating_add(1);
+        }
+        if i.key_pressed(Key::ArrowUp) {
+            state.highlighted = state.highlighted.saturating_sub(1);
+        }
+    });
+    // `ComboBox::show_index` clamps `highlighted` to the number of matches.
+
+    ui.data_mut(|d| d.insert_temp(state_id, state));
+}
+
 #[expect(clippy::too_many_arguments)]
 fn combo_box_dyn<'c, R>(
     ui: &mut Ui,
@@ -307,6 +447,7 @@ fn combo_box_dyn<'c, R>(
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
     (width, height): (Option<f32>, Option<f32>),
+    searchable: bool,
 ) -> InnerResponse<Option<R>> {
     let popup_id = ComboBox::widget_to_popup_id(button_id);
 
@@ -314,7 +455,12 @@ fn combo_box_dyn<'c, R>(
 
     let wrap_mode = wrap_mode.unwrap_or_else(|| ui.wrap_mode());
 
-    let close_behavior = close_behavior.unwrap_or(PopupCloseBehavior::CloseOnClick);
+    let close_behavior = close_behavior.unwrap_or(if searchable {
+        // Clicking the search field shouldn't close the popup:
+        PopupCloseBehavior::CloseOnClickOutside
+    } else {
+        PopupCloseBehavior::CloseOnClick
+    });
 
     let margin = ui.spacing().button_padding;
     let button_response = button_frame(ui, button_id, is_popup_open, Sense::click(), |ui| {
@@ -380,6 +526,10 @@ fn combo_box_dyn<'c, R>(
         .show(|ui| {
             ui.set_min_width(ui.available_width());
 
+            if searchable {
+                search_field(ui, popup_id);
+            }
+
             ScrollArea::vertical()
                 .max_height(height)
                 .show(ui, |ui| {
@@ -395,6 +545,11 @@ fn combo_box_dyn<'c, R>(
         })
         .map(|r| r.inner);
 
+    if searchable && inner.is_none() {
+        // The popup is closed - reset the search filter:
+        ui.data_mut(|d| d.remove::<SearchState>(SearchState::id(popup_id)));
+    }
+
     InnerResponse {
         inner,
         response: button_response,
@@ -312,6 +312,16 @@ impl Frame {
     }
 
     /// Optional drop-shadow behind the frame.
+    ///
+    /// The offset, blur width, spread, and color are all configurable:
+    ///
+    /// ```
+    /// # use egui::{Frame, epaint::Shadow, Color32};
+    /// let card = Frame::new().shadow(Shadow::new([2, 3], 12, 2, Color32::from_black_alpha(96)));
+    /// ```
+    ///
+    /// The shadow is rendered as a feathered mesh (see [`Shadow`]),
+    /// and is clipped by the frame's clip rectangle like everything else in it.
     #[inline]
     pub fn shadow(mut self, shadow: Shadow) -> Self {
         self.shadow = shadow;
@@ -1,7 +1,8 @@
 use crate::style::StyleModifier;
 use crate::{
-    Button, Color32, Context, Frame, Id, InnerResponse, Layout, Popup, PopupCloseBehavior,
-    Response, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo, Widget as _, WidgetText,
+    Button, Color32, Context, Frame, Id, InnerResponse, Key, Layout, Modifiers, Popup,
+    PopupCloseBehavior, Response, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo, Widget as _,
+    WidgetText,
 };
 use emath::{vec2, Align, RectAlign, Vec2};
 use epaint::Stroke;
@@ -413,7 +414,10 @@ impl SubMenu {
         let is_hovered = hover_pos.is_some_and(|pos| button_rect.contains(pos));
 
         // The clicked handler is there for accessibility (keyboard navigation)
-        if (!is_any_open && is_hovered) || button_response.clicked() {
+        let arrow_right_pressed = !is_open
+            && button_response.has_focus()
+            && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowRight));
+        if (!is_any_open && is_hovered) || button_response.clicked() || arrow_right_pressed {
             set_open = Some(true);
             is_open = true;
             // Ensure that all other sub menus are closed when we open the menu
@@ -422,6 +426,21 @@ impl SubMenu {
             });
         }
 
+        // Escape (and ⏴) closes one level of the menu hierarchy at a time,
+        // so only the deepest open submenu reacts to it.
+        // We consume the key so the parent menus stay open.
+        if is_open && MenuState::is_deepest_sub_menu(ui.ctx(), id) {
+            let close_one_level = ui.input_mut(|i| {
+                i.consume_key(Modifiers::NONE, Key::Escape)
+                    || i.consume_key(Modifiers::NONE, Key::ArrowLeft)
+            });
+            if close_one_level {
+                set_open = Some(false);
+                is_open = false;
+                button_response.request_focus(); // So ⏵ can reopen it.
+            }
+        }
+
         let gap = frame.total_margin().sum().x / 2.0 + 2.0;
 
         let mut response = button_response.clone();
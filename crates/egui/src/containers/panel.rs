@@ -144,6 +144,8 @@ impl SidePanel {
     ///
     /// Default is `true`.
     ///
+    /// Double-clicking the edge resets the panel to its default width.
+    ///
     /// If you want your panel to be resizable you also need a widget in it that
     /// takes up more space as you resize it, such as:
     /// * Wrapping text ([`Ui::horizontal_wrapped`]).
@@ -263,7 +265,12 @@ impl SidePanel {
                 resize_hover = resize_response.hovered();
                 is_resizing = resize_response.dragged();
 
-                if is_resizing {
+                if resize_response.double_clicked() {
+                    // Double-click the resize handle to reset to the default width:
+                    width =
+                        clamp_to_range(default_width, width_range).at_most(available_rect.width());
+                    side.set_rect_width(&mut panel_rect, width);
+                } else if is_resizing {
                     if let Some(pointer) = resize_response.interact_pointer_pos() {
                         width = (pointer.x - side.side_x(panel_rect)).abs();
                         width = clamp_to_range(width, width_range).at_most(available_rect.width());
@@ -318,7 +325,7 @@ impl SidePanel {
             let resize_x = side.opposite().side_x(panel_rect);
             let resize_rect = Rect::from_x_y_ranges(resize_x..=resize_x, panel_rect.y_range())
                 .expand2(vec2(ui.style().interaction.resize_grab_radius_side, 0.0));
-            let resize_response = ui.interact(resize_rect, resize_id, Sense::drag());
+            let resize_response = ui.interact(resize_rect, resize_id, Sense::click_and_drag());
             resize_hover = resize_response.hovered();
             is_resizing = resize_response.dragged();
         }
@@ -631,6 +638,8 @@ impl TopBottomPanel {
     ///
     /// Default is `false`.
     ///
+    /// Double-clicking the edge resets the panel to its default height.
+    ///
     /// If you want your panel to be resizable you also need a widget in it that
     /// takes up more space as you resize it, such as:
     /// * Wrapping text ([`Ui::horizontal_wrapped`]).
@@ -759,7 +768,14 @@ impl TopBottomPanel {
                 resize_hover = resize_response.hovered();
                 is_resizing = resize_response.dragged();
 
-                if is_resizing {
+                if resize_response.double_clicked() {
+                    // Double-click the resize handle to reset to the default height:
+                    height = default_height.unwrap_or_else(|| {
+                        ui.style().spacing.interact_size.y + frame.inner_margin.sum().y
+                    });
+                    height = clamp_to_range(height, height_range).at_most(available_rect.height());
+                    side.set_rect_height(&mut panel_rect, height);
+                } else if is_resizing {
                     if let Some(pointer) = resize_response.interact_pointer_pos() {
                         height = (pointer.y - side.side_y(panel_rect)).abs();
                         height =
@@ -815,7 +831,7 @@ impl TopBottomPanel {
             let resize_y = side.opposite().side_y(panel_rect);
             let resize_rect = Rect::from_x_y_ranges(panel_rect.x_range(), resize_y..=resize_y)
                 .expand2(vec2(0.0, ui.style().interaction.resize_grab_radius_side));
-            let resize_response = ui.interact(resize_rect, resize_id, Sense::drag());
+            let resize_response = ui.interact(resize_rect, resize_id, Sense::click_and_drag());
             resize_hover = resize_response.hovered();
             is_resizing = resize_response.dragged();
         }
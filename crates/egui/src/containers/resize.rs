@@ -17,6 +17,11 @@ pub(crate) struct State {
 
     /// Externally requested size (e.g. by Window) for the next frame
     pub(crate) requested_size: Option<Vec2>,
+
+    /// True once the user has resized the area by dragging.
+    /// A manual resize disables auto-shrinking (see [`Resize::auto_shrink`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    user_resized: bool,
 }
 
 impl State {
@@ -44,6 +49,8 @@ pub struct Resize {
 
     default_size: Vec2,
 
+    auto_shrink: Vec2b,
+
     with_stroke: bool,
 }
 
@@ -56,6 +63,7 @@ impl Default for Resize {
             min_size: Vec2::splat(16.0),
             max_size: Vec2::splat(f32::INFINITY),
             default_size: vec2(320.0, 128.0), // TODO(emilk): preferred size of [`Resize`] area.
+            auto_shrink: Vec2b::FALSE,
             with_stroke: true,
         }
     }
@@ -172,6 +180,24 @@ impl Resize {
         self.resizable
     }
 
+    /// Should the area shrink back down when its contents shrink?
+    ///
+    /// By default (`false`) the area only ever auto-expands:
+    /// once the contents have been large, the area stays that large
+    /// even if the contents later take up less space.
+    ///
+    /// With auto-shrink on, the area follows the contents back down again.
+    /// A small amount of slack is kept so that contents oscillating in size
+    /// by a pixel won't make the area jitter.
+    ///
+    /// Once the user resizes the area by dragging, auto-shrinking is disabled,
+    /// just as auto-expanding is.
+    #[inline]
+    pub fn auto_shrink(mut self, auto_shrink: impl Into<Vec2b>) -> Self {
+        self.auto_shrink = auto_shrink.into();
+        self
+    }
+
     /// Not manually resizable, just takes the size of its contents.
     /// Text will not wrap, but will instead make your window width expand.
     pub fn auto_sized(self) -> Self {
@@ -228,6 +254,7 @@ impl Resize {
                 desired_size: default_size,
                 last_content_size: vec2(0.0, 0.0),
                 requested_size: None,
+                user_resized: false,
             }
         });
 
@@ -253,11 +280,27 @@ impl Resize {
 
         if let Some(user_requested_size) = user_requested_size {
             state.desired_size = user_requested_size;
+            state.user_resized = true; // Manual resizes disable auto-shrinking.
         } else {
             // We are not being actively resized, so auto-expand to include size of last frame.
             // This prevents auto-shrinking if the contents contain width-filling widgets (separators etc)
             // but it makes a lot of interactions with [`Window`]s nicer.
             state.desired_size = state.desired_size.max(state.last_content_size);
+
+            if !state.user_resized {
+                // Allow for one pixel of content jitter without resizing:
+                const AUTO_SHRINK_SLACK: f32 = 2.0;
+
+                for d in 0..2 {
+                    if self.auto_shrink[d]
+                        && 0.0 < state.last_content_size[d]
+                        && AUTO_SHRINK_SLACK < state.desired_size[d] - state.last_content_size[d]
+                    {
+                        // Follow the contents back down again:
+                        state.desired_size[d] = state.last_content_size[d];
+                    }
+                }
+            }
         }
 
         state.desired_size = state
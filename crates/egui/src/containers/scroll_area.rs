@@ -3,7 +3,7 @@
 use std::ops::{Add, AddAssign, BitOr, BitOrAssign};
 
 use crate::{
-    emath, epaint, lerp, pass_state, pos2, remap, remap_clamp, Context, CursorIcon, Id,
+    emath, epaint, lerp, pass_state, pos2, remap, remap_clamp, Align, Context, CursorIcon, Id,
     NumExt as _, Pos2, Rangef, Rect, Sense, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b,
 };
 
@@ -47,6 +47,12 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// When did the user last scroll, drag a scroll bar, or hover near one?
+    ///
+    /// Used by [`ScrollBarVisibility::AutoHide`] to fade the bars out after inactivity.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_scroll_activity: f64,
 }
 
 impl Default for State {
@@ -61,6 +67,7 @@ impl Default for State {
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            last_scroll_activity: f64::NEG_INFINITY,
         }
     }
 }
@@ -99,7 +106,7 @@ pub struct ScrollAreaOutput<R> {
 }
 
 /// Indicate whether the horizontal and vertical scroll bars must be always visible, hidden or visible when needed.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ScrollBarVisibility {
     /// Hide scroll bar even if they are needed.
@@ -118,6 +125,23 @@ pub enum ScrollBarVisibility {
     /// Always show the scroll bar, even if the contents fit in the container
     /// and there is no need to scroll.
     AlwaysVisible,
+
+    /// Show the scroll bars when needed (like [`Self::VisibleWhenNeeded`]),
+    /// but fade them out after a period of inactivity.
+    ///
+    /// The bars fade back in when the user scrolls, drags a bar,
+    /// or hovers near the edge where a bar lives.
+    /// The bars are overlaid on the contents,
+    /// so the contents don't reflow when the bars fade in and out.
+    ///
+    /// See [`Self::AUTO_HIDE`] for sensible defaults.
+    AutoHide {
+        /// How long to keep the bars visible after the last activity, in seconds.
+        fade_delay: f32,
+
+        /// How long the fade in/out takes, in seconds.
+        fade_duration: f32,
+    },
 }
 
 impl Default for ScrollBarVisibility {
@@ -128,10 +152,18 @@ impl Default for ScrollBarVisibility {
 }
 
 impl ScrollBarVisibility {
-    pub const ALL: [Self; 3] = [
+    /// [`Self::AutoHide`] with sensible defaults:
+    /// fade out over a quarter of a second, after a second of inactivity.
+    pub const AUTO_HIDE: Self = Self::AutoHide {
+        fade_delay: 1.0,
+        fade_duration: 0.25,
+    };
+
+    pub const ALL: [Self; 4] = [
         Self::AlwaysHidden,
         Self::VisibleWhenNeeded,
         Self::AlwaysVisible,
+        Self::AUTO_HIDE,
     ];
 }
 
@@ -291,6 +323,7 @@ pub struct ScrollArea {
     on_drag_cursor: Option<CursorIcon>,
     scroll_source: ScrollSource,
     wheel_scroll_multiplier: Vec2,
+    momentum_decay: f32,
 
     /// If true for vertical or horizontal the scroll wheel will stick to the
     /// end position until user manually changes position. It will become true
@@ -299,6 +332,9 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    /// Scroll to this row in [`Self::show_rows`].
+    scroll_to_row: Option<(usize, Option<Align>)>,
 }
 
 impl ScrollArea {
@@ -344,8 +380,10 @@ impl ScrollArea {
             on_drag_cursor: None,
             scroll_source: ScrollSource::default(),
             wheel_scroll_multiplier: Vec2::splat(1.0),
+            momentum_decay: 3.0,
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            scroll_to_row: None,
         }
     }
 
@@ -466,6 +504,23 @@ impl ScrollArea {
         self
     }
 
+    /// Scroll to the given row when using [`Self::show_rows`].
+    ///
+    /// The row offset is computed from the row height, so this works even
+    /// when the target row isn't currently rendered.
+    ///
+    /// `align` specifies if the row should be aligned to the top, center, or bottom
+    /// of the visible area. If `align` is `None`, the scroll area will scroll
+    /// just enough to bring the row into view.
+    ///
+    /// The scroll is animated unless [`Self::animated`] is turned off.
+    /// Has no effect outside of [`Self::show_rows`].
+    #[inline]
+    pub fn scroll_to_row(mut self, row: usize, align: Option<Align>) -> Self {
+        self.scroll_to_row = Some((row, align));
+        self
+    }
+
     /// Set the cursor used when the mouse pointer is hovering over the [`ScrollArea`].
     ///
     /// Only applies if [`Self::scroll_source()`] has set [`ScrollSource::drag`] to `true`.
@@ -573,6 +628,22 @@ impl ScrollArea {
         self
     }
 
+    /// How quickly kinetic (momentum) scrolling slows down after a flick.
+    ///
+    /// The velocity decays exponentially: every second it is multiplied by `e^-momentum_decay`,
+    /// so higher values make the scrolling stop sooner.
+    /// The decay is frame-rate independent.
+    ///
+    /// Only relevant when dragging to scroll (e.g. on touch screens);
+    /// see [`Self::scroll_source`].
+    ///
+    /// Defaults to `3.0`.
+    #[inline]
+    pub fn momentum_decay(mut self, momentum_decay: f32) -> Self {
+        self.momentum_decay = momentum_decay;
+        self
+    }
+
     /// For each axis, should the containing area shrink if the content is small?
     ///
     /// * If `true`, egui will add blank space outside the scroll area.
@@ -668,6 +739,10 @@ struct Prepared {
     saved_scroll_target: [Option<pass_state::ScrollTarget>; 2],
 
     animated: bool,
+
+    /// The scroll offset when [`ScrollArea::begin`] ran,
+    /// so we can detect scrolling for [`ScrollBarVisibility::AutoHide`].
+    offset_at_begin: Vec2,
 }
 
 impl ScrollArea {
@@ -686,8 +761,10 @@ impl ScrollArea {
             on_drag_cursor,
             scroll_source,
             wheel_scroll_multiplier,
+            momentum_decay,
             stick_to_end,
             animated,
+            scroll_to_row: _, // consumed by `show_rows` before we get here
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -704,9 +781,13 @@ impl ScrollArea {
         state.offset.x = offset_x.unwrap_or(state.offset.x);
         state.offset.y = offset_y.unwrap_or(state.offset.y);
 
+        let offset_at_begin = state.offset;
+
         let show_bars: Vec2b = match scroll_bar_visibility {
             ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
-            ScrollBarVisibility::VisibleWhenNeeded => state.show_scroll,
+            ScrollBarVisibility::VisibleWhenNeeded | ScrollBarVisibility::AutoHide { .. } => {
+                state.show_scroll
+            }
             ScrollBarVisibility::AlwaysVisible => direction_enabled,
         };
 
@@ -715,7 +796,14 @@ impl ScrollArea {
             ctx.animate_bool_responsive(id.with("v"), show_bars[1]),
         );
 
-        let current_bar_use = show_bars_factor.yx() * ui.spacing().scroll.allocated_width();
+        let current_bar_use =
+            if matches!(scroll_bar_visibility, ScrollBarVisibility::AutoHide { .. }) {
+                // Auto-hiding bars are overlaid on the contents,
+                // so the contents don't reflow when the bars fade in and out.
+                Vec2::ZERO
+            } else {
+                show_bars_factor.yx() * ui.spacing().scroll.allocated_width()
+            };
 
         let available_outer = ui.available_rect_before_wrap();
 
@@ -791,6 +879,14 @@ impl ScrollArea {
                 .interact_rect
                 .map(|rect| ui.interact(rect, id.with("area"), Sense::drag()));
 
+            if content_response_option
+                .as_ref()
+                .is_some_and(|response| response.is_pointer_button_down_on())
+            {
+                // A new touch/press cancels any ongoing kinetic scrolling:
+                state.vel = Vec2::ZERO;
+            }
+
             if content_response_option
                 .as_ref()
                 .is_some_and(|response| response.dragged())
@@ -814,15 +910,13 @@ impl ScrollArea {
                         direction_enabled.to_vec2() * ui.input(|input| input.pointer.velocity());
                 }
                 for d in 0..2 {
-                    // Kinetic scrolling
+                    // Kinetic scrolling with frame-rate independent exponential decay:
                     let stop_speed = 20.0; // Pixels per second.
-                    let friction_coeff = 1000.0; // Pixels per second squared.
 
-                    let friction = friction_coeff * dt;
-                    if friction > state.vel[d].abs() || state.vel[d].abs() < stop_speed {
+                    state.vel[d] *= (-momentum_decay * dt).exp();
+                    if state.vel[d].abs() < stop_speed {
                         state.vel[d] = 0.0;
                     } else {
-                        state.vel[d] -= friction * state.vel[d].signum();
                         // Offset has an inverted coordinate system compared to
                         // the velocity, so we subtract it instead of adding it
                         state.offset[d] -= state.vel[d] * dt;
@@ -897,6 +991,7 @@ impl ScrollArea {
             stick_to_end,
             saved_scroll_target,
             animated,
+            offset_at_begin,
         }
     }
 
@@ -928,7 +1023,7 @@ impl ScrollArea {
     /// # });
     /// ```
     pub fn show_rows<R>(
-        self,
+        mut self,
         ui: &mut Ui,
         row_height_sans_spacing: f32,
         total_rows: usize,
@@ -936,9 +1031,19 @@ impl ScrollArea {
     ) -> ScrollAreaOutput<R> {
         let spacing = ui.spacing().item_spacing;
         let row_height_with_spacing = row_height_sans_spacing + spacing.y;
+        let scroll_to_row = self.scroll_to_row.take();
         self.show_viewport(ui, |ui, viewport| {
             ui.set_height((row_height_with_spacing * total_rows as f32 - spacing.y).at_least(0.0));
 
+            if let Some((row, align)) = scroll_to_row {
+                let row_y = ui.max_rect().top() + row as f32 * row_height_with_spacing;
+                let target_rect = Rect::from_x_y_ranges(
+                    ui.max_rect().x_range(),
+                    row_y..=row_y + row_height_sans_spacing,
+                );
+                ui.scroll_to_rect(target_rect, align);
+            }
+
             let mut min_row = (viewport.min.y / row_height_with_spacing).floor() as usize;
             let mut max_row = (viewport.max.y / row_height_with_spacing).ceil() as usize + 1;
             if max_row > total_rows {
@@ -1012,6 +1117,7 @@ impl Prepared {
             stick_to_end,
             saved_scroll_target,
             animated,
+            offset_at_begin,
         } = self;
 
         let content_size = content_ui.min_size();
@@ -1167,7 +1273,9 @@ impl Prepared {
 
         let show_scroll_this_frame = match scroll_bar_visibility {
             ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
-            ScrollBarVisibility::VisibleWhenNeeded => content_is_too_large,
+            ScrollBarVisibility::VisibleWhenNeeded | ScrollBarVisibility::AutoHide { .. } => {
+                content_is_too_large
+            }
             ScrollBarVisibility::AlwaysVisible => direction_enabled,
         };
 
@@ -1181,6 +1289,47 @@ impl Prepared {
 
         let scroll_style = ui.spacing().scroll;
 
+        // For `AutoHide`: fade the bars out after a period of inactivity.
+        let fade_factor = if let ScrollBarVisibility::AutoHide {
+            fade_delay,
+            fade_duration,
+        } = scroll_bar_visibility
+        {
+            let edge_margin = scroll_style.bar_width
+                + scroll_style.bar_inner_margin
+                + scroll_style.bar_outer_margin;
+            let is_hovering_bar_edge = is_hovering_outer_rect
+                && ui.input(|i| i.pointer.latest_pos()).is_some_and(|pos| {
+                    (direction_enabled[0] && outer_rect.bottom() - edge_margin <= pos.y)
+                        || (direction_enabled[1] && outer_rect.right() - edge_margin <= pos.x)
+                });
+
+            let now = ui.input(|i| i.time);
+            if state.offset != offset_at_begin
+                || is_hovering_bar_edge
+                || state.scroll_bar_interaction.any()
+            {
+                state.last_scroll_activity = now;
+            }
+
+            let idle_time = now - state.last_scroll_activity;
+            let visible = idle_time < fade_delay as f64;
+            if visible {
+                // Schedule a repaint for when it is time to start fading out:
+                ui.ctx()
+                    .request_repaint_after(std::time::Duration::from_secs_f64(
+                        fade_delay as f64 - idle_time,
+                    ));
+            }
+            ui.ctx().animate_value_with_time(
+                id.with("bar_fade"),
+                if visible { 1.0 } else { 0.0 },
+                fade_duration,
+            )
+        } else {
+            1.0
+        };
+
         // Paint the bars:
         let scroll_bar_rect = scroll_bar_rect.unwrap_or(inner_rect);
         for d in 0..2 {
@@ -1190,7 +1339,7 @@ impl Prepared {
             }
 
             let show_factor = show_bars_factor[d];
-            if show_factor == 0.0 {
+            if show_factor == 0.0 || fade_factor == 0.0 {
                 state.scroll_bar_interaction[d] = false;
                 continue;
             }
@@ -1411,14 +1560,14 @@ impl Prepared {
                     visuals.corner_radius,
                     ui.visuals()
                         .extreme_bg_color
-                        .gamma_multiply(background_opacity),
+                        .gamma_multiply(background_opacity * fade_factor),
                 ));
 
                 // Handle:
                 ui.painter().add(epaint::Shape::rect_filled(
                     handle_rect,
                     visuals.corner_radius,
-                    handle_color.gamma_multiply(handle_opacity),
+                    handle_color.gamma_multiply(handle_opacity * fade_factor),
                 ));
             }
         }
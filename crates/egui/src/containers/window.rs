@@ -240,7 +240,12 @@ impl<'open> Window<'open> {
 
     /// Constrain the movement of the window to the given rectangle.
     ///
-    /// For instance: `.constrain_to(ctx.screen_rect())`.
+    /// For instance: `.constrain_to(ctx.screen_rect())`,
+    /// or the inner rect of a [`crate::CentralPanel`] to keep a floating
+    /// window from drifting over the side panels.
+    ///
+    /// The constraint is re-applied every frame, so if the rect shrinks
+    /// (e.g. a panel is resized) the window is nudged back inside it.
     #[inline]
     pub fn constrain_to(mut self, constrain_rect: Rect) -> Self {
         self.area = self.area.constrain_to(constrain_rect);
@@ -342,7 +347,33 @@ impl<'open> Window<'open> {
         self
     }
 
-    /// Can the window be collapsed by clicking on its title?
+    /// Should the window shrink back down when its contents shrink?
+    ///
+    /// By default (`false`) a window auto-expands to fit new contents,
+    /// but never shrinks back when the contents get smaller,
+    /// which can leave awkward empty space around dynamic contents.
+    ///
+    /// You can make the window only auto-shrink in one direction by using
+    /// e.g. `[false, true]` as the argument,
+    /// making the window only shrink back in the y-direction.
+    ///
+    /// Once the user resizes the window by dragging, auto-shrinking is disabled,
+    /// just as auto-expanding is.
+    #[inline]
+    pub fn auto_shrink(mut self, auto_shrink: impl Into<Vec2b>) -> Self {
+        self.resize = self.resize.auto_shrink(auto_shrink);
+        self
+    }
+
+    /// Can the window be collapsed to just its title bar,
+    /// by clicking the collapse button or double-clicking the title?
+    ///
+    /// The height change is animated, and the title bar of a collapsed window
+    /// stays interactive (it can still be dragged and closed).
+    /// The collapsed state and the expanded content height are persisted,
+    /// so the window reopens to its prior height.
+    ///
+    /// Default: `true`.
     #[inline]
     pub fn collapsible(mut self, collapsible: bool) -> Self {
         self.collapsible = collapsible;
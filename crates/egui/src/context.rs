@@ -134,6 +134,42 @@ impl Plugins {
 
 // ----------------------------------------------------------------------------
 
+/// State of an ongoing [`Context::set_visuals_animated`] transition.
+#[derive(Clone)]
+struct VisualsAnimation {
+    start_time: f64,
+    duration: f32,
+    from: crate::Visuals,
+    to: crate::Visuals,
+}
+
+impl VisualsAnimation {
+    /// Built-in plugin advancing [`Context::set_visuals_animated`] transitions.
+    fn register(ctx: &Context) {
+        ctx.on_begin_pass("VisualsAnimation", Arc::new(Self::begin_pass));
+    }
+
+    fn begin_pass(ctx: &Context) {
+        // We use `Id::NULL` since there is only one instance of this plugin.
+        let Some(animation) = ctx.data(|d| d.get_temp::<Self>(Id::NULL)) else {
+            return;
+        };
+
+        let time = ctx.input(|i| i.time);
+        let t = ((time - animation.start_time) as f32 / animation.duration).clamp(0.0, 1.0);
+
+        if 1.0 <= t {
+            ctx.set_visuals(animation.to.clone());
+            ctx.data_mut(|d| d.remove::<Self>(Id::NULL));
+        } else {
+            ctx.set_visuals(animation.from.lerp(&animation.to, t));
+            ctx.request_repaint();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Repaint-logic
 impl ContextImpl {
     /// This is where we update the repaint logic.
@@ -405,6 +441,9 @@ struct ContextImpl {
     fonts: std::collections::BTreeMap<OrderedFloat<f32>, Fonts>,
     font_definitions: FontDefinitions,
 
+    /// See [`Context::set_galley_cache_capacity`].
+    galley_cache_capacity: Option<usize>,
+
     memory: Memory,
     animation_manager: AnimationManager,
 
@@ -431,8 +470,15 @@ struct ContextImpl {
 
     paint_stats: PaintStats,
 
+    /// Shortcuts registered with [`Context::register_shortcut`], for conflict detection.
+    registered_shortcuts: Vec<(String, KeyboardShortcut)>,
+
     request_repaint_callback: Option<Box<dyn Fn(RequestRepaintInfo) + Send + Sync>>,
 
+    /// If set, [`Context::open_url`] calls this instead of asking the
+    /// integration to open a browser.
+    open_url_hook: Option<Arc<dyn Fn(crate::OpenUrl) + Send + Sync>>,
+
     viewport_parents: ViewportIdMap<ViewportId>,
     viewports: ViewportIdMap<ViewportState>,
 
@@ -614,6 +660,10 @@ impl ContextImpl {
                 )
             });
 
+        if is_new {
+            fonts.set_galley_cache_capacity(self.galley_cache_capacity);
+        }
+
         {
             profiling::scope!("Fonts::begin_pass");
             fonts.begin_pass(pixels_per_point, max_texture_side);
@@ -759,8 +809,13 @@ impl Default for Context {
 
         // Register built-in plugins:
         crate::debug_text::register(&ctx);
+        #[cfg(not(target_arch = "wasm32"))]
+        crate::font_watcher::register(&ctx);
+        crate::frame_stats::register(&ctx);
+        crate::toasts::register(&ctx);
         crate::text_selection::LabelSelectionState::register(&ctx);
         crate::DragAndDrop::register(&ctx);
+        VisualsAnimation::register(&ctx);
 
         ctx
     }
@@ -1426,6 +1481,35 @@ impl Context {
         crate::debug_text::print(self, text);
     }
 
+    /// Show a toast notification.
+    ///
+    /// The toast will auto-dismiss after its duration has elapsed.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.add_toast(egui::Toast::success("File saved"));
+    /// ```
+    ///
+    /// See [`crate::toasts`] for more.
+    pub fn add_toast(&self, toast: crate::Toast) {
+        self.toasts(|toasts| toasts.add(toast));
+    }
+
+    /// Access the queue of toast notifications, and the options for how to show them.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.toasts(|toasts| {
+    ///     toasts.align = egui::Align2::LEFT_BOTTOM;
+    ///     toasts.add(egui::Toast::warning("Disk almost full"));
+    /// });
+    /// ```
+    ///
+    /// See [`crate::toasts`] for more.
+    pub fn toasts<R>(&self, writer: impl FnOnce(&mut crate::toasts::Toasts) -> R) -> R {
+        self.data_mut(|data| writer(data.get_temp_mut_or_default(Id::NULL)))
+    }
+
     /// What operating system are we running on?
     ///
     /// When compiling natively, this is
@@ -1447,13 +1531,29 @@ impl Context {
 
     /// Set the cursor icon.
     ///
-    /// Equivalent to:
-    /// ```
-    /// # let ctx = egui::Context::default();
-    /// ctx.output_mut(|o| o.cursor_icon = egui::CursorIcon::PointingHand);
-    /// ```
+    /// This is a request with priority zero:
+    /// if several widgets request a cursor icon this frame, the last one wins,
+    /// unless someone used [`Self::set_cursor_icon_with_priority`] with a higher priority.
     pub fn set_cursor_icon(&self, cursor_icon: CursorIcon) {
-        self.output_mut(|o| o.cursor_icon = cursor_icon);
+        self.set_cursor_icon_with_priority(cursor_icon, 0);
+    }
+
+    /// Set the cursor icon, unless a request with a higher priority was already made this frame.
+    ///
+    /// Use this when several overlapping regions compete for the cursor,
+    /// e.g. so that a resize-handle's [`CursorIcon::ResizeHorizontal`]
+    /// beats the [`CursorIcon::Default`] of the background behind it,
+    /// regardless of widget order.
+    ///
+    /// Among requests of equal priority the last one wins.
+    /// [`Self::set_cursor_icon`] requests with priority zero.
+    pub fn set_cursor_icon_with_priority(&self, cursor_icon: CursorIcon, priority: u32) {
+        self.output_mut(|o| {
+            if o.cursor_icon_priority <= priority {
+                o.cursor_icon = cursor_icon;
+                o.cursor_icon_priority = priority;
+            }
+        });
     }
 
     /// Add a command to [`PlatformOutput::commands`],
@@ -1464,6 +1564,9 @@ impl Context {
 
     /// Open an URL in a browser.
     ///
+    /// If a hook has been installed with [`Self::set_open_url_hook`],
+    /// the url is passed to it instead.
+    ///
     /// Equivalent to:
     /// ```
     /// # let ctx = egui::Context::default();
@@ -1471,7 +1574,36 @@ impl Context {
     /// ctx.output_mut(|o| o.open_url = Some(open_url));
     /// ```
     pub fn open_url(&self, open_url: crate::OpenUrl) {
-        self.send_cmd(crate::OutputCommand::OpenUrl(open_url));
+        let hook = self.read(|ctx| ctx.open_url_hook.clone());
+        if let Some(hook) = hook {
+            // Call the hook after releasing the lock,
+            // so that it is free to call back into the context:
+            hook(open_url);
+        } else {
+            self.send_cmd(crate::OutputCommand::OpenUrl(open_url));
+        }
+    }
+
+    /// Intercept URLs that egui is asked to open, e.g. by a clicked [`crate::Hyperlink`].
+    ///
+    /// When set, the hook receives the [`crate::OpenUrl`] instead of the
+    /// integration opening a browser. [`crate::OpenUrl::new_tab`] tells you
+    /// whether the user signaled new-tab intent (e.g. by holding Ctrl/Cmd
+    /// or middle-clicking).
+    ///
+    /// Useful for sandboxed apps and apps with custom navigation.
+    /// Use [`Self::clear_open_url_hook`] to restore the default behavior.
+    ///
+    /// The hook is called without holding any [`Context`] locks,
+    /// so it is fine to call back into the context from it.
+    pub fn set_open_url_hook(&self, hook: impl Fn(crate::OpenUrl) + Send + Sync + 'static) {
+        self.write(|ctx| ctx.open_url_hook = Some(Arc::new(hook)));
+    }
+
+    /// Remove the hook set by [`Self::set_open_url_hook`],
+    /// restoring the default behavior of opening a browser.
+    pub fn clear_open_url_hook(&self) {
+        self.write(|ctx| ctx.open_url_hook = None);
     }
 
     /// Copy the given text to the system clipboard.
@@ -1527,6 +1659,48 @@ impl Context {
         }
     }
 
+    /// Register a named keyboard shortcut, for conflict detection.
+    ///
+    /// Registering is optional, and does not change how the shortcut behaves —
+    /// it only makes it visible to [`Self::shortcut_conflicts`],
+    /// so that large applications can check their keymap for accidental double-bindings.
+    ///
+    /// Registering the same name again replaces the previous binding,
+    /// so it is fine to call this every frame.
+    pub fn register_shortcut(&self, name: impl Into<String>, shortcut: KeyboardShortcut) {
+        let name = name.into();
+        self.write(|ctx| {
+            if let Some((_, existing)) = ctx
+                .registered_shortcuts
+                .iter_mut()
+                .find(|(existing_name, _)| *existing_name == name)
+            {
+                *existing = shortcut;
+            } else {
+                ctx.registered_shortcuts.push((name, shortcut));
+            }
+        });
+    }
+
+    /// All pairs of shortcuts registered with [`Self::register_shortcut`]
+    /// that are bound to the same key combination.
+    ///
+    /// Returns the two names and the combination they share.
+    /// Empty if there are no conflicts.
+    pub fn shortcut_conflicts(&self) -> Vec<(String, String, KeyboardShortcut)> {
+        self.read(|ctx| {
+            let mut conflicts = vec![];
+            for (i, (a_name, a)) in ctx.registered_shortcuts.iter().enumerate() {
+                for (b_name, b) in &ctx.registered_shortcuts[i + 1..] {
+                    if a == b {
+                        conflicts.push((a_name.clone(), b_name.clone(), *a));
+                    }
+                }
+            }
+            conflicts
+        })
+    }
+
     /// The total number of completed passes (usually there is one pass per rendered frame).
     ///
     /// Starts at zero, and is incremented for each completed pass inside of [`Self::run`] (usually once).
@@ -1545,6 +1719,33 @@ impl Context {
         })
     }
 
+    /// Statistics about the most recent frame: frame time, pass count,
+    /// and how many shapes/vertices were tessellated.
+    ///
+    /// The underlying instrumentation is always on and cheap,
+    /// so this is fine to call in release builds.
+    ///
+    /// See also [`Self::show_performance_overlay`] for a ready-made on-screen display.
+    pub fn frame_stats(&self) -> crate::frame_stats::FrameStats {
+        crate::frame_stats::FrameStats {
+            frame_time: self.input(|i| i.stable_dt),
+            cumulative_pass_nr: self.cumulative_pass_nr(),
+            paint_stats: self.read(|ctx| ctx.paint_stats),
+        }
+    }
+
+    /// Show or hide a small built-in performance overlay,
+    /// painted on [`crate::Order::Debug`] in the top-right corner of the screen.
+    ///
+    /// It displays the mean frame time with a compact graph,
+    /// the pass count, and the number of shapes and vertices
+    /// (see [`Self::frame_stats`]).
+    ///
+    /// Off by default. Cheap enough to leave on in release builds.
+    pub fn show_performance_overlay(&self, show: bool) {
+        crate::frame_stats::set_enabled(self, show);
+    }
+
     /// Call this if there is need to repaint the UI, i.e. if you are showing an animation.
     ///
     /// If this is called at least once in a frame, then there will be another frame right after this.
@@ -1580,6 +1781,23 @@ impl Context {
         self.write(|ctx| ctx.request_repaint(id, cause));
     }
 
+    /// Like [`Self::request_repaint`], but also records a human-readable reason.
+    ///
+    /// The reason shows up (together with the callsite file and line number)
+    /// in [`Self::repaint_causes`], which is helpful when debugging
+    /// why an app keeps repainting while seemingly idle.
+    ///
+    /// Recording a cause is cheap (a `&'static str` is stored as-is),
+    /// so this is fine to call every frame.
+    #[track_caller]
+    pub fn request_repaint_with_reason(&self, reason: impl Into<Cow<'static, str>>) {
+        let cause = RepaintCause::new_reason(reason);
+        self.write(|ctx| {
+            let viewport_id = ctx.viewport_id();
+            ctx.request_repaint(viewport_id, cause);
+        });
+    }
+
     /// Request repaint after at most the specified duration elapses.
     ///
     /// The backend can chose to repaint sooner, for instance if some other code called
@@ -1657,6 +1875,24 @@ impl Context {
         self.write(|ctx| ctx.request_repaint_after(duration, id, cause));
     }
 
+    /// Like [`Self::request_repaint_after`], but also records a human-readable reason.
+    ///
+    /// The reason shows up (together with the callsite file and line number)
+    /// in [`Self::repaint_causes`], which is helpful when debugging
+    /// why an app keeps repainting while seemingly idle.
+    #[track_caller]
+    pub fn request_repaint_after_with_reason(
+        &self,
+        duration: Duration,
+        reason: impl Into<Cow<'static, str>>,
+    ) {
+        let cause = RepaintCause::new_reason(reason);
+        self.write(|ctx| {
+            let viewport_id = ctx.viewport_id();
+            ctx.request_repaint_after(duration, viewport_id, cause);
+        });
+    }
+
     /// Was a repaint requested last pass for the current viewport?
     #[must_use]
     pub fn requested_repaint_last_pass(&self) -> bool {
@@ -1815,6 +2051,42 @@ impl Context {
         }
     }
 
+    /// The currently active [`FontDefinitions`].
+    ///
+    /// Note that changes queued with [`Self::set_fonts`] or [`Self::add_font`]
+    /// only show up here at the start of the next pass.
+    pub(crate) fn font_definitions(&self) -> FontDefinitions {
+        self.read(|ctx| ctx.font_definitions.clone())
+    }
+
+    /// Limit how many text galleys are kept in the layout cache.
+    ///
+    /// If `Some(n)`, the `n` most recently used galleys are kept,
+    /// and the least recently used ones beyond that are evicted.
+    /// If `None` (the default), galleys that went unused for a frame are evicted.
+    ///
+    /// Use this to bound the memory used by the cache in long-running apps
+    /// that lay out a lot of unique text (e.g. logs or chat),
+    /// especially on embedded and wasm targets.
+    ///
+    /// See also [`Self::galley_cache_stats`].
+    pub fn set_galley_cache_capacity(&self, capacity: Option<usize>) {
+        self.write(|ctx| {
+            ctx.galley_cache_capacity = capacity;
+            for fonts in ctx.fonts.values() {
+                fonts.set_galley_cache_capacity(capacity);
+            }
+        });
+    }
+
+    /// The current size of the text layout cache.
+    ///
+    /// Only counts the cache for the current `pixels_per_point`.
+    /// Not valid until the first call to [`Context::run`].
+    pub fn galley_cache_stats(&self) -> epaint::text::GalleyCacheStats {
+        self.fonts(|f| f.galley_cache_stats())
+    }
+
     /// Tell `egui` which fonts to use.
     ///
     /// The default `egui` fonts only support latin and cyrillic alphabets,
@@ -1982,6 +2254,35 @@ impl Context {
         self.style_mut_of(self.theme(), |style| style.visuals = visuals);
     }
 
+    /// Like [`Self::set_visuals`], but smoothly blend from the current visuals
+    /// to the given ones over `duration` seconds.
+    ///
+    /// All colors and other interpolatable values are blended each frame
+    /// with [`crate::Visuals::lerp`], and repaints are requested until the
+    /// transition is done. Fields that cannot be interpolated
+    /// (booleans, enums, …) switch at the midpoint.
+    ///
+    /// Useful for a pleasant light/dark theme toggle:
+    ///
+    /// ```
+    /// # let mut ctx = egui::Context::default();
+    /// ctx.set_visuals_animated(egui::Visuals::light(), 0.3);
+    /// ```
+    pub fn set_visuals_animated(&self, visuals: crate::Visuals, duration: f32) {
+        if duration <= 0.0 {
+            self.set_visuals(visuals);
+            return;
+        }
+        let animation = VisualsAnimation {
+            start_time: self.input(|i| i.time),
+            duration,
+            from: self.style().visuals.clone(),
+            to: visuals,
+        };
+        self.data_mut(|d| d.insert_temp(Id::NULL, animation));
+        self.request_repaint();
+    }
+
     /// The number of physical pixels for each logical point.
     ///
     /// This is calculated as [`Self::zoom_factor`] * [`Self::native_pixels_per_point`]
@@ -2094,7 +2395,7 @@ impl Context {
         options: TextureOptions,
     ) -> TextureHandle {
         let name = name.into();
-        let image = image.into();
+        let mut image = image.into();
         let max_texture_side = self.input(|i| i.max_texture_side);
         debug_assert!(
             image.width() <= max_texture_side && image.height() <= max_texture_side,
@@ -2104,6 +2405,22 @@ impl Context {
             image.height(),
             max_texture_side
         );
+        if max_texture_side < image.width() || max_texture_side < image.height() {
+            // Downscale rather than letting the texture upload fail in the painter.
+            // Mostly relevant in release builds, where the above assert is compiled out.
+            if let ImageData::Color(color_image) = &image {
+                log::warn!(
+                    "Texture {:?} has size {}x{}, but the maximum texture side is {} - downscaling it to fit",
+                    name,
+                    image.width(),
+                    image.height(),
+                    max_texture_side
+                );
+                image = ImageData::Color(Arc::new(color_image.downscale_to_fit(max_texture_side)));
+            }
+            // The font atlas (`ImageData::Font`) is already limited to `max_texture_side`
+            // by `Fonts::begin_pass`.
+        }
         let tex_mngr = self.tex_manager();
         let tex_id = tex_mngr.write().alloc(name, image, options);
         TextureHandle::new(tex_mngr, tex_id)
@@ -2564,6 +2881,72 @@ impl Context {
         })
     }
 
+    /// Tessellate the given shapes into triangle meshes,
+    /// streaming each finished [`ClippedPrimitive`] to `emit`.
+    ///
+    /// This is an opt-in alternative to [`Self::tessellate`] for integrations that want
+    /// to overlap tessellation with other work, e.g. uploading the finished meshes to
+    /// the GPU. Use it in place of [`Self::tessellate`] on the shapes of a
+    /// [`FullOutput`]; [`Self::run`] and [`Self::end_pass`] are unaffected.
+    ///
+    /// ## Ordering guarantees
+    /// * Primitives are emitted in paint order (back to front),
+    ///   in exactly the same order as [`Self::tessellate`] returns them.
+    /// * The shapes of one layer come before those of any layer above it
+    ///   (see [`crate::Order`]), so all primitives of a layer are emitted
+    ///   before the first primitive of the next.
+    /// * A primitive is emitted as soon as no later shape can be batched into it,
+    ///   which happens when a shape with a different clip rectangle or texture follows it.
+    /// * All remaining primitives are emitted before this function returns.
+    ///
+    /// `emit` is called on the calling thread without holding any [`Context`] locks,
+    /// so it is fine to call back into the context from it.
+    pub fn tessellate_streaming(
+        &self,
+        shapes: Vec<ClippedShape>,
+        pixels_per_point: f32,
+        emit: &mut dyn FnMut(ClippedPrimitive),
+    ) {
+        profiling::function_scope!();
+
+        let (tessellation_options, font_tex_size, prepared_discs) = self.write(|ctx| {
+            let tessellation_options = ctx.memory.options.tessellation_options;
+            let texture_atlas = if let Some(fonts) = ctx.fonts.get(&pixels_per_point.into()) {
+                fonts.texture_atlas()
+            } else {
+                #[cfg(feature = "log")]
+                log::warn!("No font size matching {pixels_per_point} pixels per point found.");
+                ctx.fonts
+                    .iter()
+                    .next()
+                    .expect("No fonts loaded")
+                    .1
+                    .texture_atlas()
+            };
+            let (font_tex_size, prepared_discs) = {
+                let atlas = texture_atlas.lock();
+                (atlas.size(), atlas.prepared_discs())
+            };
+            (tessellation_options, font_tex_size, prepared_discs)
+        });
+
+        let mut paint_stats = PaintStats::from_shapes(&shapes);
+        {
+            profiling::scope!("tessellator::tessellate_shapes_with");
+            tessellator::Tessellator::new(
+                pixels_per_point,
+                tessellation_options,
+                font_tex_size,
+                prepared_discs,
+            )
+            .tessellate_shapes_with(shapes, &mut |clipped_primitive| {
+                paint_stats.add_clipped_primitive(&clipped_primitive);
+                emit(clipped_primitive);
+            });
+        }
+        self.write(|ctx| ctx.paint_stats = paint_stats);
+    }
+
     // ---------------------------------------------------------------------
 
     /// Position and size of the egui area.
@@ -2773,6 +3156,40 @@ impl Context {
         self.memory(|mem| mem.layer_id_at(pos))
     }
 
+    /// Was there a primary press (or touch) outside the given rect this frame?
+    ///
+    /// This is useful for dismiss-on-outside-click logic
+    /// for custom popups that don't use the built-in popup system.
+    /// `layer_id` should be the layer the rect is on (e.g. the popup layer):
+    /// presses landing on a layer above it (e.g. a window covering the rect)
+    /// are ignored, since they are consumed by that layer.
+    ///
+    /// See also [`crate::Response::clicked_elsewhere`]
+    /// for the rect of an existing widget.
+    pub fn clicked_outside_rect(&self, layer_id: LayerId, rect: Rect) -> bool {
+        let press_pos = self.input(|i| {
+            i.pointer
+                .primary_pressed()
+                .then(|| i.pointer.press_origin())
+                .flatten()
+        });
+        let Some(pos) = press_pos else {
+            return false;
+        };
+        if rect.contains(pos) {
+            return false;
+        }
+        if let Some(layer_under_pointer) = self.layer_id_at(pos) {
+            if layer_under_pointer != layer_id
+                && self.memory(|mem| mem.areas().compare_order(layer_id, layer_under_pointer))
+                    == std::cmp::Ordering::Less
+            {
+                return false; // The press was consumed by a layer above us.
+            }
+        }
+        true
+    }
+
     /// Moves the given area to the top in its [`Order`].
     ///
     /// [`crate::Area`]:s and [`crate::Window`]:s also do this automatically when being clicked on or interacted with.
@@ -2930,12 +3347,33 @@ impl Context {
     /// When it is called with a new value, it linearly interpolates to it in the given time.
     #[track_caller] // To track repaint cause
     pub fn animate_value_with_time(&self, id: Id, target_value: f32, animation_time: f32) -> f32 {
+        self.animate_value_with_time_and_easing(
+            id,
+            target_value,
+            animation_time,
+            emath::easing::linear,
+        )
+    }
+
+    /// Like [`Self::animate_value_with_time`] but allows you to control the easing function.
+    ///
+    /// Use e.g. [`emath::easing::cubic_out`] for a responsive start and a slow end,
+    /// or pick a function from [`emath::easing::Easing`] with [`emath::easing::Easing::function`].
+    #[track_caller] // To track repaint cause
+    pub fn animate_value_with_time_and_easing(
+        &self,
+        id: Id,
+        target_value: f32,
+        animation_time: f32,
+        easing: fn(f32) -> f32,
+    ) -> f32 {
         let animated_value = self.write(|ctx| {
             ctx.animation_manager.animate_value(
                 &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
                 animation_time,
                 id,
                 target_value,
+                easing,
             )
         });
         let animation_in_progress = animated_value != target_value;
@@ -2952,6 +3390,61 @@ impl Context {
     }
 }
 
+/// ## Undo/redo
+impl Context {
+    /// A shared undo/redo stack for arbitrary state, keyed by `id`.
+    ///
+    /// This is the same undo system that [`crate::TextEdit`] uses internally,
+    /// made available for any widget state.
+    /// Feed it a snapshot of your state every frame with
+    /// [`crate::util::undoer::Undoer::feed_state`]
+    /// and it will coalesce rapid changes into undo points.
+    /// The maximum depth and the time-based coalescing are set by
+    /// [`crate::util::undoer::Settings`]; to change them,
+    /// replace the stack before feeding it any state:
+    /// `*ctx.undoer(id).lock() = Undoer::with_settings(settings);`.
+    ///
+    /// The stack is kept in temporary (non-persisted) memory.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut text = String::new();
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    ///
+    /// let undoer = ui.ctx().undoer::<String>(ui.id().with("undo"));
+    /// let mut undoer = undoer.lock();
+    ///
+    /// let time = ui.input(|i| i.time);
+    /// undoer.feed_state(time, &text);
+    ///
+    /// let undo = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+    /// let redo = KeyboardShortcut::new(Modifiers::COMMAND, Key::Y);
+    ///
+    /// if ui.input_mut(|i| i.consume_shortcut(&undo)) {
+    ///     if let Some(previous) = undoer.undo(&text) {
+    ///         text = previous.clone();
+    ///     }
+    /// } else if ui.input_mut(|i| i.consume_shortcut(&redo)) {
+    ///     if let Some(next) = undoer.redo(&text) {
+    ///         text = next.clone();
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn undoer<State>(
+        &self,
+        id: Id,
+    ) -> Arc<crate::mutex::Mutex<crate::util::undoer::Undoer<State>>>
+    where
+        State: Clone + PartialEq + Send + Sync + 'static,
+    {
+        self.data_mut(|d| {
+            d.get_temp_mut_or_default::<Arc<crate::mutex::Mutex<crate::util::undoer::Undoer<State>>>>(id)
+                .clone()
+        })
+    }
+}
+
 impl Context {
     /// Show a ui for settings (style and tessellation options).
     pub fn settings_ui(&self, ui: &mut Ui) {
@@ -3102,10 +3595,7 @@ impl Context {
         let mut textures: Vec<_> = tex_mngr.allocated().collect();
         textures.sort_by_key(|(id, _)| *id);
 
-        let mut bytes = 0;
-        for (_, tex) in &textures {
-            bytes += tex.bytes_used();
-        }
+        let bytes = tex_mngr.bytes_used();
 
         ui.label(format!(
             "{} allocated texture(s), using {:.1} MB",
@@ -352,7 +352,7 @@ impl ViewportInfo {
 }
 
 /// A file about to be dropped into egui.
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct HoveredFile {
     /// Set by the `egui-winit` backend.
@@ -360,6 +360,15 @@ pub struct HoveredFile {
 
     /// With the `eframe` web backend, this is set to the mime-type of the file (if available).
     pub mime: String,
+
+    /// Pointer position at the time of the hover, in ui points,
+    /// so you can highlight the drop target under the cursor.
+    ///
+    /// With the `eframe` web backend this is the `dragover` position.
+    /// On native this is the last known pointer position;
+    /// some platforms don't report pointer movement during a drag,
+    /// in which case this is `None`.
+    pub position: Option<Pos2>,
 }
 
 /// A file dropped into egui.
@@ -397,6 +406,12 @@ pub enum Event {
     /// The integration detected a "paste" event (e.g. Cmd+V).
     Paste(String),
 
+    /// The integration detected a "paste" event (e.g. Cmd+V) while the clipboard held an image,
+    /// e.g. a screenshot.
+    ///
+    /// Only sent by integrations on platforms that support image clipboards.
+    PasteImage(crate::ColorImage),
+
     /// Text input, e.g. via keyboard.
     ///
     /// When the user presses enter/return, do not send a [`Text`](Event::Text) (just [`Key::Enter`]).
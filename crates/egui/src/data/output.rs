@@ -111,6 +111,13 @@ pub struct PlatformOutput {
     /// Set the cursor to this icon.
     pub cursor_icon: CursorIcon,
 
+    /// The priority of the current [`Self::cursor_icon`].
+    ///
+    /// Set with [`crate::Context::set_cursor_icon_with_priority`];
+    /// requests with a lower priority than this are ignored.
+    /// Reset to zero at the start of each frame.
+    pub cursor_icon_priority: u32,
+
     /// If set, open this url.
     #[deprecated = "Use `Context::open_url` or `PlatformOutput::commands` instead"]
     pub open_url: Option<OpenUrl>,
@@ -190,6 +197,7 @@ impl PlatformOutput {
         let Self {
             mut commands,
             cursor_icon,
+            cursor_icon_priority,
             open_url,
             copied_text,
             mut events,
@@ -202,7 +210,10 @@ impl PlatformOutput {
         } = newer;
 
         self.commands.append(&mut commands);
-        self.cursor_icon = cursor_icon;
+        if self.cursor_icon_priority <= cursor_icon_priority {
+            self.cursor_icon = cursor_icon;
+            self.cursor_icon_priority = cursor_icon_priority;
+        }
         if open_url.is_some() {
             self.open_url = open_url;
         }
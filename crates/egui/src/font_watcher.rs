@@ -0,0 +1,204 @@
+//! Hot-reload fonts from a directory.
+//!
+//! Call [`Context::watch_fonts_dir`] with a directory of `.ttf`/`.otf` files,
+//! and egui will poll it for changes, reinstalling the fonts
+//! (and thereby rebuilding the font atlas and invalidating cached galleys)
+//! whenever a font file is added, removed, or modified.
+//!
+//! Native only.
+
+use std::{collections::BTreeMap, path::PathBuf, sync::Arc, time::SystemTime};
+
+use epaint::text::{FontData, FontFamily};
+
+use crate::{Context, Id};
+
+/// How often we poll the watched directory for changes, in seconds.
+const SCAN_INTERVAL: f64 = 1.0;
+
+/// Register this plugin on the given egui context,
+/// so that it will be called every pass.
+///
+/// This is a built-in plugin in egui,
+/// meaning [`Context`] calls this from its `Default` implementation,
+/// so this is marked as `pub(crate)`.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_begin_pass("font_watcher", Arc::new(FontWatcher::begin_pass));
+}
+
+/// One entry per watched font file, used to detect changes.
+type DirFingerprint = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// State of the font hot-reload plugin.
+///
+/// Set up with [`Context::watch_fonts_dir`].
+#[derive(Clone, Default)]
+struct FontWatcher {
+    /// The watched directory, or `None` if watching is disabled.
+    dir: Option<PathBuf>,
+
+    /// Modification times and sizes of the font files found in the last scan.
+    fingerprint: DirFingerprint,
+
+    /// Names of the fonts we have installed, so we can replace them on reload.
+    installed: Vec<String>,
+
+    /// When did we last scan the directory?
+    last_scan_time: f64,
+
+    /// Used to avoid logging the same scan failure every [`SCAN_INTERVAL`].
+    warned_about_dir: bool,
+}
+
+impl FontWatcher {
+    fn begin_pass(ctx: &Context) {
+        let Some(mut state) = ctx.data(|data| data.get_temp::<Self>(Id::NULL)) else {
+            return; // Plugin not in use
+        };
+        let Some(dir) = state.dir.clone() else {
+            return; // Watching has been disabled
+        };
+
+        let time = ctx.input(|i| i.time);
+        if time - state.last_scan_time < SCAN_INTERVAL {
+            return;
+        }
+        state.last_scan_time = time;
+
+        match scan_dir(&dir) {
+            Ok(fingerprint) => {
+                state.warned_about_dir = false;
+                if fingerprint != state.fingerprint {
+                    state.installed = install_fonts(ctx, &fingerprint, &state.installed);
+                    state.fingerprint = fingerprint;
+                }
+            }
+            Err(err) => {
+                if !state.warned_about_dir {
+                    log::warn!("Failed to scan watched fonts directory {dir:?}: {err}");
+                    state.warned_about_dir = true;
+                }
+            }
+        }
+
+        ctx.data_mut(|data| data.insert_temp(Id::NULL, state));
+    }
+}
+
+/// List the font files in the given directory, with their modification times and sizes.
+fn scan_dir(dir: &std::path::Path) -> std::io::Result<DirFingerprint> {
+    let mut fingerprint = DirFingerprint::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_font_file = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("ttf") || ext.eq_ignore_ascii_case("otf"));
+        if is_font_file {
+            let metadata = std::fs::metadata(&path)?;
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            fingerprint.insert(path, (modified, metadata.len()));
+        }
+    }
+    Ok(fingerprint)
+}
+
+/// Replace the previously installed fonts with the given font files.
+///
+/// Returns the names of the newly installed fonts.
+/// Malformed font files are logged and skipped, keeping whatever was installed before.
+fn install_fonts(ctx: &Context, fingerprint: &DirFingerprint, previous: &[String]) -> Vec<String> {
+    let mut definitions = ctx.font_definitions();
+
+    for name in previous {
+        definitions.font_data.remove(name);
+        definitions
+            .families
+            .remove(&FontFamily::Name(name.as_str().into()));
+        for fonts in definitions.families.values_mut() {
+            fonts.retain(|font_name| font_name != name);
+        }
+    }
+
+    let mut installed = vec![];
+
+    for path in fingerprint.keys() {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                log::warn!("Failed to read font file {path:?}: {err}");
+                continue;
+            }
+        };
+
+        let font_data = FontData::from_owned(bytes);
+        if let Err(err) = font_data.validate() {
+            log::warn!("Ignoring malformed font file {path:?}: {err}");
+            continue;
+        }
+
+        let Some(name) = path.file_stem().map(|stem| stem.to_string_lossy()) else {
+            continue;
+        };
+        let name = name.to_string();
+
+        definitions
+            .font_data
+            .insert(name.clone(), Arc::new(font_data));
+        definitions
+            .families
+            .insert(FontFamily::Name(name.as_str().into()), vec![name.clone()]);
+
+        installed.push(name);
+    }
+
+    // Make the watched fonts take effect without any extra configuration:
+    // the first one (alphabetically) becomes the primary proportional font.
+    for name in installed.iter().rev() {
+        let proportional = definitions
+            .families
+            .entry(FontFamily::Proportional)
+            .or_default();
+        proportional.insert(0, name.clone());
+    }
+
+    ctx.set_fonts(definitions);
+    ctx.request_repaint(); // Make sure the new fonts are shown
+
+    installed
+}
+
+impl Context {
+    /// Hot-reload fonts from the given directory (native only).
+    ///
+    /// The directory is polled about once per second for `.ttf` and `.otf` files.
+    /// Whenever a font file is added, removed, or modified,
+    /// the fonts are reinstalled with [`Self::set_fonts`],
+    /// which rebuilds the font atlas and invalidates all cached text layout.
+    ///
+    /// Each watched font is registered under its file stem as a [`FontFamily::Name`],
+    /// and is also inserted as the highest-priority [`FontFamily::Proportional`] font
+    /// so that dropping a font file into the directory has a visible effect.
+    /// Malformed font files are logged and skipped, keeping the previous fonts.
+    ///
+    /// This is meant as a development tool, e.g. for iterating on a theme -
+    /// you probably don't want to ship it enabled.
+    pub fn watch_fonts_dir(&self, dir: impl Into<PathBuf>) {
+        let dir = dir.into();
+        self.data_mut(|data| {
+            let state = data.get_temp_mut_or_default::<FontWatcher>(Id::NULL);
+            *state = FontWatcher {
+                dir: Some(dir),
+                ..Default::default()
+            };
+        });
+    }
+
+    /// Stop watching the directory given to [`Self::watch_fonts_dir`].
+    ///
+    /// The fonts installed by the watcher remain active.
+    pub fn stop_watching_fonts_dir(&self) {
+        self.data_mut(|data| {
+            data.remove::<FontWatcher>(Id::NULL);
+        });
+    }
+}
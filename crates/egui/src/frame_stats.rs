@@ -0,0 +1,145 @@
+//! An opt-in performance overlay, and access to per-frame statistics.
+//!
+//! This is a built-in plugin, following the same pattern as [`crate::debug_text`]:
+//! its state is stored with [`crate::Context::data_mut`] and it is called at the end of each pass.
+//! Toggle it with [`crate::Context::show_performance_overlay`].
+
+use epaint::stats::PaintStats;
+
+use crate::{
+    pos2, util::History, vec2, Color32, Context, FontFamily, FontId, Id, Rect, Shape, Stroke,
+};
+
+/// Statistics about the most recent frame.
+///
+/// Returned by [`Context::frame_stats`].
+///
+/// The instrumentation behind this is always on and cheap
+/// (the paint statistics are counted during tessellation anyway),
+/// so it is fine to query this in release builds.
+#[derive(Clone, Copy, Default)]
+pub struct FrameStats {
+    /// An estimate of the time between this frame and the previous one, in seconds.
+    ///
+    /// Same as [`crate::InputState::stable_dt`].
+    pub frame_time: f32,
+
+    /// How many passes has been run since the creation of the [`Context`]?
+    ///
+    /// Since this increases with every repaint, you can use it as a repaint counter.
+    pub cumulative_pass_nr: u64,
+
+    /// Number of shapes, vertices, indices etc from the latest call to [`Context::tessellate`].
+    pub paint_stats: PaintStats,
+}
+
+/// Register this plugin on the given egui context,
+/// so that it will be called every pass.
+///
+/// This is a built-in plugin in egui,
+/// meaning [`Context`] calls this from its `Default` implementation,
+/// so this is marked as `pub(crate)`.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_end_pass("performance_overlay", std::sync::Arc::new(State::end_pass));
+}
+
+/// Show or hide the overlay.
+pub(crate) fn set_enabled(ctx: &Context, enabled: bool) {
+    ctx.data_mut(|data| {
+        if enabled {
+            // We use `Id::NULL` as the id, since we only have one instance of this plugin.
+            data.get_temp_mut_or_default::<State>(Id::NULL);
+        } else {
+            data.remove_temp::<State>(Id::NULL);
+        }
+    });
+}
+
+/// The state of the performance overlay.
+///
+/// Present in the temporary memory only while the overlay is enabled.
+#[derive(Clone)]
+struct State {
+    frame_times: History<f32>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            // Keep roughly the last second of frame times:
+            frame_times: History::new(2..120, 1.0),
+        }
+    }
+}
+
+impl State {
+    fn end_pass(ctx: &Context) {
+        let state = ctx.data_mut(|data| data.get_temp::<Self>(Id::NULL));
+        let Some(mut state) = state else {
+            return; // The overlay is off.
+        };
+
+        let (now, dt) = ctx.input(|i| (i.time, i.stable_dt));
+        state.frame_times.add(now, dt);
+        state.paint(ctx);
+
+        ctx.data_mut(|data| data.insert_temp(Id::NULL, state));
+    }
+
+    fn paint(&self, ctx: &Context) {
+        let stats = ctx.frame_stats();
+        let painter = ctx.debug_painter();
+
+        let font_id = FontId::new(10.0, FontFamily::Monospace);
+        let color = Color32::GRAY;
+        let row_height = 12.0;
+        let graph_height = 24.0;
+        let width = 140.0;
+
+        let screen_rect = ctx.screen_rect();
+        let rect = Rect::from_min_size(
+            pos2(screen_rect.right() - width - 8.0, screen_rect.top() + 8.0),
+            vec2(width, 4.0 * row_height + graph_height + 8.0),
+        );
+
+        painter.add(Shape::rect_filled(
+            rect.expand(4.0),
+            2.0,
+            Color32::from_black_alpha(192),
+        ));
+
+        let mean_frame_time = self.frame_times.average().unwrap_or_default();
+        let lines = [
+            format!("{:6.2} ms/frame", 1e3 * mean_frame_time),
+            format!("{:6} passes", stats.cumulative_pass_nr),
+            format!("{:6} shapes", stats.paint_stats.shapes.num_elements()),
+            format!("{:6} vertices", stats.paint_stats.vertices.num_elements()),
+        ];
+
+        let mut pos = rect.min;
+        for line in lines {
+            painter.text(pos, crate::Align2::LEFT_TOP, line, font_id.clone(), color);
+            pos.y += row_height;
+        }
+
+        // A compact frame-time graph, newest to the right:
+        let graph_rect =
+            Rect::from_min_size(pos2(rect.left(), pos.y + 4.0), vec2(width, graph_height));
+        let max_dt = self.frame_times.values().fold(1.0 / 30.0, f32::max);
+        let now = ctx.input(|i| i.time);
+        let max_age = self.frame_times.max_age();
+        let points: Vec<_> = self
+            .frame_times
+            .iter()
+            .map(|(time, dt)| {
+                let age = (now - time) as f32;
+                let x = crate::remap(age, max_age..=0.0, graph_rect.x_range());
+                let y = crate::remap_clamp(dt, 0.0..=max_dt, graph_rect.bottom_up_range());
+                pos2(x, y)
+            })
+            .collect();
+        if points.len() >= 2 {
+            painter.add(Shape::line(points, Stroke::new(1.0, color)));
+        }
+    }
+}
@@ -78,7 +78,9 @@ pub(crate) struct GridLayout {
     spacing: Vec2,
     min_cell_size: Vec2,
     max_cell_size: Vec2,
+    max_col_widths: Vec<f32>,
     color_picker: Option<ColorPickerFn>,
+    hover_highlight: bool,
 
     // Cursor:
     col: usize,
@@ -113,7 +115,9 @@ impl GridLayout {
             spacing: ui.spacing().item_spacing,
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
+            max_col_widths: Vec::new(),
             color_picker: None,
+            hover_highlight: false,
 
             col: 0,
             row: 0,
@@ -134,8 +138,16 @@ impl GridLayout {
             .unwrap_or(self.min_cell_size.y)
     }
 
+    /// Maximum width of the given column, considering any per-column override.
+    fn max_col_width(&self, col: usize) -> f32 {
+        self.max_col_widths
+            .get(col)
+            .copied()
+            .unwrap_or(self.max_cell_size.x)
+    }
+
     pub(crate) fn wrap_text(&self) -> bool {
-        self.max_cell_size.x.is_finite()
+        self.max_col_width(self.col).is_finite()
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
@@ -150,11 +162,11 @@ impl GridLayout {
                     .unwrap_or(self.min_cell_size.x)
             } else {
                 (self.initial_available.right() - region.cursor.left())
-                    .at_most(self.max_cell_size.x)
+                    .at_most(self.max_col_width(self.col))
             }
-        } else if self.max_cell_size.x.is_finite() {
+        } else if self.max_col_width(self.col).is_finite() {
             // TODO(emilk): should probably heed `prev_state` here too
-            self.max_cell_size.x
+            self.max_col_width(self.col)
         } else {
             // If we want to allow width-filling widgets like [`Separator`] in one of the first cells
             // then we need to make sure they don't spill out of the first cell:
@@ -237,23 +249,37 @@ impl GridLayout {
     }
 
     fn paint_row(&self, cursor: &Rect, painter: &Painter) {
-        // handle row color painting based on color-picker function
-        let Some(color_picker) = self.color_picker.as_ref() else {
+        if self.color_picker.is_none() && !self.hover_highlight {
             return;
-        };
-        let Some(row_color) = color_picker(self.row, &self.style) else {
-            return;
-        };
+        }
         let Some(height) = self.prev_state.row_height(self.row) else {
             return;
         };
-        // Paint background for coming row:
+        // The rect of the coming row:
         let size = Vec2::new(self.prev_state.full_width(self.spacing.x), height);
         let rect = Rect::from_min_size(cursor.min, size);
         let rect = rect.expand2(0.5 * self.spacing.y * Vec2::Y);
         let rect = rect.expand2(2.0 * Vec2::X); // HACK: just looks better with some spacing on the sides
 
-        painter.rect_filled(rect, 2.0, row_color);
+        // handle row color painting based on color-picker function
+        let mut row_color = self
+            .color_picker
+            .as_ref()
+            .and_then(|color_picker| color_picker(self.row, &self.style));
+
+        // The hover tint replaces any stripe color:
+        if self.hover_highlight
+            && self
+                .ctx
+                .rect_contains_pointer(painter.layer_id(), rect.intersect(painter.clip_rect()))
+        {
+            row_color = Some(self.style.visuals.widgets.hovered.bg_fill);
+        }
+
+        if let Some(row_color) = row_color {
+            // Paint background for coming row:
+            painter.rect_filled(rect, 2.0, row_color);
+        }
     }
 
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
@@ -315,9 +341,11 @@ pub struct Grid {
     min_col_width: Option<f32>,
     min_row_height: Option<f32>,
     max_cell_size: Vec2,
+    max_col_widths: Vec<f32>,
     spacing: Option<Vec2>,
     start_row: usize,
     color_picker: Option<ColorPickerFn>,
+    hover_highlight: bool,
 }
 
 impl Grid {
@@ -329,9 +357,11 @@ impl Grid {
             min_col_width: None,
             min_row_height: None,
             max_cell_size: Vec2::INFINITY,
+            max_col_widths: Vec::new(),
             spacing: None,
             start_row: 0,
             color_picker: None,
+            hover_highlight: false,
         }
     }
 
@@ -367,6 +397,19 @@ impl Grid {
         }
     }
 
+    /// If `true`, tint the background of the row under the pointer.
+    ///
+    /// This can make dense key/value grids easier to read.
+    /// Combines with [`Self::striped`]: the hover tint replaces
+    /// the stripe color of the hovered row.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn hover_highlight(mut self, hover_highlight: bool) -> Self {
+        self.hover_highlight = hover_highlight;
+        self
+    }
+
     /// Set minimum width of each column.
     /// Default: [`crate::style::Spacing::interact_size`]`.x`.
     #[inline]
@@ -390,6 +433,19 @@ impl Grid {
         self
     }
 
+    /// Set soft maximum widths (wrapping widths) per column, by column index.
+    ///
+    /// Columns beyond the end of the slice keep the default from [`Self::max_col_width`]
+    /// (auto-sizing unless that is set). Use [`f32::INFINITY`] to skip a column.
+    ///
+    /// Useful when one column (e.g. a long-text value column) should wrap
+    /// while the others (e.g. a label column) stay auto-sized.
+    #[inline]
+    pub fn max_col_widths(mut self, max_col_widths: &[f32]) -> Self {
+        self.max_col_widths = max_col_widths.to_vec();
+        self
+    }
+
     /// Set spacing between columns/rows.
     /// Default: [`crate::style::Spacing::item_spacing`].
     #[inline]
@@ -423,9 +479,11 @@ impl Grid {
             min_col_width,
             min_row_height,
             max_cell_size,
+            max_col_widths,
             spacing,
             start_row,
             mut color_picker,
+            hover_highlight,
         } = self;
         let min_col_width = min_col_width.unwrap_or_else(|| ui.spacing().interact_size.x);
         let min_row_height = min_row_height.unwrap_or_else(|| ui.spacing().interact_size.y);
@@ -458,19 +516,21 @@ impl Grid {
 
         ui.scope_builder(ui_builder, |ui| {
             ui.horizontal(|ui| {
-                let is_color = color_picker.is_some();
+                let paints_rows = color_picker.is_some() || hover_highlight;
                 let grid = GridLayout {
                     num_columns,
                     color_picker,
+                    hover_highlight,
                     min_cell_size: vec2(min_col_width, min_row_height),
                     max_cell_size,
+                    max_col_widths,
                     spacing,
                     row: start_row,
                     ..GridLayout::new(ui, id, prev_state)
                 };
 
                 // paint first incoming row
-                if is_color {
+                if paints_rows {
                     let cursor = ui.cursor();
                     let painter = ui.painter();
                     grid.paint_row(&cursor, painter);
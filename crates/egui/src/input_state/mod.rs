@@ -9,7 +9,7 @@ use crate::{
     util::History,
 };
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     time::Duration,
 };
 
@@ -24,6 +24,16 @@ pub struct InputOptions {
     /// After a pointer-down event, if the pointer moves more than this, it won't become a click.
     pub max_click_dist: f32,
 
+    /// For a widget that senses both clicks and drags, the press is ambiguous:
+    /// it only becomes a drag after the pointer has moved more than this from where it was pressed,
+    /// and otherwise resolves to a click on release.
+    ///
+    /// Lower this to make such widgets (e.g. sliders) commit to a drag sooner.
+    /// Values larger than [`Self::max_click_dist`] have no effect,
+    /// since a press that moves beyond that stops being a potential click,
+    /// and therefore becomes a drag.
+    pub drag_start_dist: f32,
+
     /// If the pointer is down for longer than this it will no longer register as a click.
     ///
     /// If a touch is held for this many seconds while still, then it will register as a
@@ -35,14 +45,23 @@ pub struct InputOptions {
     /// The new pointer press must come within this many seconds from previous pointer release
     /// for double click (or when this value is doubled, triple click) to count.
     pub max_double_click_delay: f64,
+
+    /// A new click must land within this distance of the previous click
+    /// for it to count towards a double- or triple-click.
+    ///
+    /// The default is infinity (clicks anywhere count as consecutive),
+    /// matching the behavior from before this option existed.
+    pub max_double_click_dist: f32,
 }
 
 impl Default for InputOptions {
     fn default() -> Self {
         Self {
             max_click_dist: 6.0,
+            drag_start_dist: 6.0,
             max_click_duration: 0.8,
             max_double_click_delay: 0.3,
+            max_double_click_dist: f32::INFINITY,
         }
     }
 }
@@ -52,8 +71,10 @@ impl InputOptions {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         let Self {
             max_click_dist,
+            drag_start_dist,
             max_click_duration,
             max_double_click_delay,
+            max_double_click_dist,
         } = self;
         crate::containers::CollapsingHeader::new("InputOptions")
             .default_open(false)
@@ -66,6 +87,14 @@ impl InputOptions {
                     )
                     .on_hover_text("If the pointer moves more than this, it won't become a click");
                 });
+                ui.horizontal(|ui| {
+                    ui.label("Drag start distance");
+                    ui.add(
+                        crate::DragValue::new(drag_start_dist)
+                            .range(0.0..=f32::INFINITY)
+                    )
+                    .on_hover_text("A click-and-drag widget only becomes dragged once the pointer moves more than this");
+                });
                 ui.horizontal(|ui| {
                     ui.label("Max click duration");
                     ui.add(
@@ -84,6 +113,14 @@ impl InputOptions {
                     )
                     .on_hover_text("Max time interval for double click to count");
                 });
+                ui.horizontal(|ui| {
+                    ui.label("Max double click distance");
+                    ui.add(
+                        crate::DragValue::new(max_double_click_dist)
+                            .range(0.0..=f32::INFINITY)
+                    )
+                    .on_hover_text("Max distance between consecutive clicks for a double click to count");
+                });
             });
     }
 }
@@ -221,6 +258,11 @@ pub struct InputState {
     // The keys that are currently being held down.
     pub keys_down: HashSet<Key>,
 
+    /// For how long each key in [`Self::keys_down`] has been held, in seconds.
+    ///
+    /// Accumulated from [`Self::stable_dt`]. Read it with [`Self::key_down_duration`].
+    key_down_durations: HashMap<Key, f32>,
+
     /// In-order events received this frame
     pub events: Vec<Event>,
 
@@ -254,6 +296,7 @@ impl Default for InputState {
             focused: false,
             modifiers: Default::default(),
             keys_down: Default::default(),
+            key_down_durations: Default::default(),
             events: Default::default(),
             input_options: Default::default(),
         }
@@ -407,6 +450,19 @@ impl InputState {
             }
         }
 
+        let mut key_down_durations = self.key_down_durations;
+        {
+            let dt = stable_dt.at_most(0.1);
+            for duration in key_down_durations.values_mut() {
+                *duration += dt;
+            }
+            key_down_durations.retain(|key, _| keys_down.contains(key));
+            for key in &keys_down {
+                // Keys pressed this frame start at zero:
+                key_down_durations.entry(*key).or_insert(0.0);
+            }
+        }
+
         let is_scrolling = raw_scroll_delta != Vec2::ZERO || smooth_scroll_delta != Vec2::ZERO;
         let last_scroll_time = if is_scrolling {
             time
@@ -435,6 +491,7 @@ impl InputState {
             focused: new.focused,
             modifiers: new.modifiers,
             keys_down,
+            key_down_durations,
             events: new.events.clone(), // TODO(emilk): remove clone() and use raw.events
             raw: new,
             input_options: options.input_options.clone(),
@@ -610,11 +667,60 @@ impl InputState {
             .count()
     }
 
+    /// Was the given key pressed this frame, ignoring key-repeat events?
+    ///
+    /// Use this when you want to react only to the initial press of a key,
+    /// e.g. to implement your own repeat curve together with [`Self::key_down_duration`].
+    pub fn key_pressed_raw(&self, desired_key: Key) -> bool {
+        self.events.iter().any(|event| {
+            matches!(
+                event,
+                Event::Key {
+                    key,
+                    pressed: true,
+                    repeat: false,
+                    ..
+                } if *key == desired_key
+            )
+        })
+    }
+
+    /// Did we get a key-repeat event for the given key this frame?
+    ///
+    /// Key-repeat events are synthesized by the OS (or by egui)
+    /// while a key is being held down.
+    pub fn key_repeat(&self, desired_key: Key) -> bool {
+        self.events.iter().any(|event| {
+            matches!(
+                event,
+                Event::Key {
+                    key,
+                    pressed: true,
+                    repeat: true,
+                    ..
+                } if *key == desired_key
+            )
+        })
+    }
+
     /// Is the given key currently held down?
     pub fn key_down(&self, desired_key: Key) -> bool {
         self.keys_down.contains(&desired_key)
     }
 
+    /// For how long has the given key been held down, in seconds?
+    ///
+    /// Returns `0.0` the frame the key was pressed, and also if the key is not down.
+    ///
+    /// The duration is accumulated from [`Self::stable_dt`],
+    /// so it behaves well in reactive mode.
+    pub fn key_down_duration(&self, desired_key: Key) -> f32 {
+        self.key_down_durations
+            .get(&desired_key)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
     /// Was the given key released this frame?
     pub fn key_released(&self, desired_key: Key) -> bool {
         self.events.iter().any(|event| {
@@ -855,6 +961,10 @@ pub struct PointerState {
     /// for it to be registered as a click.
     pub(crate) has_moved_too_much_for_a_click: bool,
 
+    /// Set to `true` if the pointer has moved far enough (since being pressed)
+    /// for a click-and-drag widget to commit to a drag.
+    pub(crate) has_moved_enough_for_a_drag: bool,
+
     /// Did [`Self::is_decidedly_dragging`] go from `false` to `true` this frame?
     ///
     /// This could also be the trigger point for a long-touch.
@@ -868,6 +978,10 @@ pub struct PointerState {
     /// Used to check for triple-clicks.
     last_last_click_time: f64,
 
+    /// Where did the pointer get clicked last?
+    /// Used to check that consecutive clicks are close enough to count as double-clicks.
+    last_click_pos: Option<Pos2>,
+
     /// When was the pointer last moved?
     /// Used for things like showing hover ui/tooltip with a delay.
     last_move_time: f64,
@@ -896,9 +1010,11 @@ impl Default for PointerState {
             press_origin: None,
             press_start_time: None,
             has_moved_too_much_for_a_click: false,
+            has_moved_enough_for_a_drag: false,
             started_decidedly_dragging: false,
             last_click_time: f64::NEG_INFINITY,
             last_last_click_time: f64::NEG_INFINITY,
+            last_click_pos: None,
             last_move_time: f64::NEG_INFINITY,
             pointer_events: vec![],
             input_options: Default::default(),
@@ -939,6 +1055,8 @@ impl PointerState {
                     if let Some(press_origin) = self.press_origin {
                         self.has_moved_too_much_for_a_click |=
                             press_origin.distance(pos) > self.input_options.max_click_dist;
+                        self.has_moved_enough_for_a_drag |=
+                            press_origin.distance(pos) > self.input_options.drag_start_dist;
                     }
 
                     self.last_move_time = time;
@@ -968,6 +1086,7 @@ impl PointerState {
                         self.press_origin = Some(pos);
                         self.press_start_time = Some(time);
                         self.has_moved_too_much_for_a_click = false;
+                        self.has_moved_enough_for_a_drag = false;
                         self.pointer_events.push(PointerEvent::Pressed {
                             position: pos,
                             button,
@@ -977,10 +1096,17 @@ impl PointerState {
                         let clicked = self.could_any_button_be_click();
 
                         let click = if clicked {
-                            let double_click = (time - self.last_click_time)
-                                < self.input_options.max_double_click_delay;
-                            let triple_click = (time - self.last_last_click_time)
-                                < (self.input_options.max_double_click_delay * 2.0);
+                            let clicks_are_close =
+                                self.last_click_pos.is_none_or(|last_click_pos| {
+                                    last_click_pos.distance(pos)
+                                        <= self.input_options.max_double_click_dist
+                                });
+                            let double_click = clicks_are_close
+                                && (time - self.last_click_time)
+                                    < self.input_options.max_double_click_delay;
+                            let triple_click = clicks_are_close
+                                && (time - self.last_last_click_time)
+                                    < (self.input_options.max_double_click_delay * 2.0);
                             let count = if triple_click {
                                 3
                             } else if double_click {
@@ -991,6 +1117,7 @@ impl PointerState {
 
                             self.last_last_click_time = self.last_click_time;
                             self.last_click_time = time;
+                            self.last_click_pos = Some(pos);
 
                             Some(Click {
                                 pos,
@@ -1297,8 +1424,8 @@ impl PointerState {
 
     /// Just because the mouse is down doesn't mean we are dragging.
     /// We could be at the start of a click.
-    /// But if the mouse is down long enough, or has moved far enough,
-    /// then we consider it a drag.
+    /// But if the mouse is down long enough, or has moved further than
+    /// [`InputOptions::drag_start_dist`], then we consider it a drag.
     ///
     /// This function can return true on the same frame the drag is released,
     /// but NOT on the first frame it was started.
@@ -1307,7 +1434,7 @@ impl PointerState {
     pub fn is_decidedly_dragging(&self) -> bool {
         (self.any_down() || self.any_released())
             && !self.any_pressed()
-            && !self.could_any_button_be_click()
+            && (self.has_moved_enough_for_a_drag || !self.could_any_button_be_click())
             && !self.any_click()
     }
 
@@ -1379,6 +1506,7 @@ impl InputState {
             focused,
             modifiers,
             keys_down,
+            key_down_durations: _,
             events,
             input_options: _,
         } = self;
@@ -1461,9 +1589,11 @@ impl PointerState {
             press_origin,
             press_start_time,
             has_moved_too_much_for_a_click,
+            has_moved_enough_for_a_drag,
             started_decidedly_dragging,
             last_click_time,
             last_last_click_time,
+            last_click_pos,
             pointer_events,
             last_move_time,
             input_options: _,
@@ -1484,11 +1614,15 @@ impl PointerState {
         ui.label(format!(
             "has_moved_too_much_for_a_click: {has_moved_too_much_for_a_click}"
         ));
+        ui.label(format!(
+            "has_moved_enough_for_a_drag: {has_moved_enough_for_a_drag}"
+        ));
         ui.label(format!(
             "started_decidedly_dragging: {started_decidedly_dragging}"
         ));
         ui.label(format!("last_click_time: {last_click_time:#?}"));
         ui.label(format!("last_last_click_time: {last_last_click_time:#?}"));
+        ui.label(format!("last_click_pos: {last_click_pos:?}"));
         ui.label(format!("last_move_time: {last_move_time:#?}"));
         ui.label(format!("pointer_events: {pointer_events:?}"));
     }
@@ -145,6 +145,10 @@ impl Widget for &mut epaint::TessellationOptions {
                 prerasterized_discs,
                 round_text_to_pixels,
                 round_line_segments_to_pixels,
+                round_line_joins,
+                round_line_joins_min_width,
+                round_line_joins_fan_angle,
+                round_line_caps,
                 round_rects_to_pixels,
                 debug_paint_clip_rects,
                 debug_paint_text_rects,
@@ -181,6 +185,23 @@ impl Widget for &mut epaint::TessellationOptions {
 
             ui.checkbox(validate_meshes, "Validate meshes").on_hover_text("Check that incoming meshes are valid, i.e. that all indices are in range, etc.");
 
+            ui.horizontal(|ui| {
+                ui.checkbox(round_line_joins, "Round line joins")
+                    .on_hover_text("Insert rounded joins (arc fans) at the corners of thick open polylines.");
+
+                if *round_line_joins {
+                    ui.add(crate::DragValue::new(round_line_joins_min_width).range(0.0..=20.0).speed(0.1).prefix("width ≥ ").suffix(" pt"))
+                        .on_hover_text("Minimum stroke width for round joins to kick in");
+                    ui.add(crate::DragValue::new(round_line_joins_fan_angle).range(0.05..=1.5).speed(0.01).prefix("fan ").suffix(" rad"))
+                        .on_hover_text("Maximum angle covered by each segment of a join fan");
+                }
+            });
+
+            if *round_line_joins {
+                ui.checkbox(round_line_caps, "Round line caps")
+                    .on_hover_text("Also add round caps at the endpoints of open polylines.");
+            }
+
             ui.collapsing("Align to pixel grid", |ui| {
                 ui.checkbox(round_text_to_pixels, "Text")
                     .on_hover_text("Most text already is, so don't expect to see a large change.");
@@ -2,7 +2,7 @@ use emath::GuiRounding as _;
 
 use crate::{
     emath::{pos2, vec2, Align2, NumExt as _, Pos2, Rect, Vec2},
-    Align,
+    Align, Context, Id,
 };
 const INFINITY: f32 = f32::INFINITY;
 
@@ -117,6 +117,48 @@ impl Direction {
 
 // ----------------------------------------------------------------------------
 
+/// How to distribute leftover main-axis space in a wrapping [`Layout`].
+///
+/// After wrapping has decided which widgets go on a row,
+/// there is usually some space left over at the end of the row.
+/// This option distributes that space among the row's widgets,
+/// or the gaps between them, similar to `justify-content` in CSS flexbox.
+///
+/// Since egui is an immediate mode library the contents of a row
+/// aren't known until the whole row has been laid out,
+/// so the distribution is based on the rows of the previous pass
+/// (the same trick [`crate::Grid`] uses to remember column widths).
+/// The first pass with new content will therefore be slightly off,
+/// but a repaint is requested automatically to correct it.
+///
+/// Only used when [`Layout::main_wrap`] is enabled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MainWrapDistribute {
+    /// Keep the leftover space at the end of the row (default).
+    #[default]
+    None,
+
+    /// Put the leftover space in the gaps between the row's widgets,
+    /// so that the first and last widget are flush with the edges.
+    ///
+    /// Like `justify-content: space-between` in CSS.
+    SpaceBetween,
+
+    /// Put the leftover space around the row's widgets,
+    /// with half a gap before the first and after the last widget.
+    ///
+    /// Like `justify-content: space-around` in CSS.
+    SpaceAround,
+
+    /// Grow each widget on the row by an equal share of the leftover space.
+    ///
+    /// Like `flex-grow: 1` in CSS.
+    Grow,
+}
+
+// ----------------------------------------------------------------------------
+
 /// The layout of a [`Ui`][`crate::Ui`], e.g. "vertical & centered".
 ///
 /// ```
@@ -153,6 +195,11 @@ pub struct Layout {
     /// For vertical layouts justify mean all widgets get maximum width.
     /// For horizontal layouts justify mean all widgets get maximum height.
     pub cross_justify: bool,
+
+    /// How to distribute leftover main-axis space on each row of a wrapping layout.
+    ///
+    /// Only used when [`Self::main_wrap`] is enabled.
+    pub main_wrap_distribute: MainWrapDistribute,
 }
 
 impl Default for Layout {
@@ -176,6 +223,7 @@ impl Layout {
             main_justify: false,
             cross_align: valign,
             cross_justify: false,
+            main_wrap_distribute: MainWrapDistribute::None,
         }
     }
 
@@ -191,6 +239,7 @@ impl Layout {
             main_justify: false,
             cross_align: valign,
             cross_justify: false,
+            main_wrap_distribute: MainWrapDistribute::None,
         }
     }
 
@@ -206,6 +255,7 @@ impl Layout {
             main_justify: false,
             cross_align: halign,
             cross_justify: false,
+            main_wrap_distribute: MainWrapDistribute::None,
         }
     }
 
@@ -227,6 +277,7 @@ impl Layout {
             main_justify: false,
             cross_align: halign,
             cross_justify: false,
+            main_wrap_distribute: MainWrapDistribute::None,
         }
     }
 
@@ -239,6 +290,7 @@ impl Layout {
             main_justify: false,
             cross_align,
             cross_justify: false,
+            main_wrap_distribute: MainWrapDistribute::None,
         }
     }
 
@@ -255,6 +307,7 @@ impl Layout {
             main_justify: true,
             cross_align: Align::Center,
             cross_justify: true,
+            main_wrap_distribute: MainWrapDistribute::None,
         }
     }
 
@@ -309,6 +362,31 @@ impl Layout {
             ..self
         }
     }
+
+    /// How to distribute leftover main-axis space on each row of a wrapping layout.
+    ///
+    /// Only used together with [`Self::with_main_wrap`].
+    /// See [`MainWrapDistribute`] for the available modes and caveats.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let layout = egui::Layout::left_to_right(egui::Align::TOP)
+    ///     .with_main_wrap(true)
+    ///     .with_main_wrap_distribute(egui::MainWrapDistribute::SpaceBetween);
+    /// ui.with_layout(layout, |ui| {
+    ///     for i in 0..10 {
+    ///         let _ = ui.button(format!("Button {i}"));
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    #[inline(always)]
+    pub fn with_main_wrap_distribute(self, main_wrap_distribute: MainWrapDistribute) -> Self {
+        Self {
+            main_wrap_distribute,
+            ..self
+        }
+    }
 }
 
 /// ## Inspectors
@@ -333,6 +411,11 @@ impl Layout {
         self.cross_justify
     }
 
+    #[inline(always)]
+    pub fn main_wrap_distribute(&self) -> MainWrapDistribute {
+        self.main_wrap_distribute
+    }
+
     #[inline(always)]
     pub fn is_horizontal(&self) -> bool {
         self.main_dir().is_horizontal()
@@ -534,17 +617,15 @@ impl Layout {
         );
 
         if self.main_wrap {
-            let available_size = self.available_rect_before_wrap(region).size();
-
             let Region {
                 mut cursor,
                 mut max_rect,
                 min_rect,
             } = *region;
 
-            match self.main_dir {
-                Direction::LeftToRight => {
-                    if available_size.x < child_size.x && max_rect.left() < cursor.left() {
+            if self.will_wrap(region, child_size) {
+                match self.main_dir {
+                    Direction::LeftToRight => {
                         // New row
                         let new_row_height = cursor.height().max(child_size.y);
                         // let new_top = cursor.bottom() + spacing.y;
@@ -555,9 +636,7 @@ impl Layout {
                         );
                         max_rect.max.y = max_rect.max.y.max(cursor.max.y);
                     }
-                }
-                Direction::RightToLeft => {
-                    if available_size.x < child_size.x && cursor.right() < max_rect.right() {
+                    Direction::RightToLeft => {
                         // New row
                         let new_row_height = cursor.height().max(child_size.y);
                         // let new_top = cursor.bottom() + spacing.y;
@@ -568,9 +647,7 @@ impl Layout {
                         );
                         max_rect.max.y = max_rect.max.y.max(cursor.max.y);
                     }
-                }
-                Direction::TopDown => {
-                    if available_size.y < child_size.y && max_rect.top() < cursor.top() {
+                    Direction::TopDown => {
                         // New column
                         let new_col_width = cursor.width().max(child_size.x);
                         cursor = Rect::from_min_max(
@@ -579,9 +656,7 @@ impl Layout {
                         );
                         max_rect.max.x = max_rect.max.x.max(cursor.max.x);
                     }
-                }
-                Direction::BottomUp => {
-                    if available_size.y < child_size.y && cursor.bottom() < max_rect.bottom() {
+                    Direction::BottomUp => {
                         // New column
                         let new_col_width = cursor.width().max(child_size.x);
                         cursor = Rect::from_min_max(
@@ -609,6 +684,30 @@ impl Layout {
         }
     }
 
+    /// Will a widget of the given size be wrapped to a new row/column?
+    pub(crate) fn will_wrap(&self, region: &Region, child_size: Vec2) -> bool {
+        if !self.main_wrap {
+            return false;
+        }
+
+        let available_size = self.available_rect_before_wrap(region).size();
+        let cursor = region.cursor;
+        let max_rect = region.max_rect;
+
+        match self.main_dir {
+            Direction::LeftToRight => {
+                available_size.x < child_size.x && max_rect.left() < cursor.left()
+            }
+            Direction::RightToLeft => {
+                available_size.x < child_size.x && cursor.right() < max_rect.right()
+            }
+            Direction::TopDown => available_size.y < child_size.y && max_rect.top() < cursor.top(),
+            Direction::BottomUp => {
+                available_size.y < child_size.y && cursor.bottom() < max_rect.bottom()
+            }
+        }
+    }
+
     fn next_frame_ignore_wrap(&self, region: &Region, child_size: Vec2) -> Rect {
         region.sanity_check();
         debug_assert!(
@@ -816,6 +915,222 @@ impl Layout {
 
 // ----------------------------------------------------------------------------
 
+/// The rows laid out during one pass of a wrapping layout.
+///
+/// Stored between passes so that [`MainWrapDistributor`] can predict
+/// how much space is left over on each row.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct DistributeState {
+    rows: Vec<RowInfo>,
+}
+
+/// One wrapped row (or column) of widgets.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct RowInfo {
+    /// Main-axis space left at the end of the row, before any distribution.
+    leftover: f32,
+
+    /// Number of widgets on the row.
+    num_children: usize,
+}
+
+impl DistributeState {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_temp(id))
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        // Not persisted, for the same reasons as the `crate::Grid` state:
+        // if the contents change, the old rows are useless anyway.
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+}
+
+/// Distributes leftover main-axis space in a wrapping layout,
+/// according to [`Layout::main_wrap_distribute`].
+///
+/// The row contents aren't known until a whole row has been laid out,
+/// so this uses the rows remembered from the previous pass
+/// (the same trick as [`crate::Grid`]).
+/// The accumulated state is saved when this is dropped,
+/// and a repaint is requested if it changed.
+pub(crate) struct MainWrapDistributor {
+    ctx: Context,
+    id: Id,
+
+    /// First pass (no previous state)?
+    is_first_pass: bool,
+
+    /// The rows of the previous pass (if any).
+    prev_state: DistributeState,
+
+    /// The rows accumulated during the current pass.
+    curr_state: DistributeState,
+
+    /// Index of the next widget within the current row.
+    index_in_row: usize,
+
+    /// Total extra space handed out on the current row so far.
+    distributed_in_row: f32,
+
+    /// Leftover space of the current row, as if nothing had been distributed.
+    pending_leftover: f32,
+}
+
+impl MainWrapDistributor {
+    pub(crate) fn new(ctx: &Context, id: Id) -> Self {
+        let prev_state = DistributeState::load(ctx, id);
+        let is_first_pass = prev_state.is_none();
+        Self {
+            ctx: ctx.clone(),
+            id,
+            is_first_pass,
+            prev_state: prev_state.unwrap_or_default(),
+            curr_state: DistributeState::default(),
+            index_in_row: 0,
+            distributed_in_row: 0.0,
+            pending_leftover: 0.0,
+        }
+    }
+
+    /// Extra main-axis space for the widget at `index_in_row` on `row`:
+    /// a translation for the spacing modes,
+    /// or a size increase for [`MainWrapDistribute::Grow`].
+    fn extra(&self, mode: MainWrapDistribute, row: usize, index_in_row: usize) -> f32 {
+        let Some(info) = self.prev_state.rows.get(row) else {
+            return 0.0;
+        };
+        let n = info.num_children;
+        if n == 0 || info.leftover <= 0.0 || n <= index_in_row {
+            return 0.0; // The contents changed since the last pass - wait for the next one.
+        }
+        match mode {
+            MainWrapDistribute::None => 0.0,
+            MainWrapDistribute::SpaceBetween => {
+                if n < 2 || index_in_row == 0 {
+                    0.0
+                } else {
+                    info.leftover / (n - 1) as f32
+                }
+            }
+            MainWrapDistribute::SpaceAround => {
+                if index_in_row == 0 {
+                    info.leftover / (2 * n) as f32
+                } else {
+                    info.leftover / n as f32
+                }
+            }
+            MainWrapDistribute::Grow => info.leftover / n as f32,
+        }
+    }
+
+    /// Adjust the frame returned by [`Layout::next_frame`]
+    /// using the rows of the previous pass.
+    pub(crate) fn adjust_frame(
+        &self,
+        layout: &Layout,
+        region: &Region,
+        child_size: Vec2,
+        mut frame: Rect,
+    ) -> Rect {
+        let (row, index_in_row) = if layout.will_wrap(region, child_size) {
+            (self.curr_state.rows.len() + 1, 0)
+        } else {
+            (self.curr_state.rows.len(), self.index_in_row)
+        };
+
+        let extra = self.extra(layout.main_wrap_distribute, row, index_in_row);
+        if extra <= 0.0 {
+            return frame;
+        }
+
+        if layout.main_wrap_distribute == MainWrapDistribute::Grow {
+            match layout.main_dir {
+                Direction::LeftToRight => frame.max.x += extra,
+                Direction::RightToLeft => frame.min.x -= extra,
+                Direction::TopDown => frame.max.y += extra,
+                Direction::BottomUp => frame.min.y -= extra,
+            }
+        } else {
+            frame = frame.translate(match layout.main_dir {
+                Direction::LeftToRight => vec2(extra, 0.0),
+                Direction::RightToLeft => vec2(-extra, 0.0),
+                Direction::TopDown => vec2(0.0, extra),
+                Direction::BottomUp => vec2(0.0, -extra),
+            });
+        }
+
+        frame.round_ui()
+    }
+
+    /// Called after a widget has been placed.
+    ///
+    /// `wrapped` is true if the widget started a new row.
+    pub(crate) fn advance(
+        &mut self,
+        layout: &Layout,
+        region: &Region,
+        wrapped: bool,
+        widget_rect: Rect,
+    ) {
+        if wrapped {
+            self.finish_row();
+        }
+
+        self.distributed_in_row += self.extra(
+            layout.main_wrap_distribute,
+            self.curr_state.rows.len(),
+            self.index_in_row,
+        );
+        self.index_in_row += 1;
+
+        // How much space is left after this widget?
+        // We add back what we have already distributed,
+        // or the distribution would feed on itself next pass.
+        let max_rect = region.max_rect;
+        let measured = match layout.main_dir {
+            Direction::LeftToRight => max_rect.right() - widget_rect.right(),
+            Direction::RightToLeft => widget_rect.left() - max_rect.left(),
+            Direction::TopDown => max_rect.bottom() - widget_rect.bottom(),
+            Direction::BottomUp => widget_rect.top() - max_rect.top(),
+        };
+        self.pending_leftover = measured + self.distributed_in_row;
+    }
+
+    /// An explicit row break (e.g. [`crate::Ui::end_row`]).
+    pub(crate) fn end_row(&mut self) {
+        if 0 < self.index_in_row {
+            self.finish_row();
+        }
+    }
+
+    fn finish_row(&mut self) {
+        self.curr_state.rows.push(RowInfo {
+            // Round to guard against float drift causing eternal repaint requests:
+            leftover: self.pending_leftover.round_ui(),
+            num_children: self.index_in_row,
+        });
+        self.index_in_row = 0;
+        self.distributed_in_row = 0.0;
+        self.pending_leftover = 0.0;
+    }
+}
+
+impl Drop for MainWrapDistributor {
+    fn drop(&mut self) {
+        self.end_row(); // finish the last row (if any)
+
+        // We need to always save state on the first pass,
+        // otherwise we would request a repaint every pass (see `crate::Grid`):
+        if self.curr_state != self.prev_state || self.is_first_pass {
+            std::mem::take(&mut self.curr_state).store(&self.ctx, self.id);
+            self.ctx.request_repaint();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// ## Debug stuff
 impl Layout {
     /// Shows where the next widget is going to be placed
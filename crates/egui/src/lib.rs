@@ -414,6 +414,9 @@ mod context;
 mod data;
 pub mod debug_text;
 mod drag_and_drop;
+#[cfg(not(target_arch = "wasm32"))]
+mod font_watcher;
+pub mod frame_stats;
 pub(crate) mod grid;
 pub mod gui_zoom;
 mod hit_test;
@@ -435,6 +438,7 @@ pub mod response;
 mod sense;
 pub mod style;
 pub mod text_selection;
+pub mod toasts;
 mod ui;
 mod ui_builder;
 mod ui_stack;
@@ -467,7 +471,7 @@ pub use emath::{
 };
 pub use epaint::{
     mutex,
-    text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
+    text::{ColorGlyph, FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TextureOptions, TextureWrapMode, TexturesDelta},
     ClippedPrimitive, ColorImage, CornerRadius, FontImage, ImageData, Margin, Mesh, PaintCallback,
     PaintCallbackInfo, Shadow, Shape, Stroke, StrokeKind, TextureHandle, TextureId,
@@ -477,7 +481,7 @@ pub mod text {
     pub use crate::text_selection::CCursorRange;
     pub use epaint::text::{
         cursor::CCursor, FontData, FontDefinitions, FontFamily, Fonts, Galley, LayoutJob,
-        LayoutSection, TextFormat, TextWrapping, TAB_SIZE,
+        LayoutSection, TextFormat, TextTruncate, TextWrapping, TAB_SIZE,
     };
 }
 
@@ -493,7 +497,7 @@ pub use self::{
         Key, UserData,
     },
     drag_and_drop::DragAndDrop,
-    epaint::text::TextWrapMode,
+    epaint::text::{TextTruncate, TextWrapMode},
     grid::Grid,
     id::{Id, IdMap},
     input_state::{InputState, MultiTouchInfo, PointerState},
@@ -506,6 +510,7 @@ pub use self::{
     sense::Sense,
     style::{FontSelection, Spacing, Style, TextStyle, Visuals},
     text::{Galley, TextFormat},
+    toasts::{Toast, ToastKind},
     ui::Ui,
     ui_builder::UiBuilder,
     ui_stack::*,
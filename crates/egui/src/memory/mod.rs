@@ -284,6 +284,15 @@ pub struct Options {
     /// By default this is `true` in debug builds.
     pub warn_on_id_clash: bool,
 
+    /// If `true`, moving focus with the arrow keys wraps around at the edges:
+    /// pressing the right arrow key on the right-most focusable widget
+    /// moves focus to the left-most widget roughly aligned with it, etc.
+    ///
+    /// Useful for grids of buttons and toolbars.
+    ///
+    /// By default this is `false`: focus stays put at the edges.
+    pub wrap_arrow_focus: bool,
+
     // ------------------------------
     // Input:
     /// Multiplier for the scroll speed when reported in [`crate::MouseWheelUnit::Line`]s.
@@ -333,6 +342,7 @@ impl Default for Options {
             screen_reader: false,
             preload_font_glyphs: true,
             warn_on_id_clash: cfg!(debug_assertions),
+            wrap_arrow_focus: false,
 
             // Input:
             line_scroll_speed,
@@ -391,6 +401,7 @@ impl Options {
             screen_reader: _, // needs to come from the integration
             preload_font_glyphs: _,
             warn_on_id_clash,
+            wrap_arrow_focus,
 
             line_scroll_speed,
             scroll_zoom_speed,
@@ -421,6 +432,11 @@ impl Options {
 
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
 
+                ui.checkbox(
+                    wrap_arrow_focus,
+                    "Wrap around when moving focus with arrow keys",
+                );
+
                 ui.checkbox(reduce_texture_memory, "Reduce texture memory");
             });
 
@@ -622,9 +638,9 @@ impl Focus {
         }
     }
 
-    pub(crate) fn end_pass(&mut self, used_ids: &IdMap<Rect>) {
+    pub(crate) fn end_pass(&mut self, used_ids: &IdMap<Rect>, wrap_arrow_focus: bool) {
         if self.focus_direction.is_cardinal() {
-            if let Some(found_widget) = self.find_widget_in_direction(used_ids) {
+            if let Some(found_widget) = self.find_widget_in_direction(used_ids, wrap_arrow_focus) {
                 self.focused_widget = Some(FocusWidget::new(found_widget));
             }
         }
@@ -705,7 +721,11 @@ impl Focus {
         self.focus_direction = FocusDirection::None;
     }
 
-    fn find_widget_in_direction(&mut self, new_rects: &IdMap<Rect>) -> Option<Id> {
+    fn find_widget_in_direction(
+        &mut self,
+        new_rects: &IdMap<Rect>,
+        wrap_arrow_focus: bool,
+    ) -> Option<Id> {
         // NOTE: `new_rects` here include some widgets _not_ interested in focus.
 
         /// * negative if `a` is left of `b`
@@ -777,6 +797,36 @@ impl Focus {
             }
         }
 
+        if best_id.is_none() && wrap_arrow_focus {
+            // Nothing in the search direction - wrap around to the far edge:
+            // look for the widget furthest away in the _opposite_ direction.
+            let mut best_wrap_score = f32::NEG_INFINITY;
+
+            for (candidate_id, candidate_rect) in &self.focus_widgets_cache {
+                if *candidate_id == current_focused.id {
+                    continue;
+                }
+
+                let to_candidate = vec2(
+                    range_diff(candidate_rect.x_range(), current_rect.x_range()),
+                    range_diff(candidate_rect.y_range(), current_rect.y_range()),
+                );
+
+                let acos_angle = to_candidate.normalized().dot(-search_direction);
+
+                let is_in_search_cone = 0.5_f32.sqrt() <= acos_angle;
+                if is_in_search_cone {
+                    // Prefer far-away and well-aligned candidates:
+                    let score = to_candidate.length() * acos_angle * acos_angle;
+
+                    if best_wrap_score < score {
+                        best_wrap_score = score;
+                        best_id = Some(*candidate_id);
+                    }
+                }
+            }
+        }
+
         best_id
     }
 }
@@ -807,7 +857,8 @@ impl Memory {
     pub(crate) fn end_pass(&mut self, used_ids: &IdMap<Rect>) {
         self.caches.update();
         self.areas_mut().end_pass();
-        self.focus_mut().end_pass(used_ids);
+        let wrap_arrow_focus = self.options.wrap_arrow_focus;
+        self.focus_mut().end_pass(used_ids, wrap_arrow_focus);
 
         // Clean up abandoned popups.
         if let Some(popup) = self.popups.get_mut(&self.viewport_id) {
@@ -1230,6 +1281,11 @@ pub struct Areas {
     /// results in them being sent to the top and keeping their previous internal order.
     wants_to_be_on_top: ahash::HashSet<LayerId>,
 
+    /// Fine-grained stacking within an [`Order`] band, see [`crate::Area::order_bias`].
+    ///
+    /// Layers without an entry have bias `0`.
+    order_bias: ahash::HashMap<LayerId, i32>,
+
     /// The sublayers that each layer has.
     ///
     /// The parent sublayer is moved directly above the child sublayers in the ordering.
@@ -1321,6 +1377,17 @@ impl Areas {
             .filter_map(|layer| Some((layer, self.get(layer.id)?)))
     }
 
+    /// Set the stacking bias of a layer within its [`Order`] band.
+    ///
+    /// See [`crate::Area::order_bias`].
+    pub fn set_order_bias(&mut self, layer_id: LayerId, bias: i32) {
+        if bias == 0 {
+            self.order_bias.remove(&layer_id);
+        } else {
+            self.order_bias.insert(layer_id, bias);
+        }
+    }
+
     pub fn move_to_top(&mut self, layer_id: LayerId) {
         self.visible_areas_current_frame.insert(layer_id);
         self.wants_to_be_on_top.insert(layer_id);
@@ -1388,6 +1455,7 @@ impl Areas {
             visible_areas_current_frame,
             order,
             wants_to_be_on_top,
+            order_bias,
             sublayers,
             ..
         } = self;
@@ -1395,7 +1463,14 @@ impl Areas {
         std::mem::swap(visible_areas_last_frame, visible_areas_current_frame);
         visible_areas_current_frame.clear();
 
-        order.sort_by_key(|layer| (layer.order, wants_to_be_on_top.contains(layer)));
+        // The sort is stable, so ties keep their relative order from the previous frame:
+        order.sort_by_key(|layer| {
+            (
+                layer.order,
+                order_bias.get(layer).copied().unwrap_or_default(),
+                wants_to_be_on_top.contains(layer),
+            )
+        });
         wants_to_be_on_top.clear();
 
         // For all layers with sublayers, put the sublayers directly after the parent layer:
@@ -64,6 +64,35 @@ impl Painter {
         self
     }
 
+    /// Create a painter painting to another layer, keeping the clip rectangle.
+    ///
+    /// Like [`Self::with_layer_id`], but borrows instead of consuming,
+    /// mirroring [`Self::with_clip_rect`].
+    ///
+    /// Shapes added through the returned painter are appended to the target layer,
+    /// so they paint on top of anything already added to that layer this frame.
+    ///
+    /// The clip rectangle is kept, so the shapes still clip to the region
+    /// this painter covers; use [`Self::with_clip_rect`] to narrow it further,
+    /// or [`crate::Context::layer_painter`] if you want to cover the whole screen.
+    ///
+    /// Useful for overlays that must appear above sibling widgets,
+    /// e.g. a drag-preview following the cursor:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let layer_id = egui::LayerId::new(egui::Order::Tooltip, ui.id().with("drag_preview"));
+    /// let painter = ui.painter().with_layer(layer_id);
+    /// # _ = painter;
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn with_layer(&self, layer_id: LayerId) -> Self {
+        let mut new_self = self.clone();
+        new_self.layer_id = layer_id;
+        new_self
+    }
+
     /// Create a painter for a sub-region of this [`Painter`].
     ///
     /// The clip-rect of the returned [`Painter`] will be the intersection
@@ -485,6 +514,28 @@ impl Painter {
     ) -> ShapeIdx {
         self.add(Shape::image(texture_id, rect, uv, tint))
     }
+
+    /// Add many [`Mesh`]es at once.
+    ///
+    /// Consecutive meshes that share the same [`epaint::TextureId`] are merged
+    /// into a single [`Shape::Mesh`], reducing the number of draw calls.
+    /// This is safe because all meshes added through one [`Painter`] share
+    /// the same clip rect.
+    ///
+    /// [`Mesh`]: epaint::Mesh
+    pub fn add_meshes(&self, meshes: impl IntoIterator<Item = epaint::Mesh>) {
+        let mut merged: Vec<epaint::Mesh> = Vec::new();
+        for mesh in meshes {
+            if mesh.is_empty() {
+                continue;
+            }
+            match merged.last_mut() {
+                Some(last) if last.texture_id == mesh.texture_id => last.append(mesh),
+                _ => merged.push(mesh),
+            }
+        }
+        self.extend(merged.into_iter().map(Shape::mesh));
+    }
 }
 
 /// ## Text
@@ -512,6 +563,35 @@ impl Painter {
         rect
     }
 
+    /// Lay out and paint some text on top of a rounded background box.
+    ///
+    /// The background is sized to the text plus `padding` on all sides.
+    ///
+    /// To center the text at the given position, use `Align2::CENTER_CENTER`.
+    ///
+    /// Useful for e.g. plot labels and map annotations.
+    ///
+    /// Returns the bounding rect of the background box.
+    #[expect(clippy::needless_pass_by_value)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn text_with_background(
+        &self,
+        pos: Pos2,
+        anchor: Align2,
+        text: impl ToString,
+        font_id: FontId,
+        text_color: Color32,
+        bg_color: Color32,
+        padding: Vec2,
+        corner_radius: impl Into<CornerRadius>,
+    ) -> Rect {
+        let galley = self.layout_no_wrap(text.to_string(), font_id, text_color);
+        let bg_rect = anchor.anchor_size(pos, galley.size() + 2.0 * padding);
+        self.rect_filled(bg_rect, corner_radius, bg_color);
+        self.galley(bg_rect.min + padding, galley, text_color);
+        bg_rect
+    }
+
     /// Will wrap text at the given width and line break at `\n`.
     ///
     /// Paint the results with [`Self::galley`].
@@ -1,4 +1,7 @@
-use crate::{grid, vec2, Layout, Painter, Pos2, Rect, Region, Vec2};
+use crate::{
+    grid, layout::MainWrapDistributor, vec2, Layout, MainWrapDistribute, Painter, Pos2, Rect,
+    Region, Vec2,
+};
 
 #[cfg(debug_assertions)]
 use crate::{Align2, Color32, Stroke};
@@ -6,6 +9,10 @@ use crate::{Align2, Color32, Stroke};
 pub(crate) struct Placer {
     /// If set this will take precedence over [`crate::layout`].
     grid: Option<grid::GridLayout>,
+
+    /// Only set for wrapping layouts with [`MainWrapDistribute`].
+    wrap_distributor: Option<MainWrapDistributor>,
+
     layout: Layout,
     region: Region,
 }
@@ -15,6 +22,7 @@ impl Placer {
         let region = layout.region_from_max_rect(max_rect);
         Self {
             grid: None,
+            wrap_distributor: None,
             layout,
             region,
         }
@@ -25,6 +33,11 @@ impl Placer {
         self.grid = Some(grid);
     }
 
+    #[inline(always)]
+    pub(crate) fn set_wrap_distributor(&mut self, wrap_distributor: MainWrapDistributor) {
+        self.wrap_distributor = Some(wrap_distributor);
+    }
+
     pub(crate) fn save_grid(&mut self) {
         if let Some(grid) = &mut self.grid {
             grid.save();
@@ -117,8 +130,14 @@ impl Placer {
         if let Some(grid) = &self.grid {
             grid.next_cell(self.region.cursor, child_size)
         } else {
-            self.layout
-                .next_frame(&self.region, child_size, item_spacing)
+            let frame = self
+                .layout
+                .next_frame(&self.region, child_size, item_spacing);
+            if let Some(wrap_distributor) = &self.wrap_distributor {
+                wrap_distributor.adjust_frame(&self.layout, &self.region, child_size, frame)
+            } else {
+                frame
+            }
         }
     }
 
@@ -132,13 +151,23 @@ impl Placer {
     }
 
     /// Apply justify or alignment after calling `next_space`.
-    pub(crate) fn justify_and_align(&self, rect: Rect, child_size: Vec2) -> Rect {
+    pub(crate) fn justify_and_align(&self, rect: Rect, mut child_size: Vec2) -> Rect {
         debug_assert!(!rect.any_nan(), "rect: {rect:?}");
         debug_assert!(!child_size.any_nan(), "child_size is NaN: {child_size:?}");
 
         if let Some(grid) = &self.grid {
             grid.justify_and_align(rect, child_size)
         } else {
+            if self.wrap_distributor.is_some()
+                && self.layout.main_wrap_distribute == MainWrapDistribute::Grow
+            {
+                // Grow the widget to fill the enlarged frame:
+                if self.layout.is_horizontal() {
+                    child_size.x = child_size.x.max(rect.width());
+                } else {
+                    child_size.y = child_size.y.max(rect.height());
+                }
+            }
             self.layout.justify_and_align(rect, child_size)
         }
     }
@@ -174,6 +203,11 @@ impl Placer {
         if let Some(grid) = &mut self.grid {
             grid.advance(&mut self.region.cursor, frame_rect, widget_rect);
         } else {
+            if let Some(wrap_distributor) = &mut self.wrap_distributor {
+                // Same new-row test as `Layout::advance_after_rects`:
+                let wrapped = !self.region.cursor.intersects(frame_rect.shrink(1.0));
+                wrap_distributor.advance(&self.layout, &self.region, wrapped, widget_rect);
+            }
             self.layout.advance_after_rects(
                 &mut self.region.cursor,
                 frame_rect,
@@ -193,6 +227,9 @@ impl Placer {
         if let Some(grid) = &mut self.grid {
             grid.end_row(&mut self.region.cursor, painter);
         } else {
+            if let Some(wrap_distributor) = &mut self.wrap_distributor {
+                wrap_distributor.end_row();
+            }
             self.layout.end_row(&mut self.region, item_spacing);
         }
     }
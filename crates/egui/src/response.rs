@@ -580,12 +580,42 @@ impl Response {
     }
 
     /// Show this UI when hovering if the widget is disabled.
+    ///
+    /// Use this to explain why a widget is disabled,
+    /// e.g. inside [`Ui::add_enabled_ui`]`(false, …)`:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let all_required_fields_are_set = false;
+    /// ui.add_enabled_ui(all_required_fields_are_set, |ui| {
+    ///     ui.button("Submit")
+    ///         .on_disabled_hover_ui(|ui| {
+    ///             ui.label("First fill in all required fields");
+    ///         });
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// Disabled widgets are still hit-tested for hovering (they just can't be
+    /// clicked or otherwise interacted with), so this works out of the box.
+    ///
+    /// See also [`Self::on_disabled_hover_text`] and [`Self::on_hover_ui`].
+    #[doc(alias = "tooltip")]
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         Tooltip::for_disabled(&self).show(add_contents);
         self
     }
 
     /// Like `on_hover_ui`, but show the ui next to cursor.
+    ///
+    /// The tooltip is anchored to the current pointer position rather than the
+    /// widget rect, so it follows the cursor while hovered. This is useful for
+    /// large widgets such as plots or canvases, where a tooltip at a fixed
+    /// offset from the widget would be far from the point of interest.
+    ///
+    /// Near the screen edges the tooltip is flipped and clamped so it doesn't
+    /// overflow off-screen.
+    #[doc(alias = "tooltip")]
     pub fn on_hover_ui_at_pointer(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         Tooltip::for_enabled(&self)
             .at_pointer()
@@ -659,6 +689,11 @@ impl Response {
     }
 
     /// Show this text when hovering if the widget is disabled.
+    ///
+    /// Use this to explain why a widget is disabled.
+    ///
+    /// See also [`Self::on_disabled_hover_ui`] and [`Self::on_hover_text`].
+    #[doc(alias = "tooltip")]
     pub fn on_disabled_hover_text(self, text: impl Into<WidgetText>) -> Self {
         self.on_disabled_hover_ui(|ui| {
             // Prevent `Area` auto-sizing from shrinking tooltips with dynamic content.
@@ -760,23 +795,63 @@ impl Response {
         align: Option<Align>,
         animation: crate::style::ScrollAnimation,
     ) {
+        self.scroll_to_me_aligns_animation([align, align], animation);
+    }
+
+    /// Like [`Self::scroll_to_me`], but with a separate alignment for the horizontal and vertical axes.
+    ///
+    /// This lets you position a widget precisely in a bidirectional [`crate::ScrollArea`],
+    /// e.g. `response.scroll_to_me_aligns([Some(Align::Center); 2])` to center it on both axes,
+    /// or `[None, Some(Align::TOP)]` to put its top at the top of the scroll area
+    /// while scrolling horizontally just enough to bring it into view.
+    pub fn scroll_to_me_aligns(&self, aligns: [Option<Align>; 2]) {
+        self.scroll_to_me_aligns_animation(aligns, self.ctx.style().scroll_animation);
+    }
+
+    /// Like [`Self::scroll_to_me_aligns`], but allows you to specify the [`crate::style::ScrollAnimation`].
+    pub fn scroll_to_me_aligns_animation(
+        &self,
+        aligns: [Option<Align>; 2],
+        animation: crate::style::ScrollAnimation,
+    ) {
+        let ranges = [self.rect.x_range(), self.rect.y_range()];
         self.ctx.pass_state_mut(|state| {
-            state.scroll_target[0] = Some(pass_state::ScrollTarget::new(
-                self.rect.x_range(),
-                align,
-                animation,
-            ));
-            state.scroll_target[1] = Some(pass_state::ScrollTarget::new(
-                self.rect.y_range(),
-                align,
-                animation,
-            ));
+            for d in 0..2 {
+                state.scroll_target[d] = Some(pass_state::ScrollTarget::new(
+                    ranges[d], aligns[d], animation,
+                ));
+            }
         });
     }
 
     /// For accessibility.
     ///
     /// Call after interacting and potential calls to [`Self::mark_changed`].
+    ///
+    /// This is how custom widgets built from [`Ui::allocate_response`] and a [`crate::Painter`]
+    /// become visible to screen readers: the [`crate::WidgetInfo`] provides the role
+    /// (via [`crate::WidgetType`]), label, value, and state for the AccessKit node.
+    /// When AccessKit is inactive this only registers debug information, so the overhead is low.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut on = false;
+    /// let desired_size = ui.spacing().interact_size.y * egui::vec2(2.0, 1.0);
+    /// let (rect, mut response) = ui.allocate_exact_size(desired_size, egui::Sense::click());
+    /// if response.clicked() {
+    ///     on = !on;
+    ///     response.mark_changed();
+    /// }
+    /// response.widget_info(|| {
+    ///     egui::WidgetInfo::selected(egui::WidgetType::Checkbox, ui.is_enabled(), on, "Dark mode")
+    /// });
+    /// // …then paint the toggle into `rect`.
+    /// # _ = rect;
+    /// # });
+    /// ```
+    ///
+    /// For full control over the AccessKit node (custom roles, live regions, …),
+    /// see [`Context::accesskit_node_builder`].
     pub fn widget_info(&self, make_info: impl Fn() -> crate::WidgetInfo) {
         use crate::output::OutputEvent;
 
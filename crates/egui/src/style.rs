@@ -1066,6 +1066,141 @@ impl Visuals {
     pub fn gray_out(&self, color: Color32) -> Color32 {
         crate::ecolor::tint_color_towards(color, self.fade_out_to_color())
     }
+
+    /// Linearly interpolate towards `other`.
+    ///
+    /// `t` is in `[0, 1]`, where `0` returns `self` and `1` returns `other`.
+    /// All colors, strokes, corner radii, shadows and other interpolatable
+    /// values are blended; fields that cannot be interpolated
+    /// (booleans, enums, …) switch from `self` to `other` at `t = 0.5`.
+    ///
+    /// Used by [`crate::Context::set_visuals_animated`] for smooth theme transitions.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let switched = if t < 0.5 { self } else { other };
+
+        Self {
+            dark_mode: switched.dark_mode,
+            override_text_color: match (self.override_text_color, other.override_text_color) {
+                (Some(a), Some(b)) => Some(a.lerp_to_gamma(b, t)),
+                _ => switched.override_text_color,
+            },
+            widgets: Widgets {
+                noninteractive: lerp_widget_visuals(
+                    &self.widgets.noninteractive,
+                    &other.widgets.noninteractive,
+                    t,
+                ),
+                inactive: lerp_widget_visuals(&self.widgets.inactive, &other.widgets.inactive, t),
+                hovered: lerp_widget_visuals(&self.widgets.hovered, &other.widgets.hovered, t),
+                active: lerp_widget_visuals(&self.widgets.active, &other.widgets.active, t),
+                open: lerp_widget_visuals(&self.widgets.open, &other.widgets.open, t),
+            },
+            selection: Selection {
+                bg_fill: self
+                    .selection
+                    .bg_fill
+                    .lerp_to_gamma(other.selection.bg_fill, t),
+                stroke: lerp_stroke(&self.selection.stroke, &other.selection.stroke, t),
+            },
+            hyperlink_color: self.hyperlink_color.lerp_to_gamma(other.hyperlink_color, t),
+            faint_bg_color: self.faint_bg_color.lerp_to_gamma(other.faint_bg_color, t),
+            extreme_bg_color: self
+                .extreme_bg_color
+                .lerp_to_gamma(other.extreme_bg_color, t),
+            code_bg_color: self.code_bg_color.lerp_to_gamma(other.code_bg_color, t),
+            warn_fg_color: self.warn_fg_color.lerp_to_gamma(other.warn_fg_color, t),
+            error_fg_color: self.error_fg_color.lerp_to_gamma(other.error_fg_color, t),
+            window_corner_radius: lerp_corner_radius(
+                self.window_corner_radius,
+                other.window_corner_radius,
+                t,
+            ),
+            window_shadow: lerp_shadow(&self.window_shadow, &other.window_shadow, t),
+            window_fill: self.window_fill.lerp_to_gamma(other.window_fill, t),
+            window_stroke: lerp_stroke(&self.window_stroke, &other.window_stroke, t),
+            window_highlight_topmost: switched.window_highlight_topmost,
+            menu_corner_radius: lerp_corner_radius(
+                self.menu_corner_radius,
+                other.menu_corner_radius,
+                t,
+            ),
+            panel_fill: self.panel_fill.lerp_to_gamma(other.panel_fill, t),
+            popup_shadow: lerp_shadow(&self.popup_shadow, &other.popup_shadow, t),
+            resize_corner_size: emath::lerp(self.resize_corner_size..=other.resize_corner_size, t),
+            text_cursor: TextCursorStyle {
+                stroke: lerp_stroke(&self.text_cursor.stroke, &other.text_cursor.stroke, t),
+                preview: switched.text_cursor.preview,
+                blink: switched.text_cursor.blink,
+                on_duration: emath::lerp(
+                    self.text_cursor.on_duration..=other.text_cursor.on_duration,
+                    t,
+                ),
+                off_duration: emath::lerp(
+                    self.text_cursor.off_duration..=other.text_cursor.off_duration,
+                    t,
+                ),
+            },
+            clip_rect_margin: emath::lerp(self.clip_rect_margin..=other.clip_rect_margin, t),
+            button_frame: switched.button_frame,
+            collapsing_header_frame: switched.collapsing_header_frame,
+            indent_has_left_vline: switched.indent_has_left_vline,
+            striped: switched.striped,
+            slider_trailing_fill: switched.slider_trailing_fill,
+            handle_shape: match (self.handle_shape, other.handle_shape) {
+                (HandleShape::Rect { aspect_ratio: a }, HandleShape::Rect { aspect_ratio: b }) => {
+                    HandleShape::Rect {
+                        aspect_ratio: emath::lerp(a..=b, t),
+                    }
+                }
+                _ => switched.handle_shape,
+            },
+            interact_cursor: switched.interact_cursor,
+            image_loading_spinners: switched.image_loading_spinners,
+            numeric_color_space: switched.numeric_color_space,
+        }
+    }
+}
+
+fn lerp_widget_visuals(a: &WidgetVisuals, b: &WidgetVisuals, t: f32) -> WidgetVisuals {
+    WidgetVisuals {
+        bg_fill: a.bg_fill.lerp_to_gamma(b.bg_fill, t),
+        weak_bg_fill: a.weak_bg_fill.lerp_to_gamma(b.weak_bg_fill, t),
+        bg_stroke: lerp_stroke(&a.bg_stroke, &b.bg_stroke, t),
+        corner_radius: lerp_corner_radius(a.corner_radius, b.corner_radius, t),
+        fg_stroke: lerp_stroke(&a.fg_stroke, &b.fg_stroke, t),
+        expansion: emath::lerp(a.expansion..=b.expansion, t),
+    }
+}
+
+fn lerp_stroke(a: &Stroke, b: &Stroke, t: f32) -> Stroke {
+    Stroke {
+        width: emath::lerp(a.width..=b.width, t),
+        color: a.color.lerp_to_gamma(b.color, t),
+    }
+}
+
+fn lerp_corner_radius(a: CornerRadius, b: CornerRadius, t: f32) -> CornerRadius {
+    let lerp_u8 = |a: u8, b: u8| emath::lerp(a as f32..=b as f32, t).round() as u8;
+    CornerRadius {
+        nw: lerp_u8(a.nw, b.nw),
+        ne: lerp_u8(a.ne, b.ne),
+        sw: lerp_u8(a.sw, b.sw),
+        se: lerp_u8(a.se, b.se),
+    }
+}
+
+fn lerp_shadow(a: &Shadow, b: &Shadow, t: f32) -> Shadow {
+    let lerp_u8 = |a: u8, b: u8| emath::lerp(a as f32..=b as f32, t).round() as u8;
+    let lerp_i8 = |a: i8, b: i8| emath::lerp(a as f32..=b as f32, t).round() as i8;
+    Shadow {
+        offset: [
+            lerp_i8(a.offset[0], b.offset[0]),
+            lerp_i8(a.offset[1], b.offset[1]),
+        ],
+        blur: lerp_u8(a.blur, b.blur),
+        spread: lerp_u8(a.spread, b.spread),
+        color: a.color.lerp_to_gamma(b.color, t),
+    }
 }
 
 /// Selected text, selected elements etc
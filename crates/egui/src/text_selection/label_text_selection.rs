@@ -8,7 +8,7 @@ use crate::{
 };
 
 use super::{
-    text_cursor_state::cursor_rect,
+    text_cursor_state::{byte_index_from_char_index, cursor_rect},
     visuals::{paint_text_selection, RowVertexIndices},
     TextCursorState,
 };
@@ -100,6 +100,9 @@ pub struct LabelSelectionState {
     text_to_copy: String,
     last_copied_galley_rect: Option<Rect>,
 
+    /// See [`Self::set_copy_as_markdown`].
+    copy_as_markdown: bool,
+
     /// Painted selections this frame.
     ///
     /// Kept so we can undo a bad selection visualization if we don't see both ends of the selection this frame.
@@ -118,6 +121,7 @@ impl Default for LabelSelectionState {
             has_reached_secondary: Default::default(),
             text_to_copy: Default::default(),
             last_copied_galley_rect: Default::default(),
+            copy_as_markdown: Default::default(),
             painted_selections: Default::default(),
         }
     }
@@ -238,8 +242,48 @@ impl LabelSelectionState {
         self.selection = None;
     }
 
-    fn copy_text(&mut self, new_galley_rect: Rect, galley: &Galley, cursor_range: &CCursorRange) {
-        let new_text = selected_text(galley, cursor_range);
+    /// If `true`, copying a selection produces markdown instead of plain text.
+    ///
+    /// The markup is inferred from the style of each galley section:
+    /// `*italics*`, `` `inline code` `` for monospace text,
+    /// and `#` headings for text noticeably larger than [`crate::TextStyle::Body`].
+    /// Text without any such styling is copied as plain text, as before.
+    /// Note that egui has no true bold, so e.g. [`crate::RichText::strong`]
+    /// text copies as plain text.
+    ///
+    /// Remember to [`Self::store`] the state after changing this:
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let mut state = egui::text_selection::LabelSelectionState::load(ctx);
+    /// state.set_copy_as_markdown(true);
+    /// state.store(ctx);
+    /// # });
+    /// ```
+    ///
+    /// Default: `false`.
+    pub fn set_copy_as_markdown(&mut self, copy_as_markdown: bool) {
+        self.copy_as_markdown = copy_as_markdown;
+    }
+
+    /// See [`Self::set_copy_as_markdown`].
+    pub fn copy_as_markdown(&self) -> bool {
+        self.copy_as_markdown
+    }
+
+    fn copy_text(
+        &mut self,
+        ui: &Ui,
+        new_galley_rect: Rect,
+        galley: &Galley,
+        cursor_range: &CCursorRange,
+    ) {
+        let new_text = if self.copy_as_markdown {
+            let body_font_size = crate::TextStyle::Body.resolve(ui.style()).size;
+            selected_markdown(galley, cursor_range, body_font_size)
+        } else {
+            selected_text(galley, cursor_range)
+        };
         if new_text.is_empty() {
             return;
         }
@@ -555,7 +599,7 @@ impl LabelSelectionState {
             }
 
             if got_copy_event(ui.ctx()) {
-                self.copy_text(galley_rect, galley, &cursor_range);
+                self.copy_text(ui, galley_rect, galley, &cursor_range);
             }
 
             cursor_state.set_char_range(Some(cursor_range));
@@ -703,6 +747,76 @@ fn selected_text(galley: &Galley, cursor_range: &CCursorRange) -> String {
     }
 }
 
+/// Like [`selected_text`], but reconstructs markdown markup from the galley's style sections.
+///
+/// egui text carries no semantic markup, so this is a heuristic:
+/// italic sections become `*italics*`, monospace sections become `` `code` ``,
+/// and sections noticeably larger than `body_font_size` become `#` headings.
+fn selected_markdown(galley: &Galley, cursor_range: &CCursorRange, body_font_size: f32) -> String {
+    let text = galley.text();
+
+    // Same elision logic as in `selected_text`:
+    let everything_is_selected = cursor_range.contains(CCursorRange::select_all(galley));
+    let copy_everything = cursor_range.is_empty() || everything_is_selected;
+
+    let (start, end) = if copy_everything {
+        (0, text.len())
+    } else {
+        let [start, end] = cursor_range.sorted_cursors();
+        (
+            byte_index_from_char_index(text, start.index),
+            byte_index_from_char_index(text, end.index),
+        )
+    };
+
+    let mut markdown = String::new();
+
+    for section in &galley.job.sections {
+        let section_start = section.byte_range.start.max(start);
+        let section_end = section.byte_range.end.min(end);
+        if section_end <= section_start {
+            continue;
+        }
+        let slice = &text[section_start..section_end];
+        let format = &section.format;
+
+        let heading_level = if 0.0 < body_font_size {
+            let ratio = format.font_id.size / body_font_size;
+            if 1.8 <= ratio {
+                Some(1)
+            } else if 1.4 <= ratio {
+                Some(2)
+            } else if 1.1 <= ratio {
+                Some(3)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        if let Some(heading_level) = heading_level {
+            for _ in 0..heading_level {
+                markdown.push('#');
+            }
+            markdown.push(' ');
+            markdown.push_str(slice);
+        } else if format.font_id.family == epaint::FontFamily::Monospace {
+            markdown.push('`');
+            markdown.push_str(slice);
+            markdown.push('`');
+        } else if format.italics {
+            markdown.push('*');
+            markdown.push_str(slice);
+            markdown.push('*');
+        } else {
+            markdown.push_str(slice);
+        }
+    }
+
+    markdown
+}
+
 fn estimate_row_height(galley: &Galley) -> f32 {
     if let Some(placed_row) = galley.rows.first() {
         placed_row.height()
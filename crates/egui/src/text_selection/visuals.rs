@@ -95,6 +95,51 @@ pub fn paint_text_selection(
     }
 }
 
+/// Underline the text currently being composed by an IME (the pre-edit text).
+///
+/// `galley_pos` is where the galley is painted on screen.
+pub fn paint_ime_preedit_underline(
+    painter: &Painter,
+    visuals: &Visuals,
+    galley: &Galley,
+    galley_pos: crate::Pos2,
+    cursor_range: &CCursorRange,
+) {
+    if cursor_range.is_empty() {
+        return;
+    }
+
+    let stroke = visuals.text_cursor.stroke;
+    let [min, max] = cursor_range.sorted_cursors();
+    let min = galley.layout_from_cursor(min);
+    let max = galley.layout_from_cursor(max);
+
+    for ri in min.row..=max.row {
+        let placed_row = &galley.rows[ri];
+        let row = &placed_row.row;
+
+        let left = if ri == min.row {
+            row.x_offset(min.column)
+        } else {
+            0.0
+        };
+        let right = if ri == max.row {
+            row.x_offset(max.column)
+        } else {
+            row.size.x
+        };
+
+        let y = placed_row.pos.y + row.size.y;
+        painter.line_segment(
+            [
+                galley_pos + vec2(placed_row.pos.x + left, y),
+                galley_pos + vec2(placed_row.pos.x + right, y),
+            ],
+            (stroke.width, stroke.color),
+        );
+    }
+}
+
 /// Paint one end of the selection, e.g. the primary cursor.
 ///
 /// This will never blink.
@@ -0,0 +1,260 @@
+//! Transient toast notifications.
+//!
+//! Push a [`Toast`] from anywhere with [`Context::add_toast`]
+//! (or manage the queue with [`Context::toasts`]).
+//! The toasts are stacked in a corner of the screen,
+//! fade out and auto-dismiss after their duration,
+//! and can be closed early with their close button.
+//! Hovering a toast pauses its dismiss timer.
+//!
+//! ```
+//! # let ctx = egui::Context::default();
+//! ctx.add_toast(egui::Toast::success("File saved"));
+//! ```
+
+use crate::{Align2, Area, Color32, Context, Frame, Id, Order, Sense, Vec2, WidgetText};
+
+/// Register this plugin on the given egui context,
+/// so that it will be called every pass.
+///
+/// This is a built-in plugin in egui,
+/// meaning [`Context`] calls this from its `Default` implementation,
+/// so this is marked as `pub(crate)`.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_begin_pass("toasts", std::sync::Arc::new(Toasts::begin_pass));
+}
+
+/// What kind of message does a [`Toast`] carry?
+///
+/// This decides the color of the indicator dot next to the text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastKind {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    fn color(self) -> Color32 {
+        match self {
+            Self::Info => Color32::from_rgb(0, 155, 255),
+            Self::Success => Color32::from_rgb(0, 200, 80),
+            Self::Warning => Color32::from_rgb(255, 180, 0),
+            Self::Error => Color32::from_rgb(255, 60, 60),
+        }
+    }
+}
+
+/// A transient notification, shown by the [`Toasts`] plugin.
+///
+/// See the [module docs](crate::toasts) for an example.
+#[derive(Clone)]
+pub struct Toast {
+    pub text: WidgetText,
+
+    pub kind: ToastKind,
+
+    /// For how long the toast is shown before it auto-dismisses, in seconds.
+    pub duration: f32,
+}
+
+impl Toast {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            kind: ToastKind::Info,
+            duration: 4.0,
+        }
+    }
+
+    /// An [`ToastKind::Info`] toast.
+    pub fn info(text: impl Into<WidgetText>) -> Self {
+        Self::new(text).kind(ToastKind::Info)
+    }
+
+    /// A [`ToastKind::Success`] toast.
+    pub fn success(text: impl Into<WidgetText>) -> Self {
+        Self::new(text).kind(ToastKind::Success)
+    }
+
+    /// A [`ToastKind::Warning`] toast.
+    pub fn warning(text: impl Into<WidgetText>) -> Self {
+        Self::new(text).kind(ToastKind::Warning)
+    }
+
+    /// A [`ToastKind::Error`] toast.
+    pub fn error(text: impl Into<WidgetText>) -> Self {
+        Self::new(text).kind(ToastKind::Error)
+    }
+
+    #[inline]
+    pub fn kind(mut self, kind: ToastKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// For how long the toast is shown before it auto-dismisses, in seconds.
+    ///
+    /// Default: 4 seconds.
+    #[inline]
+    pub fn duration(mut self, seconds: f32) -> Self {
+        self.duration = seconds;
+        self
+    }
+}
+
+#[derive(Clone)]
+struct ActiveToast {
+    /// Unique per toast, so we can animate and dismiss each one individually.
+    id: u64,
+
+    toast: Toast,
+
+    /// Seconds left until auto-dismissal. Paused while hovered.
+    time_left: f32,
+}
+
+/// The queue of toast notifications, and options for how to show them.
+///
+/// This is a built-in plugin in egui.
+/// Access it with [`Context::toasts`].
+#[derive(Clone)]
+pub struct Toasts {
+    /// In which corner of the screen the toasts are stacked.
+    ///
+    /// Default: [`Align2::RIGHT_BOTTOM`].
+    pub align: Align2,
+
+    /// Distance from the screen corner.
+    pub margin: Vec2,
+
+    /// How many toasts to show at once.
+    ///
+    /// The rest are queued, and shown as older toasts are dismissed.
+    pub max_visible: usize,
+
+    next_id: u64,
+    toasts: Vec<ActiveToast>,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self {
+            align: Align2::RIGHT_BOTTOM,
+            margin: Vec2::splat(8.0),
+            max_visible: 5,
+            next_id: 0,
+            toasts: Vec::new(),
+        }
+    }
+}
+
+impl Toasts {
+    /// Over how long the toasts fade in and out, in seconds.
+    const FADE_TIME: f32 = 0.25;
+
+    /// Add a toast to the queue.
+    pub fn add(&mut self, toast: Toast) {
+        let time_left = toast.duration;
+        self.toasts.push(ActiveToast {
+            id: self.next_id,
+            toast,
+            time_left,
+        });
+        self.next_id += 1;
+    }
+
+    /// Number of toasts that are visible or queued.
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Dismiss all toasts.
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    fn begin_pass(ctx: &Context) {
+        let Some(state) = ctx.data(|d| d.get_temp::<Self>(Id::NULL)) else {
+            return;
+        };
+        if state.toasts.is_empty() {
+            return;
+        }
+
+        let dt = ctx.input(|i| i.stable_dt).min(0.1);
+
+        let mut dismissed = Vec::new();
+        let mut time_lefts = Vec::new();
+
+        Area::new(Id::new("__egui_toasts"))
+            .order(Order::Foreground)
+            .anchor(state.align, state.align.to_sign() * -state.margin)
+            .show(ctx, |ui| {
+                for active in state.toasts.iter().take(state.max_visible) {
+                    let appear_t =
+                        ctx.animate_bool(Id::new("__egui_toast_appear").with(active.id), true);
+                    let opacity = appear_t * (active.time_left / Self::FADE_TIME).clamp(0.0, 1.0);
+
+                    let mut close_clicked = false;
+
+                    let response = ui
+                        .scope(|ui| {
+                            ui.set_opacity(opacity);
+                            Frame::popup(ui.style()).show(ui, |ui| {
+                                ui.horizontal(|ui| {
+                                    let (rect, _) =
+                                        ui.allocate_exact_size(Vec2::splat(10.0), Sense::hover());
+                                    ui.painter().circle_filled(
+                                        rect.center(),
+                                        4.0,
+                                        active.toast.kind.color(),
+                                    );
+
+                                    ui.label(active.toast.text.clone());
+
+                                    close_clicked = ui.small_button("🗙").clicked();
+                                });
+                            });
+                        })
+                        .response;
+
+                    let hovered = response.contains_pointer();
+
+                    if close_clicked {
+                        dismissed.push(active.id);
+                    } else {
+                        let time_left = if hovered {
+                            active.time_left // Pause the dismiss timer while hovered.
+                        } else {
+                            active.time_left - dt
+                        };
+                        if time_left <= 0.0 {
+                            dismissed.push(active.id);
+                        } else {
+                            time_lefts.push((active.id, time_left));
+                        }
+                    }
+                }
+            });
+
+        ctx.data_mut(|d| {
+            let state = d.get_temp_mut_or_default::<Self>(Id::NULL);
+            state.toasts.retain(|t| !dismissed.contains(&t.id));
+            for (id, time_left) in time_lefts {
+                if let Some(active) = state.toasts.iter_mut().find(|t| t.id == id) {
+                    active.time_left = time_left;
+                }
+            }
+        });
+
+        // Keep ticking the timers and fades:
+        ctx.request_repaint();
+    }
+}
@@ -15,7 +15,7 @@ use crate::{
     emath, epaint,
     epaint::text::Fonts,
     grid,
-    layout::{Direction, Layout},
+    layout::{Direction, Layout, MainWrapDistribute},
     pass_state,
     placer::Placer,
     pos2, style,
@@ -149,7 +149,10 @@ impl Ui {
         let style = style.unwrap_or_else(|| ctx.style());
         let sense = sense.unwrap_or(Sense::hover());
 
-        let placer = Placer::new(max_rect, layout);
+        let mut placer = Placer::new(max_rect, layout);
+        if layout.main_wrap && layout.main_wrap_distribute != MainWrapDistribute::None {
+            placer.set_wrap_distributor(crate::layout::MainWrapDistributor::new(&ctx, id));
+        }
         let ui_stack = UiStack {
             id,
             layout_direction: layout.main_dir,
@@ -284,6 +287,7 @@ impl Ui {
             if layout.cross_align == Align::Center {
                 layout.cross_align = Align::Min;
             }
+            layout.main_wrap_distribute = MainWrapDistribute::None;
         }
 
         debug_assert!(!max_rect.any_nan(), "max_rect is NaN: {max_rect:?}");
@@ -293,7 +297,13 @@ impl Ui {
 
         self.next_auto_id_salt = self.next_auto_id_salt.wrapping_add(1);
 
-        let placer = Placer::new(max_rect, layout);
+        let mut placer = Placer::new(max_rect, layout);
+        if layout.main_wrap && layout.main_wrap_distribute != MainWrapDistribute::None {
+            placer.set_wrap_distributor(crate::layout::MainWrapDistributor::new(
+                self.ctx(),
+                unique_id,
+            ));
+        }
         let ui_stack = UiStack {
             id: unique_id,
             layout_direction: layout.main_dir,
@@ -1484,6 +1494,33 @@ impl Ui {
     /// If the contents overflow, more space will be allocated.
     /// When finished, the amount of space actually used (`min_rect`) will be allocated.
     /// So you can request a lot of space and then use less.
+    ///
+    /// The child [`Ui`] gets a fresh [`Layout`], independent of the parent's,
+    /// so you can e.g. lay out a small right-to-left region inside a vertical layout:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.vertical(|ui| {
+    ///     ui.label("Above");
+    ///     let response = ui.allocate_ui_with_layout(
+    ///         egui::vec2(ui.available_width(), 0.0),
+    ///         egui::Layout::right_to_left(egui::Align::Center),
+    ///         |ui| {
+    ///             ui.label("rightmost");
+    ///             ui.label("to the left of that");
+    ///         },
+    ///     );
+    ///     ui.label("Below"); // Placed below the space the closure actually used.
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// The returned [`InnerResponse`] contains the closure's return value
+    /// and a [`Response`] covering the space that was actually used,
+    /// which is also what advances the parent's cursor.
+    ///
+    /// If you want to place the region at an explicit rectangle instead,
+    /// use [`Self::scope_builder`] with [`UiBuilder::max_rect`] and [`UiBuilder::layout`].
     #[inline]
     pub fn allocate_ui_with_layout<R>(
         &mut self,
@@ -1810,6 +1847,51 @@ impl Ui {
         })
     }
 
+    /// Add a section that is enabled or disabled regardless of the enabled state of the parent [`Ui`].
+    ///
+    /// Unlike [`Self::add_enabled_ui`], `scope_enabled(true, …)` will re-enable the contents
+    /// even inside an already disabled [`Ui`].
+    /// This is useful for e.g. an always-clickable "Cancel" button inside an otherwise disabled form.
+    ///
+    /// The visuals reflect the effective enabled state of the contents,
+    /// so a force-enabled section is not grayed out.
+    ///
+    /// An invisible [`Ui`] cannot be re-enabled, since its contents are never shown.
+    ///
+    /// See also [`Self::is_enabled`] and [`Self::add_enabled_ui`].
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add_enabled_ui(false, |ui| {
+    ///     ui.label("I am grayed out");
+    ///     ui.scope_enabled(true, |ui| {
+    ///         if ui.button("Cancel (always clickable)").clicked() {
+    ///             /* … */
+    ///         }
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    pub fn scope_enabled<R>(
+        &mut self,
+        enabled: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.scope(|ui| {
+            if enabled {
+                if !ui.is_enabled() && ui.is_visible() {
+                    // Force-enable: undo the graying-out inherited from the disabled parent.
+                    ui.enabled = true;
+                    ui.painter.set_fade_to_color(None);
+                }
+            } else {
+                ui.disable();
+            }
+            add_contents(ui)
+        })
+    }
+
     /// Add a single [`Widget`] that is possibly invisible.
     ///
     /// An invisible widget still takes up the same space as if it were visible.
@@ -2411,6 +2493,14 @@ impl Ui {
     }
 
     /// Create a child, add content to it, and then allocate only what was used in the parent `Ui`.
+    ///
+    /// For instance, `ui.scope_builder(UiBuilder::new().max_rect(rect).layout(layout), …)`
+    /// places a child with its own [`Layout`] at the given rectangle,
+    /// and afterwards advances the parent's cursor past the space the child actually used
+    /// (its `min_rect`) — not past the whole `max_rect`.
+    ///
+    /// See also [`Self::allocate_ui_with_layout`] for when you have a desired size
+    /// rather than an explicit rectangle.
     pub fn scope_builder<R>(
         &mut self,
         ui_builder: UiBuilder,
@@ -2608,6 +2698,29 @@ impl Ui {
         self.horizontal_with_main_wrap_dyn(true, Box::new(add_contents))
     }
 
+    /// Same as [`Self::horizontal_wrapped`], but with an explicit item spacing
+    /// that overrides [`crate::style::Spacing::item_spacing`] for just this layout.
+    ///
+    /// The horizontal component is the gap between items within a row
+    /// (and is taken into account when deciding where to wrap),
+    /// and the vertical component is the gap between rows.
+    ///
+    /// Useful for e.g. tag/chip clouds where you want tighter horizontal spacing
+    /// than the global [`crate::style::Spacing::item_spacing`].
+    pub fn horizontal_wrapped_with_spacing<R>(
+        &mut self,
+        item_spacing: Vec2,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.horizontal_with_main_wrap_dyn(
+            true,
+            Box::new(|ui| {
+                ui.spacing_mut().item_spacing = item_spacing;
+                add_contents(ui)
+            }),
+        )
+    }
+
     fn horizontal_with_main_wrap_dyn<'c, R>(
         &mut self,
         main_wrap: bool,
@@ -2871,6 +2984,88 @@ impl Ui {
         result
     }
 
+    /// Split a [`Ui`] into several columns, each with its own vertical [`crate::ScrollArea`].
+    ///
+    /// Unlike [`Self::columns`], a tall column won't stretch its siblings:
+    /// each column scrolls independently within the given `max_height`.
+    /// Useful for e.g. side-by-side diff views or kanban boards.
+    ///
+    /// The columns share the available width equally.
+    /// Use [`Self::scrolling_columns_weighted`] to divide it by weights instead.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.scrolling_columns(2, 300.0, |col_idx, ui| {
+    ///     for i in 0..100 {
+    ///         ui.label(format!("Column {col_idx}, row {i}"));
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn scrolling_columns(
+        &mut self,
+        num_columns: usize,
+        max_height: f32,
+        add_column: impl FnMut(usize, &mut Self),
+    ) {
+        self.scrolling_columns_weighted(&vec![1.0; num_columns], max_height, add_column);
+    }
+
+    /// Like [`Self::scrolling_columns`], but the columns divide the
+    /// available width proportionally to the given weights.
+    ///
+    /// The weights must sum to something positive, or nothing is shown.
+    pub fn scrolling_columns_weighted(
+        &mut self,
+        weights: &[f32],
+        max_height: f32,
+        mut add_column: impl FnMut(usize, &mut Self),
+    ) {
+        let num_columns = weights.len();
+        if num_columns == 0 {
+            return;
+        }
+
+        let spacing = self.spacing().item_spacing.x;
+        let total_spacing = spacing * (num_columns as f32 - 1.0);
+        let width_to_share = self.available_width() - total_spacing;
+        let total_weight: f32 = weights.iter().sum();
+        if total_weight <= 0.0 || !total_weight.is_finite() {
+            return; // Protect against division by zero (and NaN and negative weights).
+        }
+        let top_left = self.cursor().min;
+
+        let mut x = top_left.x;
+        let mut max_used_height: f32 = 0.0;
+        for (col_idx, weight) in weights.iter().enumerate() {
+            let column_width = width_to_share * weight / total_weight;
+            let child_rect = Rect::from_min_max(
+                pos2(x, top_left.y),
+                pos2(x + column_width, self.max_rect().right_bottom().y),
+            );
+            let mut column_ui = self.new_child(
+                UiBuilder::new()
+                    .id_salt(("scrolling_column", col_idx))
+                    .max_rect(child_rect)
+                    .layout(Layout::top_down_justified(Align::LEFT)),
+            );
+            column_ui.set_width(column_width);
+
+            crate::ScrollArea::vertical()
+                .max_height(max_height)
+                .auto_shrink([false, true])
+                .show(&mut column_ui, |ui| add_column(col_idx, ui));
+
+            max_used_height = max_used_height.max(column_ui.min_size().y);
+            x += column_width + spacing;
+        }
+
+        self.advance_cursor_after_rect(Rect::from_min_size(
+            top_left,
+            vec2(self.available_width(), max_used_height),
+        ));
+    }
+
     /// Create something that can be drag-and-dropped.
     ///
     /// The `id` needs to be globally unique.
@@ -2878,6 +3073,44 @@ impl Ui {
     ///
     /// In contrast to [`Response::dnd_set_drag_payload`],
     /// this function will paint the widget at the mouse cursor while the user is dragging.
+    ///
+    /// Example, reordering a `Vec<String>` by dragging items onto each other:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut items: Vec<String> = vec!["one".into(), "two".into(), "three".into()];
+    /// let mut from = None;
+    /// let mut to = None;
+    ///
+    /// for (idx, item) in items.iter().enumerate() {
+    ///     let item_id = ui.id().with(("reorderable_item", idx));
+    ///     let response = ui
+    ///         .dnd_drag_source(item_id, idx, |ui| {
+    ///             ui.label(item.clone());
+    ///         })
+    ///         .response;
+    ///
+    ///     if response.dnd_hover_payload::<usize>().is_some() {
+    ///         // Another item is being dragged over this one; show where it would end up:
+    ///         ui.painter().hline(
+    ///             response.rect.x_range(),
+    ///             response.rect.bottom(),
+    ///             ui.visuals().widgets.active.bg_stroke,
+    ///         );
+    ///     }
+    ///
+    ///     if let Some(dragged_idx) = response.dnd_release_payload::<usize>() {
+    ///         from = Some(*dragged_idx);
+    ///         to = Some(idx);
+    ///     }
+    /// }
+    ///
+    /// if let (Some(from), Some(to)) = (from, to) {
+    ///     let item = items.remove(from);
+    ///     items.insert(to.min(items.len()), item);
+    /// }
+    /// # });
+    /// ```
     #[doc(alias = "drag and drop")]
     pub fn dnd_drag_source<Payload, R>(
         &mut self,
@@ -402,6 +402,62 @@ impl IdTypeMap {
             .cloned()
     }
 
+    /// The storage key used by the namespaced `*_in` accessors
+    /// for the given namespace and key.
+    ///
+    /// Reusable widgets can use a namespace (e.g. an [`Id`] made from the widget type)
+    /// to make it unlikely that their state collides with state
+    /// that unrelated code stores under the same key and type.
+    #[inline]
+    pub fn namespaced_key(namespace: Id, key: Id) -> Id {
+        namespace.with(key)
+    }
+
+    /// Like [`Self::insert_temp`], but stores the value under both a namespace and a key.
+    ///
+    /// See [`Self::namespaced_key`].
+    #[inline]
+    pub fn insert_temp_in<T: 'static + Any + Clone + Send + Sync>(
+        &mut self,
+        namespace: Id,
+        key: Id,
+        value: T,
+    ) {
+        self.insert_temp(Self::namespaced_key(namespace, key), value);
+    }
+
+    /// Like [`Self::insert_persisted`], but stores the value under both a namespace and a key.
+    ///
+    /// See [`Self::namespaced_key`].
+    #[inline]
+    pub fn insert_persisted_in<T: SerializableAny>(&mut self, namespace: Id, key: Id, value: T) {
+        self.insert_persisted(Self::namespaced_key(namespace, key), value);
+    }
+
+    /// Like [`Self::get_temp`], but reads the value stored under both a namespace and a key.
+    ///
+    /// See [`Self::namespaced_key`].
+    #[inline]
+    pub fn get_temp_in<T: 'static + Clone>(&self, namespace: Id, key: Id) -> Option<T> {
+        self.get_temp(Self::namespaced_key(namespace, key))
+    }
+
+    /// Like [`Self::get_persisted`], but reads the value stored under both a namespace and a key.
+    ///
+    /// See [`Self::namespaced_key`].
+    #[inline]
+    pub fn get_persisted_in<T: SerializableAny>(&mut self, namespace: Id, key: Id) -> Option<T> {
+        self.get_persisted(Self::namespaced_key(namespace, key))
+    }
+
+    /// Like [`Self::remove`], but removes the value stored under both a namespace and a key.
+    ///
+    /// See [`Self::namespaced_key`].
+    #[inline]
+    pub fn remove_in<T: 'static>(&mut self, namespace: Id, key: Id) {
+        self.remove::<T>(Self::namespaced_key(namespace, key));
+    }
+
     #[inline]
     pub fn get_temp_mut_or<T: 'static + Any + Clone + Send + Sync>(
         &mut self,
@@ -703,6 +759,32 @@ fn test_two_id_two_type() {
     assert_eq!(map.get_temp::<i32>(b), Some(42));
 }
 
+#[test]
+fn test_namespaced_access() {
+    let namespace_a = Id::new("widget_crate_a");
+    let namespace_b = Id::new("widget_crate_b");
+    let key = Id::new("shared_key");
+
+    let mut map: IdTypeMap = Default::default();
+
+    // The same key and type in two namespaces don't clobber each other:
+    map.insert_temp_in(namespace_a, key, 42);
+    map.insert_temp_in(namespace_b, key, 1337);
+    map.insert_temp(key, 7);
+
+    assert_eq!(map.get_temp_in::<i32>(namespace_a, key), Some(42));
+    assert_eq!(map.get_temp_in::<i32>(namespace_b, key), Some(1337));
+    assert_eq!(map.get_temp::<i32>(key), Some(7));
+
+    map.remove_in::<i32>(namespace_a, key);
+    assert_eq!(map.get_temp_in::<i32>(namespace_a, key), None);
+    assert_eq!(map.get_temp_in::<i32>(namespace_b, key), Some(1337));
+
+    map.insert_persisted_in(namespace_a, key, 13.37);
+    assert_eq!(map.get_persisted_in::<f64>(namespace_a, key), Some(13.37));
+    assert_eq!(map.get_persisted_in::<f64>(namespace_b, key), None);
+}
+
 #[test]
 fn test_two_id_x_two_types() {
     #![allow(clippy::approx_constant)]
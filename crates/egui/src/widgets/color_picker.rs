@@ -376,6 +376,121 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
     }
 }
 
+/// State of the active eyedropper, if any.
+///
+/// Stored at [`Id::NULL`]: only one eyedropper can be active at a time.
+#[derive(Clone)]
+struct EyedropperState {
+    /// The button that activated the eyedropper.
+    owner: Id,
+
+    /// The last rendered frame, to sample from.
+    image: Option<std::sync::Arc<crate::ColorImage>>,
+}
+
+/// Marker used to recognize our own screenshot requests in [`crate::Event::Screenshot`].
+#[derive(Clone, Copy, PartialEq)]
+struct EyedropperRequest(Id);
+
+fn request_eyedropper_frame(ctx: &Context, owner: Id) {
+    ctx.send_viewport_cmd(crate::ViewportCommand::Screenshot(crate::UserData::new(
+        EyedropperRequest(owner),
+    )));
+}
+
+/// A button that, while toggled on, samples the color under the cursor on click.
+///
+/// The colors are read back from the last rendered frame using the
+/// [`crate::ViewportCommand::Screenshot`] path, so this only does something
+/// in integrations that support screenshots (e.g. `eframe`).
+///
+/// Returns the sampled color, if any.
+fn eyedropper_ui(ui: &mut Ui) -> Option<Color32> {
+    let ctx = ui.ctx().clone();
+    let owner_id = ui.id().with("eyedropper");
+
+    let mut state = ctx.data(|d| d.get_temp::<EyedropperState>(Id::NULL));
+    let mut is_active = state.as_ref().is_some_and(|s| s.owner == owner_id);
+
+    let button = ui
+        .selectable_label(is_active, "💧")
+        .on_hover_text("Pick a color from the screen");
+    if button.clicked() {
+        is_active = !is_active;
+        state = is_active.then(|| EyedropperState {
+            owner: owner_id,
+            image: None,
+        });
+        if is_active {
+            request_eyedropper_frame(&ctx, owner_id);
+        }
+    }
+
+    let mut picked = None;
+
+    if is_active {
+        if let Some(current) = &mut state {
+            ctx.set_cursor_icon(crate::CursorIcon::Crosshair);
+
+            // Collect the requested frame when it arrives, then ask for a fresh
+            // one so the sample keeps up with what is on screen:
+            let new_image = ctx.input(|i| {
+                i.events.iter().rev().find_map(|event| {
+                    if let crate::Event::Screenshot {
+                        user_data, image, ..
+                    } = event
+                    {
+                        let is_ours = user_data
+                            .data
+                            .as_ref()
+                            .and_then(|data| data.downcast_ref::<EyedropperRequest>())
+                            .is_some_and(|request| request.0 == owner_id);
+                        is_ours.then(|| image.clone())
+                    } else {
+                        None
+                    }
+                })
+            });
+            if let Some(new_image) = new_image {
+                current.image = Some(new_image);
+                request_eyedropper_frame(&ctx, owner_id);
+            }
+
+            let pressed_at = ctx.input(|i| {
+                i.pointer
+                    .primary_pressed()
+                    .then(|| i.pointer.interact_pos())
+                    .flatten()
+            });
+
+            if let Some(pos) = pressed_at {
+                if !button.contains_pointer() {
+                    // A press on the button itself should only toggle the eyedropper.
+                    if let Some(image) = &current.image {
+                        let pixels_per_point = ctx.pixels_per_point();
+                        let x = (pos.x * pixels_per_point).floor() as usize;
+                        let y = (pos.y * pixels_per_point).floor() as usize;
+                        let [w, h] = image.size;
+                        if x < w && y < h {
+                            picked = Some(image.pixels[y * w + x]);
+                        }
+                    }
+                    state = None; // Deactivate, also if we had no frame to sample from.
+                }
+            } else if ctx.input(|i| i.key_pressed(Key::Escape)) {
+                state = None;
+            }
+        }
+    }
+
+    ctx.data_mut(|d| match state {
+        Some(state) => d.insert_temp(Id::NULL, state),
+        None => d.remove::<EyedropperState>(Id::NULL),
+    });
+
+    picked
+}
+
 fn input_type_button_ui(ui: &mut Ui) {
     let mut input_type = ui.ctx().style().visuals.numeric_color_space;
     if input_type.toggle_button_ui(ui).changed() {
@@ -406,6 +521,12 @@ fn srgba_edit_ui(ui: &mut Ui, [r, g, b, a]: &mut [u8; 4], alpha: Alpha) -> bool
                 ui.ctx().copy_text(format!("{r}, {g}, {b}, {a}"));
             }
         }
+
+        if let Some(picked) = eyedropper_ui(ui) {
+            [*r, *g, *b, *a] = picked.to_srgba_unmultiplied();
+            edited = true;
+        }
+
         edited |= DragValue::new(r).speed(0.5).prefix("R ").ui(ui).changed();
         edited |= DragValue::new(g).speed(0.5).prefix("G ").ui(ui).changed();
         edited |= DragValue::new(b).speed(0.5).prefix("B ").ui(ui).changed();
@@ -449,6 +570,11 @@ fn rgba_edit_ui(ui: &mut Ui, [r, g, b, a]: &mut [f32; 4], alpha: Alpha) -> bool
             }
         }
 
+        if let Some(picked) = eyedropper_ui(ui) {
+            [*r, *g, *b, *a] = Rgba::from(picked).to_rgba_unmultiplied();
+            edited = true;
+        }
+
         edited |= drag_value(ui, "R ", r).changed();
         edited |= drag_value(ui, "G ", g).changed();
         edited |= drag_value(ui, "B ", b).changed();
@@ -38,6 +38,7 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 pub struct DragValue<'a> {
     get_set_value: GetSetValue<'a>,
     speed: f64,
+    keyboard_step: Option<f64>,
     prefix: String,
     suffix: String,
     range: RangeInclusive<f64>,
@@ -69,6 +70,7 @@ impl<'a> DragValue<'a> {
         Self {
             get_set_value: Box::new(get_set_value),
             speed: 1.0,
+            keyboard_step: None,
             prefix: Default::default(),
             suffix: Default::default(),
             range: f64::NEG_INFINITY..=f64::INFINITY,
@@ -90,6 +92,19 @@ impl<'a> DragValue<'a> {
         self
     }
 
+    /// How much the value changes per arrow-key press when the widget has keyboard focus.
+    ///
+    /// Hold down Shift for 10x coarser steps, and Ctrl/Cmd or Alt for 10x finer steps.
+    /// PageUp/PageDown jump by 10 steps at once.
+    /// The result is clamped to [`Self::range`].
+    ///
+    /// Default: the drag [`Self::speed`].
+    #[inline]
+    pub fn keyboard_step(mut self, base: impl Into<f64>) -> Self {
+        self.keyboard_step = Some(base.into());
+        self
+    }
+
     /// Sets valid range for the value.
     ///
     /// By default all values are clamped to this range, even when not interacted with.
@@ -431,6 +446,7 @@ impl Widget for DragValue<'_> {
         let Self {
             mut get_set_value,
             speed,
+            keyboard_step,
             range,
             clamp_existing_to_range,
             prefix,
@@ -487,6 +503,22 @@ impl Widget for DragValue<'_> {
                 // problematic.
                 change += input.count_and_consume_key(Modifiers::NONE, Key::ArrowUp) as f64
                     - input.count_and_consume_key(Modifiers::NONE, Key::ArrowDown) as f64;
+
+                // Shift is for coarse steps, Ctrl/Cmd or Alt for fine steps:
+                for (modifiers, scale) in [
+                    (Modifiers::SHIFT, 10.0),
+                    (Modifiers::COMMAND, 0.1),
+                    (Modifiers::ALT, 0.1),
+                ] {
+                    change += scale
+                        * (input.count_and_consume_key(modifiers, Key::ArrowUp) as f64
+                            - input.count_and_consume_key(modifiers, Key::ArrowDown) as f64);
+                }
+
+                // PageUp/PageDown jump by 10 steps:
+                change += 10.0
+                    * (input.count_and_consume_key(Modifiers::NONE, Key::PageUp) as f64
+                        - input.count_and_consume_key(Modifiers::NONE, Key::PageDown) as f64);
             }
 
             #[cfg(feature = "accesskit")]
@@ -516,8 +548,9 @@ impl Widget for DragValue<'_> {
         }
 
         if change != 0.0 {
-            value += speed * change;
+            value += keyboard_step.unwrap_or(speed) * change;
             value = emath::round_to_decimals(value, auto_decimals);
+            value = clamp_value_to_range(value, range.clone());
         }
 
         if old_value != value {
@@ -555,19 +588,24 @@ impl Widget for DragValue<'_> {
             let mut value_text = ui
                 .data_mut(|data| data.remove_temp::<String>(id))
                 .unwrap_or_else(|| value_text.clone());
-            let response = ui.add(
-                TextEdit::singleline(&mut value_text)
-                    .clip_text(false)
-                    .horizontal_align(ui.layout().horizontal_align())
-                    .vertical_align(ui.layout().vertical_align())
-                    .margin(ui.spacing().button_padding)
-                    .min_size(ui.spacing().interact_size)
-                    .id(id)
-                    .desired_width(
-                        ui.spacing().interact_size.x - 2.0 * ui.spacing().button_padding.x,
-                    )
-                    .font(text_style),
-            );
+
+            // Show rejected input in the error color, so the user can tell
+            // their edit isn't being applied:
+            let parses = value_text.is_empty() || parse(&custom_parser, &value_text).is_some();
+
+            let mut text_edit = TextEdit::singleline(&mut value_text)
+                .clip_text(false)
+                .horizontal_align(ui.layout().horizontal_align())
+                .vertical_align(ui.layout().vertical_align())
+                .margin(ui.spacing().button_padding)
+                .min_size(ui.spacing().interact_size)
+                .id(id)
+                .desired_width(ui.spacing().interact_size.x - 2.0 * ui.spacing().button_padding.x)
+                .font(text_style);
+            if !parses {
+                text_edit = text_edit.text_color(ui.visuals().error_fg_color);
+            }
+            let response = ui.add(text_edit);
 
             let update = if update_while_editing {
                 // Update when the edit content has changed.
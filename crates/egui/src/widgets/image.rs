@@ -7,9 +7,10 @@ use epaint::{
 };
 
 use crate::{
-    load::{Bytes, SizeHint, SizedTexture, TextureLoadResult, TexturePoll},
-    pos2, Color32, Context, CornerRadius, Id, Mesh, Painter, Rect, Response, Sense, Shape, Spinner,
-    TextStyle, TextureOptions, Ui, Vec2, Widget, WidgetInfo, WidgetType,
+    load::{Bytes, ImagePoll, SizeHint, SizedTexture, TextureLoadResult, TexturePoll},
+    pos2, vec2, Color32, Context, CornerRadius, Id, LayerId, Mesh, Order, Painter, Pos2, Rect,
+    Response, Sense, Shape, Spinner, TextStyle, TextureOptions, Ui, Vec2, Widget, WidgetInfo,
+    WidgetType,
 };
 
 /// A widget which displays an image.
@@ -55,6 +56,19 @@ pub struct Image<'a> {
     size: ImageSize,
     pub(crate) show_loading_spinner: Option<bool>,
     alt_text: Option<String>,
+    magnifier: Option<ImageMagnifier>,
+}
+
+/// Options for the hover magnifier of an [`Image`].
+///
+/// See [`Image::with_magnifier`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageMagnifier {
+    /// How many times the pixels around the cursor are magnified.
+    pub zoom: f32,
+
+    /// Size of the magnifier inset, in ui points.
+    pub size: Vec2,
 }
 
 impl<'a> Image<'a> {
@@ -81,6 +95,7 @@ impl<'a> Image<'a> {
                 size,
                 show_loading_spinner: None,
                 alt_text: None,
+                magnifier: None,
             }
         }
 
@@ -215,6 +230,9 @@ impl<'a> Image<'a> {
     }
 
     /// Multiply image color with this. Default is WHITE (no tint).
+    ///
+    /// Monochrome SVG icons using `currentColor` are rasterized as white,
+    /// so this is how you recolor them, e.g. for hover/active/disabled states.
     #[inline]
     pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
         self.image_options.tint = tint.into();
@@ -280,6 +298,37 @@ impl<'a> Image<'a> {
         self.alt_text = Some(label.into());
         self
     }
+
+    /// Show a magnifier inset next to the cursor when hovering the image.
+    ///
+    /// The inset shows the pixels around the cursor magnified `zoom` times,
+    /// in a box of the given `size` (in ui points).
+    /// The sampled region is clamped to the image,
+    /// so the magnifier keeps working near the image borders.
+    ///
+    /// If the source image is available on the CPU (i.e. it was loaded from a
+    /// URI or from bytes, not from an existing texture), the position and
+    /// color of the pixel under the cursor are also shown.
+    ///
+    /// Combine with [`TextureOptions::NEAREST`] for crisp pixels:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(
+    ///     egui::Image::new(egui::include_image!("../../assets/ferris.png"))
+    ///         .texture_options(egui::TextureOptions::NEAREST)
+    ///         .with_magnifier(4.0, egui::Vec2::splat(128.0)),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn with_magnifier(mut self, zoom: f32, size: impl Into<Vec2>) -> Self {
+        self.magnifier = Some(ImageMagnifier {
+            zoom,
+            size: size.into(),
+        });
+        self
+    }
 }
 
 impl<'a, T: Into<ImageSource<'a>>> From<T> for Image<'a> {
@@ -400,6 +449,77 @@ impl<'a> Image<'a> {
             self.alt_text.as_deref(),
         );
     }
+
+    /// Paint the magnifier inset of [`Self::with_magnifier`] next to the cursor.
+    fn paint_magnifier(
+        &self,
+        ui: &Ui,
+        magnifier: ImageMagnifier,
+        image_rect: Rect,
+        pointer_pos: Pos2,
+        texture: &SizedTexture,
+        widget_id: Id,
+    ) {
+        let ImageMagnifier { zoom, size } = magnifier;
+        if zoom <= 0.0 || size.min_elem() <= 0.0 || image_rect.size().min_elem() <= 0.0 {
+            return;
+        }
+
+        let ctx = ui.ctx();
+
+        // Which part of the image to magnify, in normalized (uv) coordinates:
+        let uv_center = (pointer_pos - image_rect.min) / image_rect.size();
+        let uv_size = size / (zoom * image_rect.size());
+        let mut uv_rect = Rect::from_center_size(uv_center.to_pos2(), uv_size);
+
+        // Clamp the sampled region to the image, so we don't sample outside it near the borders:
+        uv_rect = uv_rect.translate(vec2(
+            (0.0 - uv_rect.min.x).at_least(0.0) + (1.0 - uv_rect.max.x).at_most(0.0),
+            (0.0 - uv_rect.min.y).at_least(0.0) + (1.0 - uv_rect.max.y).at_most(0.0),
+        ));
+        uv_rect = uv_rect.intersect(Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)));
+
+        // Place the inset next to the cursor, but keep it on screen:
+        let screen_rect = ctx.screen_rect();
+        let mut inset_rect = Rect::from_min_size(pointer_pos + vec2(16.0, 16.0), size);
+        inset_rect = inset_rect.translate(vec2(
+            (screen_rect.right() - inset_rect.right()).at_most(0.0),
+            (screen_rect.bottom() - inset_rect.bottom()).at_most(0.0),
+        ));
+
+        // Paint on top of everything, unclipped by the parent `Ui`:
+        let painter = ctx.layer_painter(LayerId::new(Order::Tooltip, widget_id.with("magnifier")));
+        painter.image(texture.id, inset_rect, uv_rect, self.image_options.tint);
+        painter.rect_stroke(
+            inset_rect,
+            0.0,
+            ui.visuals().window_stroke(),
+            epaint::StrokeKind::Outside,
+        );
+
+        // Pixel inspection requires CPU-side pixels,
+        // which we can get from the image loader cache:
+        if let Some(uri) = self.source.uri() {
+            if let Ok(ImagePoll::Ready { image }) = ctx.try_load_image(uri, SizeHint::default()) {
+                let [w, h] = image.size;
+                if 0 < w && 0 < h {
+                    let px = ((uv_center.x * w as f32).floor() as usize).min(w - 1);
+                    let py = ((uv_center.y * h as f32).floor() as usize).min(h - 1);
+                    let [r, g, b, a] = image.pixels[py * w + px].to_srgba_unmultiplied();
+                    let text = format!("({px}, {py}) #{r:02x}{g:02x}{b:02x}{a:02x}");
+
+                    let font_id = TextStyle::Monospace.resolve(ui.style());
+                    let galley = painter.layout_no_wrap(text, font_id, ui.visuals().text_color());
+                    let text_rect = Rect::from_min_size(
+                        inset_rect.left_bottom() + vec2(0.0, 4.0),
+                        galley.size(),
+                    );
+                    painter.rect_filled(text_rect.expand(2.0), 0.0, ui.visuals().extreme_bg_color);
+                    painter.galley(text_rect.min, galley, ui.visuals().text_color());
+                }
+            }
+        }
+    }
 }
 
 impl Widget for Image<'_> {
@@ -423,6 +543,23 @@ impl Widget for Image<'_> {
                 &self.image_options,
                 self.alt_text.as_deref(),
             );
+
+            if let Some(magnifier) = self.magnifier {
+                if let (Ok(TexturePoll::Ready { texture }), Some(pointer_pos)) =
+                    (&tlr, response.hover_pos())
+                {
+                    if rect.contains(pointer_pos) {
+                        self.paint_magnifier(
+                            ui,
+                            magnifier,
+                            rect,
+                            pointer_pos,
+                            texture,
+                            response.id,
+                        );
+                    }
+                }
+            }
         }
         texture_load_result_response(&self.source(ui.ctx()), &tlr, response)
     }
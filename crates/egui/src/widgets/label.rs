@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use crate::{
     epaint, pos2, text_selection::LabelSelectionState, Align, Direction, FontSelection, Galley,
-    Pos2, Response, Sense, Stroke, TextWrapMode, Ui, Widget, WidgetInfo, WidgetText, WidgetType,
+    Pos2, Response, Sense, Stroke, TextTruncate, TextWrapMode, Ui, Widget, WidgetInfo, WidgetText,
+    WidgetType,
 };
 
 /// Static text.
@@ -25,6 +26,7 @@ use crate::{
 pub struct Label {
     text: WidgetText,
     wrap_mode: Option<TextWrapMode>,
+    truncate_mode: Option<TextTruncate>,
     sense: Option<Sense>,
     selectable: Option<bool>,
     halign: Option<Align>,
@@ -36,6 +38,7 @@ impl Label {
         Self {
             text: text.into(),
             wrap_mode: None,
+            truncate_mode: None,
             sense: None,
             selectable: None,
             halign: None,
@@ -73,6 +76,19 @@ impl Label {
         self
     }
 
+    /// Where to remove text when truncating: at the start, middle, or end.
+    ///
+    /// For instance, [`TextTruncate::Middle`] elides long file paths
+    /// as "C:/Us…/file.txt", keeping both ends visible.
+    ///
+    /// Also sets [`Self::wrap_mode`] to [`TextWrapMode::Truncate`].
+    #[inline]
+    pub fn truncate_mode(mut self, truncate_mode: TextTruncate) -> Self {
+        self.wrap_mode = Some(TextWrapMode::Truncate);
+        self.truncate_mode = Some(truncate_mode);
+        self
+    }
+
     /// Set [`Self::wrap_mode`] to [`TextWrapMode::Extend`],
     /// disabling wrapping and truncating, and instead expanding the parent [`Ui`].
     #[inline]
@@ -239,6 +255,9 @@ impl Label {
                     layout_job.wrap.max_width = available_width;
                     layout_job.wrap.max_rows = 1;
                     layout_job.wrap.break_anywhere = true;
+                    if let Some(truncate_mode) = self.truncate_mode {
+                        layout_job.wrap.truncate = truncate_mode;
+                    }
                 }
             }
 
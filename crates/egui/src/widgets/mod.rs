@@ -19,6 +19,7 @@ mod radio_button;
 mod selected_label;
 mod separator;
 mod slider;
+mod span_label;
 mod spinner;
 pub mod text_edit;
 
@@ -38,8 +39,9 @@ pub use self::{
     selected_label::SelectableLabel,
     separator::Separator,
     slider::{Slider, SliderClamping, SliderOrientation},
+    span_label::{SpanLabel, SpanLabelOutput},
     spinner::Spinner,
-    text_edit::{TextBuffer, TextEdit},
+    text_edit::{PasteMode, TextBuffer, TextEdit},
 };
 
 // ----------------------------------------------------------------------------
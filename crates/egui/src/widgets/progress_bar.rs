@@ -1,5 +1,5 @@
 use crate::{
-    lerp, vec2, Color32, CornerRadius, NumExt as _, Pos2, Rect, Response, Rgba, Sense, Shape,
+    lerp, pos2, vec2, Color32, CornerRadius, NumExt as _, Pos2, Rect, Response, Rgba, Sense, Shape,
     Stroke, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, WidgetType,
 };
 
@@ -19,6 +19,7 @@ pub struct ProgressBar {
     text: Option<ProgressBarText>,
     fill: Option<Color32>,
     animate: bool,
+    indeterminate: bool,
     corner_radius: Option<CornerRadius>,
 }
 
@@ -32,10 +33,27 @@ impl ProgressBar {
             text: None,
             fill: None,
             animate: false,
+            indeterminate: false,
             corner_radius: None,
         }
     }
 
+    /// An indeterminate progress bar, for tasks of unknown duration.
+    ///
+    /// Instead of a filled fraction, a highlight slides back and forth over the bar,
+    /// driven by the clock, and the UI is redrawn while the bar is visible.
+    ///
+    /// If animations are disabled ([`crate::Style::animation_time`] is zero),
+    /// the bar falls back to a subtle brightness pulse.
+    ///
+    /// [`Self::text`] still works, e.g. to show a status message.
+    pub fn indeterminate() -> Self {
+        Self {
+            indeterminate: true,
+            ..Self::new(0.0)
+        }
+    }
+
     /// The desired width of the bar. Will use all horizontal space if not set.
     #[inline]
     pub fn desired_width(mut self, desired_width: f32) -> Self {
@@ -111,10 +129,11 @@ impl Widget for ProgressBar {
             text,
             fill,
             animate,
+            indeterminate,
             corner_radius,
         } = self;
 
-        let animate = animate && progress < 1.0;
+        let animate = animate && progress < 1.0 && !indeterminate;
 
         let desired_width =
             desired_width.unwrap_or_else(|| ui.available_size_before_wrap().x.at_least(96.0));
@@ -128,13 +147,15 @@ impl Widget for ProgressBar {
             } else {
                 WidgetInfo::new(WidgetType::ProgressIndicator)
             };
-            info.value = Some((progress as f64 * 100.0).floor());
+            if !indeterminate {
+                info.value = Some((progress as f64 * 100.0).floor());
+            }
 
             info
         });
 
         if ui.is_rect_visible(response.rect) {
-            if animate {
+            if animate || indeterminate {
                 ui.ctx().request_repaint();
             }
 
@@ -146,43 +167,73 @@ impl Widget for ProgressBar {
                 .rect_filled(outer_rect, corner_radius, visuals.extreme_bg_color);
             let min_width =
                 2.0 * f32::max(corner_radius.sw as _, corner_radius.nw as _).at_most(half_height);
-            let filled_width = (outer_rect.width() * progress).at_least(min_width);
-            let inner_rect =
-                Rect::from_min_size(outer_rect.min, vec2(filled_width, outer_rect.height()));
 
             let (dark, bright) = (0.7, 1.0);
-            let color_factor = if animate {
+
+            if indeterminate {
                 let time = ui.input(|i| i.time);
-                lerp(dark..=bright, time.cos().abs())
+                let fill = fill.unwrap_or(visuals.selection.bg_fill);
+
+                if ui.style().animation_time <= 0.0 {
+                    // Animations are disabled (e.g. reduced motion preference),
+                    // so pulse the whole bar instead of sliding a highlight:
+                    let color_factor = lerp(dark..=bright, time.cos().abs());
+                    ui.painter().rect_filled(
+                        outer_rect,
+                        corner_radius,
+                        Color32::from(Rgba::from(fill) * color_factor as f32),
+                    );
+                } else {
+                    let band_width = (outer_rect.width() / 3.0).at_least(min_width);
+                    let cycles_per_second = 0.7;
+                    let angle = time * cycles_per_second * std::f64::consts::TAU;
+                    // Slide back and forth:
+                    let t = 0.5 - 0.5 * angle.cos() as f32;
+                    let band_left = lerp(outer_rect.left()..=outer_rect.right() - band_width, t);
+                    let band_rect = Rect::from_min_size(
+                        pos2(band_left, outer_rect.top()),
+                        vec2(band_width, outer_rect.height()),
+                    );
+                    ui.painter().rect_filled(band_rect, corner_radius, fill);
+                }
             } else {
-                bright
-            };
+                let filled_width = (outer_rect.width() * progress).at_least(min_width);
+                let inner_rect =
+                    Rect::from_min_size(outer_rect.min, vec2(filled_width, outer_rect.height()));
+
+                let color_factor = if animate {
+                    let time = ui.input(|i| i.time);
+                    lerp(dark..=bright, time.cos().abs())
+                } else {
+                    bright
+                };
 
-            ui.painter().rect_filled(
-                inner_rect,
-                corner_radius,
-                Color32::from(
-                    Rgba::from(fill.unwrap_or(visuals.selection.bg_fill)) * color_factor as f32,
-                ),
-            );
+                ui.painter().rect_filled(
+                    inner_rect,
+                    corner_radius,
+                    Color32::from(
+                        Rgba::from(fill.unwrap_or(visuals.selection.bg_fill)) * color_factor as f32,
+                    ),
+                );
 
-            if animate && !has_custom_cr {
-                let n_points = 20;
-                let time = ui.input(|i| i.time);
-                let start_angle = time * std::f64::consts::TAU;
-                let end_angle = start_angle + 240f64.to_radians() * time.sin();
-                let circle_radius = half_height - 2.0;
-                let points: Vec<Pos2> = (0..n_points)
-                    .map(|i| {
-                        let angle = lerp(start_angle..=end_angle, i as f64 / n_points as f64);
-                        let (sin, cos) = angle.sin_cos();
-                        inner_rect.right_center()
-                            + circle_radius * vec2(cos as f32, sin as f32)
-                            + vec2(-half_height, 0.0)
-                    })
-                    .collect();
-                ui.painter()
-                    .add(Shape::line(points, Stroke::new(2.0, visuals.text_color())));
+                if animate && !has_custom_cr {
+                    let n_points = 20;
+                    let time = ui.input(|i| i.time);
+                    let start_angle = time * std::f64::consts::TAU;
+                    let end_angle = start_angle + 240f64.to_radians() * time.sin();
+                    let circle_radius = half_height - 2.0;
+                    let points: Vec<Pos2> = (0..n_points)
+                        .map(|i| {
+                            let angle = lerp(start_angle..=end_angle, i as f64 / n_points as f64);
+                            let (sin, cos) = angle.sin_cos();
+                            inner_rect.right_center()
+                                + circle_radius * vec2(cos as f32, sin as f32)
+                                + vec2(-half_height, 0.0)
+                        })
+                        .collect();
+                    ui.painter()
+                        .add(Shape::line(points, Stroke::new(2.0, visuals.text_color())));
+                }
             }
 
             if let Some(text_kind) = text {
@@ -1,4 +1,6 @@
-use crate::{vec2, Response, Sense, Ui, Vec2, Widget};
+use crate::{
+    vec2, Align, Align2, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetText,
+};
 
 /// A visual separator. A horizontal or vertical line (depending on [`crate::Layout`]).
 ///
@@ -16,6 +18,8 @@ pub struct Separator {
     spacing: f32,
     grow: f32,
     is_horizontal_line: Option<bool>,
+    text: Option<WidgetText>,
+    text_align: Align,
 }
 
 impl Default for Separator {
@@ -24,6 +28,8 @@ impl Default for Separator {
             spacing: 6.0,
             grow: 0.0,
             is_horizontal_line: None,
+            text: None,
+            text_align: Align::Center,
         }
     }
 }
@@ -83,6 +89,33 @@ impl Separator {
         self.grow -= shrink;
         self
     }
+
+    /// Show this text in a gap in the separator line.
+    ///
+    /// By default the text is centered along the line.
+    /// Use [`Self::text_align`] to change that.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(egui::Separator::default().text("OR"));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Where along the line should the text of [`Self::text`] be?
+    ///
+    /// [`Align::Min`] is the left/top end of the line, [`Align::Max`] the right/bottom end.
+    ///
+    /// Default: [`Align::Center`].
+    #[inline]
+    pub fn text_align(mut self, align: Align) -> Self {
+        self.text_align = align;
+        self
+    }
 }
 
 impl Widget for Separator {
@@ -91,6 +124,8 @@ impl Widget for Separator {
             spacing,
             grow,
             is_horizontal_line,
+            text,
+            text_align,
         } = self;
 
         let is_horizontal_line = is_horizontal_line
@@ -102,18 +137,71 @@ impl Widget for Separator {
             ui.available_size_before_wrap()
         };
 
-        let size = if is_horizontal_line {
-            vec2(available_space.x, spacing)
-        } else {
-            vec2(spacing, available_space.y)
+        let galley = text.map(|text| {
+            text.into_galley(
+                ui,
+                Some(TextWrapMode::Extend),
+                available_space.x,
+                TextStyle::Body,
+            )
+        });
+
+        let size = match (&galley, is_horizontal_line) {
+            (Some(galley), true) => vec2(available_space.x, spacing.max(galley.size().y)),
+            (Some(galley), false) => vec2(spacing.max(galley.size().x), available_space.y),
+            (None, true) => vec2(available_space.x, spacing),
+            (None, false) => vec2(spacing, available_space.y),
         };
 
         let (rect, response) = ui.allocate_at_least(size, Sense::hover());
 
         if ui.is_rect_visible(response.rect) {
             let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+            let text_gap = ui.spacing().item_spacing.x;
+            let text_color = ui.visuals().text_color();
             let painter = ui.painter();
-            if is_horizontal_line {
+            if let Some(galley) = galley {
+                // Paint the line in two segments, with a gap for the text:
+                if is_horizontal_line {
+                    let text_rect = Align2([text_align, Align::Center])
+                        .align_size_within_rect(galley.size(), rect);
+                    let y = rect.center().y;
+                    if rect.left() - grow < text_rect.left() - text_gap {
+                        painter.hline(
+                            (rect.left() - grow)..=(text_rect.left() - text_gap),
+                            y,
+                            stroke,
+                        );
+                    }
+                    if text_rect.right() + text_gap < rect.right() + grow {
+                        painter.hline(
+                            (text_rect.right() + text_gap)..=(rect.right() + grow),
+                            y,
+                            stroke,
+                        );
+                    }
+                    painter.galley(text_rect.min, galley, text_color);
+                } else {
+                    let text_rect = Align2([Align::Center, text_align])
+                        .align_size_within_rect(galley.size(), rect);
+                    let x = rect.center().x;
+                    if rect.top() - grow < text_rect.top() - text_gap {
+                        painter.vline(
+                            x,
+                            (rect.top() - grow)..=(text_rect.top() - text_gap),
+                            stroke,
+                        );
+                    }
+                    if text_rect.bottom() + text_gap < rect.bottom() + grow {
+                        painter.vline(
+                            x,
+                            (text_rect.bottom() + text_gap)..=(rect.bottom() + grow),
+                            stroke,
+                        );
+                    }
+                    painter.galley(text_rect.min, galley, text_color);
+                }
+            } else if is_horizontal_line {
                 painter.hline(
                     (rect.left() - grow)..=(rect.right() + grow),
                     rect.center().y,
@@ -3,8 +3,8 @@
 use std::ops::RangeInclusive;
 
 use crate::{
-    emath, epaint, lerp, pos2, remap, remap_clamp, style, style::HandleShape, vec2, Color32,
-    DragValue, EventFilter, Key, Label, NumExt as _, Pos2, Rangef, Rect, Response, Sense,
+    emath, epaint, lerp, pos2, remap, remap_clamp, style, style::HandleShape, vec2, Align2,
+    Color32, DragValue, EventFilter, Key, Label, NumExt as _, Pos2, Rangef, Rect, Response, Sense,
     TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, MINUS_CHAR_STR,
 };
 
@@ -118,6 +118,10 @@ pub struct Slider<'a> {
     trailing_fill: Option<bool>,
     handle_shape: Option<HandleShape>,
     update_while_editing: bool,
+
+    /// Values at which to draw tick marks on the rail.
+    ticks: Vec<f64>,
+    show_tick_labels: bool,
 }
 
 impl<'a> Slider<'a> {
@@ -169,6 +173,8 @@ impl<'a> Slider<'a> {
             trailing_fill: None,
             handle_shape: None,
             update_while_editing: true,
+            ticks: Vec::new(),
+            show_tick_labels: false,
         }
     }
 
@@ -249,6 +255,41 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Draw small tick marks on the rail at the given values.
+    ///
+    /// The positions respect the [`Self::logarithmic`] mapping of the slider,
+    /// so e.g. `[1.0, 10.0, 100.0]` gives evenly spaced ticks
+    /// on a logarithmic `1..=100` slider.
+    /// Ticks outside the slider range are not drawn.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut frequency: f64 = 440.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut frequency, 10.0..=10_000.0)
+    ///         .logarithmic(true)
+    ///         .ticks([10.0, 100.0, 1_000.0, 10_000.0])
+    ///         .tick_labels(true),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = f64>) -> Self {
+        self.ticks = ticks.into_iter().collect();
+        self
+    }
+
+    /// Label each tick of [`Self::ticks`] with its value.
+    ///
+    /// The labels are formatted like the slider value,
+    /// i.e. with [`Self::custom_formatter`] if one is set.
+    /// The default is OFF.
+    #[inline]
+    pub fn tick_labels(mut self, show_tick_labels: bool) -> Self {
+        self.show_tick_labels = show_tick_labels;
+        self
+    }
+
     /// Controls when the values will be clamped to the range.
     ///
     /// ### With `.clamping(SliderClamping::Always)` (default)
@@ -687,8 +728,7 @@ impl Slider<'_> {
             self.set_value(new_value);
         }
 
-        let mut decrement = 0usize;
-        let mut increment = 0usize;
+        let mut kb_step = 0.0f32;
 
         if response.has_focus() {
             ui.ctx().memory_mut(|m| {
@@ -715,8 +755,23 @@ impl Slider<'_> {
             };
 
             ui.input(|input| {
-                decrement += input.num_presses(dec_key);
-                increment += input.num_presses(inc_key);
+                // Shift is for coarse steps, Ctrl/Cmd or Alt for fine steps:
+                let step_scale = if input.modifiers.shift {
+                    10.0
+                } else if input.modifiers.command || input.modifiers.alt {
+                    0.1
+                } else {
+                    1.0
+                };
+
+                kb_step += step_scale
+                    * (input.num_presses(inc_key) as f32 - input.num_presses(dec_key) as f32);
+
+                // PageUp/PageDown jump by 10 steps:
+                kb_step += 10.0
+                    * step_scale
+                    * (input.num_presses(Key::PageUp) as f32
+                        - input.num_presses(Key::PageDown) as f32);
             });
         }
 
@@ -724,13 +779,12 @@ impl Slider<'_> {
         {
             use accesskit::Action;
             ui.input(|input| {
-                decrement += input.num_accesskit_action_requests(response.id, Action::Decrement);
-                increment += input.num_accesskit_action_requests(response.id, Action::Increment);
+                kb_step += input.num_accesskit_action_requests(response.id, Action::Increment)
+                    as f32
+                    - input.num_accesskit_action_requests(response.id, Action::Decrement) as f32;
             });
         }
 
-        let kb_step = increment as f32 - decrement as f32;
-
         if kb_step != 0.0 {
             let ui_point_per_step = 1.0; // move this many ui points for each kb_step
             let prev_value = self.get_value();
@@ -739,7 +793,8 @@ impl Slider<'_> {
             let new_value = match self.step {
                 Some(step) => prev_value + (kb_step as f64 * step),
                 None if self.smart_aim => {
-                    let aim_radius = 0.49 * ui_point_per_step; // Chosen so we don't include `prev_value` in the search.
+                    // Chosen so we don't include `prev_value` in the search:
+                    let aim_radius = 0.49 * ui_point_per_step * kb_step.abs().min(1.0);
                     emath::smart_aim::best_in_range_f64(
                         self.value_from_position(new_position - aim_radius, position_range),
                         self.value_from_position(new_position + aim_radius, position_range),
@@ -806,6 +861,10 @@ impl Slider<'_> {
                 );
             }
 
+            if !self.ticks.is_empty() {
+                self.ticks_ui(ui, &rail_rect, position_range, rail_radius);
+            }
+
             let radius = self.handle_radius(rect);
 
             let handle_shape = self
@@ -839,6 +898,59 @@ impl Slider<'_> {
         }
     }
 
+    /// Paint the tick marks of [`Self::ticks`], with optional labels.
+    fn ticks_ui(&self, ui: &Ui, rail_rect: &Rect, position_range: Rangef, rail_radius: f32) {
+        let stroke = ui.visuals().widgets.inactive.fg_stroke;
+        let tick_radius = rail_radius + 2.0;
+
+        // The range can go from high to low:
+        let (min, max) = (
+            self.range.start().min(*self.range.end()),
+            self.range.start().max(*self.range.end()),
+        );
+
+        for &tick in &self.ticks {
+            if tick < min || max < tick {
+                continue;
+            }
+
+            let position_1d = self.position_from_value(tick, position_range);
+            let center = self.marker_center(position_1d, rail_rect);
+
+            let (p0, p1) = match self.orientation {
+                SliderOrientation::Horizontal => (
+                    pos2(center.x, center.y - tick_radius),
+                    pos2(center.x, center.y + tick_radius),
+                ),
+                SliderOrientation::Vertical => (
+                    pos2(center.x - tick_radius, center.y),
+                    pos2(center.x + tick_radius, center.y),
+                ),
+            };
+            ui.painter().line_segment([p0, p1], stroke);
+
+            if self.show_tick_labels {
+                let max_decimals = self.max_decimals.unwrap_or(6);
+                let text = if let Some(custom_formatter) = &self.custom_formatter {
+                    custom_formatter(tick, self.min_decimals..=max_decimals)
+                } else {
+                    emath::format_with_decimals_in_range(tick, self.min_decimals..=max_decimals)
+                };
+                let (anchor, text_pos) = match self.orientation {
+                    SliderOrientation::Horizontal => (Align2::CENTER_TOP, p1 + vec2(0.0, 1.0)),
+                    SliderOrientation::Vertical => (Align2::LEFT_CENTER, p1 + vec2(1.0, 0.0)),
+                };
+                ui.painter().text(
+                    text_pos,
+                    anchor,
+                    text,
+                    TextStyle::Small.resolve(ui.style()),
+                    ui.visuals().text_color(),
+                );
+            }
+        }
+    }
+
     fn marker_center(&self, position_1d: f32, rail_rect: &Rect) -> Pos2 {
         match self.orientation {
             SliderOrientation::Horizontal => pos2(position_1d, rail_rect.center().y),
@@ -957,9 +1069,13 @@ impl Slider<'_> {
             self.set_value(old_value);
         }
 
-        let thickness = ui
+        let mut thickness = ui
             .text_style_height(&TextStyle::Body)
             .at_least(ui.spacing().interact_size.y);
+        if self.show_tick_labels && !self.ticks.is_empty() {
+            // Make room for the tick labels:
+            thickness += 2.0 * ui.text_style_height(&TextStyle::Small);
+        }
         let mut response = self.allocate_slider_space(ui, thickness);
         self.slider_ui(ui, &response);
 
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use epaint::{
+    text::{Galley, LayoutJob},
+    TextShape,
+};
+
+use crate::{CursorIcon, Pos2, Response, Sense, Ui, Widget, WidgetInfo, WidgetType};
+
+/// A paragraph laid out from a [`LayoutJob`] where the individual sections
+/// act as their own hover/click targets.
+///
+/// Each [`epaint::text::LayoutSection`] of the job is one "span".
+/// The output tells you which span (if any) is hovered or was clicked,
+/// identified by its index into [`LayoutJob::sections`].
+/// This makes it possible to have clickable words (@mentions, hashtags, inline links, …)
+/// inside a flowing, wrapping paragraph without splitting it into multiple labels.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::text::LayoutJob;
+/// use egui::{Color32, FontId, TextFormat};
+///
+/// let mut job = LayoutJob::default();
+/// job.append("Hello ", 0.0, TextFormat::default());
+/// job.append(
+///     "@world",
+///     0.0,
+///     TextFormat::simple(FontId::default(), Color32::BLUE),
+/// );
+/// let mention_span = 1; // index of the "@world" section
+///
+/// let output = egui::SpanLabel::new(job).show(ui);
+/// if output.clicked_span == Some(mention_span) {
+///     // …
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct SpanLabel {
+    job: LayoutJob,
+    sense: Sense,
+}
+
+/// What [`SpanLabel::show`] returns.
+pub struct SpanLabelOutput {
+    /// The response for the label as a whole.
+    pub response: Response,
+
+    /// The laid out text.
+    pub galley: Arc<Galley>,
+
+    /// Index into [`LayoutJob::sections`] of the span under the pointer, if any.
+    pub hovered_span: Option<usize>,
+
+    /// Index into [`LayoutJob::sections`] of the span that was clicked, if any.
+    pub clicked_span: Option<usize>,
+}
+
+impl SpanLabel {
+    pub fn new(job: LayoutJob) -> Self {
+        Self {
+            job,
+            sense: Sense::click(),
+        }
+    }
+
+    /// By default the label senses clicks.
+    /// Change this to e.g. [`Sense::hover`] if you only care about [`SpanLabelOutput::hovered_span`].
+    #[inline]
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.sense = sense;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> SpanLabelOutput {
+        let Self { mut job, sense } = self;
+
+        if job.wrap.max_width == f32::INFINITY {
+            job.wrap.max_width = ui.available_width();
+        }
+        let galley = ui.fonts(|fonts| fonts.layout_job(job));
+
+        let (rect, response) = ui.allocate_exact_size(galley.size(), sense);
+        response
+            .widget_info(|| WidgetInfo::labeled(WidgetType::Label, ui.is_enabled(), galley.text()));
+
+        let hovered_span = response
+            .hover_pos()
+            .and_then(|pos| span_at(&galley, pos - rect.min.to_vec2()));
+        let clicked_span = if response.clicked() {
+            response
+                .interact_pointer_pos()
+                .and_then(|pos| span_at(&galley, pos - rect.min.to_vec2()))
+        } else {
+            None
+        };
+
+        if ui.is_rect_visible(rect) {
+            let fallback_color = ui.visuals().text_color();
+            ui.painter()
+                .add(TextShape::new(rect.min, galley.clone(), fallback_color));
+
+            if hovered_span.is_some() {
+                ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+            }
+        }
+
+        SpanLabelOutput {
+            response,
+            galley,
+            hovered_span,
+            clicked_span,
+        }
+    }
+}
+
+impl Widget for SpanLabel {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// Which section of the galley's [`LayoutJob`] contains the given galley-relative position?
+///
+/// Uses the glyph rects for hit-testing, so only the actual text is a hit target —
+/// not the whitespace to the right of a short row.
+fn span_at(galley: &Galley, pos: Pos2) -> Option<usize> {
+    let mut char_offset = 0;
+    for placed_row in &galley.rows {
+        if placed_row.rect().y_range().contains(pos.y) {
+            let x = pos.x - placed_row.pos.x;
+            for (i, glyph) in placed_row.glyphs.iter().enumerate() {
+                if glyph.pos.x <= x && x < glyph.max_x() {
+                    return section_containing_char(&galley.job, char_offset + i);
+                }
+            }
+        }
+        char_offset += placed_row.char_count_including_newline();
+    }
+    None
+}
+
+fn section_containing_char(job: &LayoutJob, char_index: usize) -> Option<usize> {
+    let (byte_offset, _) = job.text.char_indices().nth(char_index)?;
+    job.sections
+        .iter()
+        .position(|section| section.byte_range.contains(&byte_offset))
+}
@@ -11,6 +11,12 @@ pub struct Spinner {
     /// Uses the style's `interact_size` if `None`.
     size: Option<f32>,
     color: Option<Color32>,
+
+    /// Fixed fraction of the full circle covered by the arc. Pulsates if `None`.
+    arc_fraction: Option<f32>,
+
+    /// Stroke width of the arc. Default: 3.0.
+    thickness: Option<f32>,
 }
 
 impl Spinner {
@@ -34,19 +40,47 @@ impl Spinner {
         self
     }
 
+    /// Sets the fraction of the full circle covered by the arc, in the `0.0..=1.0` range.
+    ///
+    /// E.g. `0.25` gives a thin quarter-circle loader, and values close to `1.0` a near-full ring.
+    /// If not set, the arc sweep pulsates (the default look).
+    #[inline]
+    pub fn arc_fraction(mut self, arc_fraction: f32) -> Self {
+        self.arc_fraction = Some(arc_fraction.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets the stroke width of the arc. Default: 3.0.
+    #[inline]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = Some(thickness);
+        self
+    }
+
     /// Paint the spinner in the given rectangle.
     pub fn paint_at(&self, ui: &Ui, rect: Rect) {
         if ui.is_rect_visible(rect) {
-            ui.ctx().request_repaint(); // because it is animated
+            // Respect a zero animation time (e.g. set when the OS asks for reduced motion)
+            // by drawing a static arc instead of spinning.
+            let animated = ui.style().animation_time > 0.0;
+            if animated {
+                ui.ctx().request_repaint(); // because it is animated
+            }
 
             let color = self
                 .color
                 .unwrap_or_else(|| ui.visuals().strong_text_color());
+            let thickness = self.thickness.unwrap_or(3.0);
             let radius = (rect.height() / 2.0) - 2.0;
             let n_points = (radius.round() as u32).clamp(8, 128);
-            let time = ui.input(|i| i.time);
+            let time = if animated { ui.input(|i| i.time) } else { 0.0 };
             let start_angle = time * std::f64::consts::TAU;
-            let end_angle = start_angle + 240f64.to_radians() * time.sin();
+            let sweep = match self.arc_fraction {
+                Some(arc_fraction) => f64::from(arc_fraction) * std::f64::consts::TAU,
+                None if animated => 240f64.to_radians() * time.sin(),
+                None => 240f64.to_radians(),
+            };
+            let end_angle = start_angle + sweep;
             let points: Vec<Pos2> = (0..n_points)
                 .map(|i| {
                     let angle = lerp(start_angle..=end_angle, i as f64 / n_points as f64);
@@ -55,7 +89,7 @@ impl Spinner {
                 })
                 .collect();
             ui.painter()
-                .add(Shape::line(points, Stroke::new(3.0, color)));
+                .add(Shape::line(points, Stroke::new(thickness, color)));
         }
     }
 }
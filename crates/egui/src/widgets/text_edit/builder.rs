@@ -10,8 +10,12 @@ use crate::{
     epaint,
     os::OperatingSystem,
     output::OutputEvent,
-    response, text_selection,
-    text_selection::{text_cursor_state::cursor_rect, visuals::paint_text_selection, CCursorRange},
+    pos2, response, text_selection,
+    text_selection::{
+        text_cursor_state::{byte_index_from_char_index, char_index_from_byte_index, cursor_rect},
+        visuals::paint_text_selection,
+        CCursorRange,
+    },
     vec2, Align, Align2, Color32, Context, CursorIcon, Event, EventFilter, FontSelection, Id,
     ImeEvent, Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, Response, Sense, Shape,
     TextBuffer, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, WidgetWithState,
@@ -89,6 +93,26 @@ pub struct TextEdit<'t> {
     char_limit: usize,
     return_key: Option<KeyboardShortcut>,
     background_color: Option<Color32>,
+    single_line_paste: PasteMode,
+    show_line_numbers: bool,
+}
+
+/// How a single-line [`TextEdit`] handles pasted text containing newlines.
+///
+/// See [`TextEdit::single_line_paste`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PasteMode {
+    /// Replace newlines with spaces, like most native single-line inputs.
+    ///
+    /// This is the default.
+    #[default]
+    ReplaceNewlinesWithSpaces,
+
+    /// Remove newlines entirely.
+    StripNewlines,
+
+    /// Insert the pasted text unchanged, newlines and all.
+    Raw,
 }
 
 impl WidgetWithState for TextEdit<'_> {
@@ -148,6 +172,8 @@ impl<'t> TextEdit<'t> {
             char_limit: usize::MAX,
             return_key: Some(KeyboardShortcut::new(Modifiers::NONE, Key::Enter)),
             background_color: None,
+            single_line_paste: PasteMode::default(),
+            show_line_numbers: false,
         }
     }
 
@@ -155,10 +181,39 @@ impl<'t> TextEdit<'t> {
     /// By default it comes with:
     /// - monospaced font
     /// - focus lock (tab will insert a tab character instead of moving focus)
+    ///
+    /// See also [`Self::show_line_numbers`] for an optional line-number gutter.
     pub fn code_editor(self) -> Self {
         self.font(TextStyle::Monospace).lock_focus(true)
     }
 
+    /// Show a line-number gutter to the left of the text.
+    ///
+    /// The numbers are right-aligned in a gutter that auto-sizes to the digit
+    /// count of the last line number.
+    /// They follow the row layout of the galley, so a line that wraps over
+    /// several rows is only numbered once, on its first row.
+    /// The number of the line containing the primary cursor is emphasized.
+    ///
+    /// Only has an effect on multiline text edits.
+    /// Most useful together with [`Self::code_editor`]:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut code = String::new();
+    /// ui.add(
+    ///     egui::TextEdit::multiline(&mut code)
+    ///         .code_editor()
+    ///         .show_line_numbers(true),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn show_line_numbers(mut self, show_line_numbers: bool) -> Self {
+        self.show_line_numbers = show_line_numbers;
+        self
+    }
+
     /// Use if you want to set an explicit [`Id`] for this widget.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
@@ -396,6 +451,19 @@ impl<'t> TextEdit<'t> {
         self.return_key = return_key.into();
         self
     }
+
+    /// How pasted text containing newlines is handled when this is a single-line [`TextEdit`].
+    ///
+    /// By default newlines are replaced with spaces ([`PasteMode::ReplaceNewlinesWithSpaces`]),
+    /// matching native single-line inputs and avoiding surprising `\n` in the value.
+    /// Use [`PasteMode::Raw`] if your app wants the raw paste.
+    ///
+    /// Has no effect on multiline [`TextEdit`]s.
+    #[inline]
+    pub fn single_line_paste(mut self, paste_mode: PasteMode) -> Self {
+        self.single_line_paste = paste_mode;
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -493,6 +561,8 @@ impl TextEdit<'_> {
             char_limit,
             return_key,
             background_color: _,
+            single_line_paste,
+            show_line_numbers,
         } = self;
 
         let text_color = text_color
@@ -508,11 +578,22 @@ impl TextEdit<'_> {
         const MIN_WIDTH: f32 = 24.0; // Never make a [`TextEdit`] more narrow than this.
         let available_width = (ui.available_width() - margin.sum().x).at_least(MIN_WIDTH);
         let desired_width = desired_width.unwrap_or_else(|| ui.spacing().text_edit_width);
+        const GUTTER_PADDING: f32 = 4.0; // On each side of the line numbers.
+        let gutter_width = if show_line_numbers && multiline {
+            let num_lines = text.as_str().lines().count().max(1);
+            let num_digits = num_lines.to_string().len();
+            let digit_width = ui.fonts(|f| f.glyph_width(&font_id, '0'));
+            num_digits as f32 * digit_width + 2.0 * GUTTER_PADDING
+        } else {
+            0.0
+        };
+
         let wrap_width = if ui.layout().horizontal_justify() {
             available_width
         } else {
             desired_width.min(available_width)
         };
+        let wrap_width = (wrap_width - gutter_width).at_least(MIN_WIDTH);
 
         let font_id_clone = font_id.clone();
         let mut default_layouter = move |ui: &Ui, text: &dyn TextBuffer, wrap_width: f32| {
@@ -535,10 +616,16 @@ impl TextEdit<'_> {
             galley.size().x.max(wrap_width)
         };
         let desired_height = (desired_height_rows.at_least(1) as f32) * row_height;
-        let desired_inner_size = vec2(desired_inner_width, galley.size().y.max(desired_height));
+        let desired_inner_size = vec2(
+            desired_inner_width + gutter_width,
+            galley.size().y.max(desired_height),
+        );
         let desired_outer_size = (desired_inner_size + margin.sum()).at_least(min_size);
         let (auto_id, outer_rect) = ui.allocate_space(desired_outer_size);
-        let rect = outer_rect - margin; // inner rect (excluding frame/margin).
+        let inner_rect = outer_rect - margin; // inner rect (excluding frame/margin).
+
+        // The gutter (if any) is to the left, and the text to the right of it:
+        let rect = Rect::from_min_max(inner_rect.min + vec2(gutter_width, 0.0), inner_rect.max);
 
         let id = id.unwrap_or_else(|| {
             if let Some(id_salt) = id_salt {
@@ -570,7 +657,7 @@ impl TextEdit<'_> {
 
         // Don't sent `OutputEvent::Clicked` when a user presses the space bar
         response.flags -= response::Flags::FAKE_PRIMARY_CLICKED;
-        let text_clip_rect = rect;
+        let text_clip_rect = inner_rect; // includes the gutter (if any)
         let painter = ui.painter_at(text_clip_rect.expand(1.0)); // expand to avoid clipping cursor
 
         if interactive {
@@ -595,6 +682,20 @@ impl TextEdit<'_> {
                     text_selection::visuals::paint_cursor_end(&painter, ui.visuals(), cursor_rect);
                 }
 
+                if response.hovered() && ui.input(|i| i.pointer.any_pressed()) {
+                    let modifiers = ui.input(|i| i.modifiers);
+                    if modifiers.command && !modifiers.shift && ui.memory(|mem| mem.has_focus(id)) {
+                        // Ctrl+click (cmd+click on Mac) adds a new cursor,
+                        // keeping the old one as an extra cursor:
+                        if let Some(prev_cursor_range) = state.cursor.char_range() {
+                            state.extra_cursors.push(prev_cursor_range);
+                        }
+                    } else if !modifiers.shift {
+                        // A plain click collapses back to a single cursor:
+                        state.extra_cursors.clear();
+                    }
+                }
+
                 let is_being_dragged = ui.ctx().is_being_dragged(response.id);
                 let did_interact = state.cursor.pointer_interaction(
                     ui,
@@ -609,6 +710,16 @@ impl TextEdit<'_> {
 
                     state.last_interaction_time = ui.ctx().input(|i| i.time);
                 }
+
+                if clip_text
+                    && is_being_dragged
+                    && (pointer_pos.x < rect.left() || rect.right() < pointer_pos.x)
+                {
+                    // The user is drag-selecting past the horizontal edge of the field.
+                    // `singleline_offset` chases the cursor (see below), so keep repainting
+                    // to auto-scroll even while the pointer is held still:
+                    ui.ctx().request_repaint();
+                }
             }
         }
 
@@ -641,6 +752,7 @@ impl TextEdit<'_> {
                 char_limit,
                 event_filter,
                 return_key,
+                single_line_paste,
             );
 
             if changed {
@@ -694,7 +806,7 @@ impl TextEdit<'_> {
         if ui.is_rect_visible(rect) {
             if text.as_str().is_empty() && !hint_text.is_empty() {
                 let hint_text_color = ui.visuals().weak_text_color();
-                let hint_text_font_id = hint_text_font.unwrap_or(font_id.into());
+                let hint_text_font_id = hint_text_font.unwrap_or(font_id.clone().into());
                 let galley = if multiline {
                     hint_text.into_galley(
                         ui,
@@ -724,6 +836,9 @@ impl TextEdit<'_> {
                     // Add text selection rectangles to the galley:
                     paint_text_selection(&mut galley, ui.visuals(), &cursor_range, None);
                 }
+                for extra_cursor in &state.extra_cursors {
+                    paint_text_selection(&mut galley, ui.visuals(), extra_cursor, None);
+                }
             }
 
             if !clip_text {
@@ -739,10 +854,41 @@ impl TextEdit<'_> {
                 }
             }
 
+            if 0.0 < gutter_width {
+                let cursor_row = if has_focus {
+                    state
+                        .cursor
+                        .char_range()
+                        .map(|cursor_range| galley.layout_from_cursor(cursor_range.primary).row)
+                } else {
+                    None
+                };
+                paint_line_numbers(
+                    &painter,
+                    ui.visuals(),
+                    &galley,
+                    galley_pos,
+                    font_id.clone(),
+                    inner_rect.left() + gutter_width - GUTTER_PADDING,
+                    cursor_row,
+                );
+            }
+
             painter.galley(galley_pos, galley.clone(), text_color);
 
             if has_focus {
                 if let Some(cursor_range) = state.cursor.char_range() {
+                    if state.ime_enabled {
+                        // Underline the IME pre-edit text so the user can see what is being composed:
+                        text_selection::visuals::paint_ime_preedit_underline(
+                            &painter,
+                            ui.visuals(),
+                            &galley,
+                            galley_pos,
+                            &cursor_range,
+                        );
+                    }
+
                     let primary_cursor_rect =
                         cursor_rect(&galley, &cursor_range.primary, row_height)
                             .translate(galley_pos.to_vec2());
@@ -770,6 +916,17 @@ impl TextEdit<'_> {
                                 primary_cursor_rect,
                                 now - state.last_interaction_time,
                             );
+                            for extra_cursor in &state.extra_cursors {
+                                let extra_cursor_rect =
+                                    cursor_rect(&galley, &extra_cursor.primary, row_height)
+                                        .translate(galley_pos.to_vec2());
+                                text_selection::visuals::paint_text_cursor(
+                                    ui,
+                                    &painter,
+                                    extra_cursor_rect,
+                                    now - state.last_interaction_time,
+                                );
+                            }
                         }
 
                         // Set IME output (in screen coords) when text is editable and visible
@@ -861,6 +1018,55 @@ impl TextEdit<'_> {
     }
 }
 
+/// Paint right-aligned line numbers next to the rows of `galley`.
+///
+/// A line that wraps over several rows is only numbered on its first row.
+/// The line containing `cursor_row` (if any) gets an emphasized number.
+fn paint_line_numbers(
+    painter: &crate::Painter,
+    visuals: &crate::Visuals,
+    galley: &Galley,
+    galley_pos: crate::Pos2,
+    font_id: crate::FontId,
+    right_edge: f32,
+    cursor_row: Option<usize>,
+) {
+    let mut line_number = 0;
+    let mut is_line_start = true;
+    for (ri, placed_row) in galley.rows.iter().enumerate() {
+        if is_line_start {
+            line_number += 1;
+        }
+
+        if Some(ri) == cursor_row {
+            break;
+        }
+        is_line_start = placed_row.ends_with_newline;
+    }
+    let current_line = cursor_row.map(|_| line_number);
+
+    let mut line_number = 0;
+    let mut is_line_start = true;
+    for placed_row in &galley.rows {
+        if is_line_start {
+            line_number += 1;
+            let color = if Some(line_number) == current_line {
+                visuals.strong_text_color()
+            } else {
+                visuals.weak_text_color()
+            };
+            painter.text(
+                pos2(right_edge, galley_pos.y + placed_row.pos.y),
+                Align2::RIGHT_TOP,
+                line_number.to_string(),
+                font_id.clone(),
+                color,
+            );
+        }
+        is_line_start = placed_row.ends_with_newline;
+    }
+}
+
 fn mask_if_password(is_password: bool, text: &str) -> String {
     fn mask_password(text: &str) -> String {
         std::iter::repeat(epaint::text::PASSWORD_REPLACEMENT_CHAR)
@@ -893,10 +1099,12 @@ fn events(
     char_limit: usize,
     event_filter: EventFilter,
     return_key: Option<KeyboardShortcut>,
+    single_line_paste: PasteMode,
 ) -> (bool, CCursorRange) {
     let os = ui.ctx().os();
 
     let mut cursor_range = state.cursor.char_range().unwrap_or(default_cursor_range);
+    let mut extra_cursors = std::mem::take(&mut state.extra_cursors);
 
     // We feed state to the undoer both before and after handling input
     // so that the undoer creates automatic saves even when there are no events for a while.
@@ -915,6 +1123,21 @@ fn events(
 
     let mut events = ui.input(|i| i.filtered_events(&event_filter));
 
+    if !multiline && single_line_paste != PasteMode::Raw {
+        for event in &mut events {
+            if let Event::Paste(text_to_insert) = event {
+                let newline_replacement = match single_line_paste {
+                    PasteMode::ReplaceNewlinesWithSpaces => " ",
+                    PasteMode::StripNewlines => "",
+                    PasteMode::Raw => unreachable!(),
+                };
+                *text_to_insert = text_to_insert
+                    .replace("\r\n", newline_replacement)
+                    .replace(['\n', '\r'], newline_replacement);
+            }
+        }
+    }
+
     if state.ime_enabled {
         remove_ime_incompatible_events(&mut events);
         // Process IME events first:
@@ -922,177 +1145,245 @@ fn events(
     }
 
     for event in &events {
-        let did_mutate_text = match event {
-            // First handle events that only changes the selection cursor, not the text:
-            event if cursor_range.on_event(os, event, galley, id) => None,
-
-            Event::Copy => {
-                if cursor_range.is_empty() {
-                    None
-                } else {
-                    copy_if_not_password(ui, cursor_range.slice_str(text.as_str()).to_owned());
-                    None
-                }
-            }
-            Event::Cut => {
-                if cursor_range.is_empty() {
-                    None
-                } else {
-                    copy_if_not_password(ui, cursor_range.slice_str(text.as_str()).to_owned());
-                    Some(CCursorRange::one(text.delete_selected(&cursor_range)))
-                }
-            }
-            Event::Paste(text_to_insert) => {
-                if !text_to_insert.is_empty() {
-                    let mut ccursor = text.delete_selected(&cursor_range);
-
-                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
-
-                    Some(CCursorRange::one(ccursor))
-                } else {
-                    None
-                }
+        if let Event::Key {
+            key: Key::D,
+            pressed: true,
+            modifiers,
+            ..
+        } = event
+        {
+            if modifiers.matches_logically(Modifiers::COMMAND) {
+                // Add the next occurrence of the selected text as an extra cursor:
+                select_next_occurrence(text.as_str(), &mut cursor_range, &mut extra_cursors);
+                continue;
             }
-            Event::Text(text_to_insert) => {
-                // Newlines are handled by `Key::Enter`.
-                if !text_to_insert.is_empty() && text_to_insert != "\n" && text_to_insert != "\r" {
-                    let mut ccursor = text.delete_selected(&cursor_range);
+        }
 
-                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+        let multi_cursor_outcome = if extra_cursors.is_empty() {
+            None
+        } else {
+            on_multi_cursor_event(
+                os,
+                event,
+                text,
+                galley,
+                char_limit,
+                &mut cursor_range,
+                &mut extra_cursors,
+            )
+        };
 
-                    Some(CCursorRange::one(ccursor))
-                } else {
-                    None
-                }
-            }
-            Event::Key {
-                key: Key::Tab,
-                pressed: true,
-                modifiers,
-                ..
-            } if multiline => {
-                let mut ccursor = text.delete_selected(&cursor_range);
-                if modifiers.shift {
-                    // TODO(emilk): support removing indentation over a selection?
-                    text.decrease_indentation(&mut ccursor);
-                } else {
-                    text.insert_text_at(&mut ccursor, "\t", char_limit);
-                }
-                Some(CCursorRange::one(ccursor))
+        let did_mutate_text = match multi_cursor_outcome {
+            Some(true) => {
+                // `on_multi_cursor_event` already updated the cursors:
+                Some(cursor_range)
             }
-            Event::Key {
-                key,
-                pressed: true,
-                modifiers,
-                ..
-            } if return_key.is_some_and(|return_key| {
-                *key == return_key.logical_key && modifiers.matches_logically(return_key.modifiers)
-            }) =>
-            {
-                if multiline {
-                    let mut ccursor = text.delete_selected(&cursor_range);
-                    text.insert_text_at(&mut ccursor, "\n", char_limit);
-                    // TODO(emilk): if code editor, auto-indent by same leading tabs, + one if the lines end on an opening bracket
-                    Some(CCursorRange::one(ccursor))
-                } else {
-                    ui.memory_mut(|mem| mem.surrender_focus(id)); // End input with enter
-                    break;
-                }
-            }
-
-            Event::Key {
-                key,
-                pressed: true,
-                modifiers,
-                ..
-            } if (modifiers.matches_logically(Modifiers::COMMAND) && *key == Key::Y)
-                || (modifiers.matches_logically(Modifiers::SHIFT | Modifiers::COMMAND)
-                    && *key == Key::Z) =>
-            {
-                if let Some((redo_ccursor_range, redo_txt)) = state
-                    .undoer
-                    .lock()
-                    .redo(&(cursor_range, text.as_str().to_owned()))
+            Some(false) => None,
+            None => {
+                if !extra_cursors.is_empty()
+                    && matches!(
+                        event,
+                        Event::Key { pressed: true, .. }
+                            | Event::Cut
+                            | Event::Paste(_)
+                            | Event::Text(_)
+                            | Event::Ime(_)
+                    )
                 {
-                    text.replace_with(redo_txt);
-                    Some(*redo_ccursor_range)
-                } else {
-                    None
+                    // An event we don't handle with multiple cursors - collapse to one:
+                    extra_cursors.clear();
                 }
-            }
 
-            Event::Key {
-                key: Key::Z,
-                pressed: true,
-                modifiers,
-                ..
-            } if modifiers.matches_logically(Modifiers::COMMAND) => {
-                if let Some((undo_ccursor_range, undo_txt)) = state
-                    .undoer
-                    .lock()
-                    .undo(&(cursor_range, text.as_str().to_owned()))
-                {
-                    text.replace_with(undo_txt);
-                    Some(*undo_ccursor_range)
-                } else {
-                    None
-                }
-            }
+                match event {
+                    // First handle events that only changes the selection cursor, not the text:
+                    event if cursor_range.on_event(os, event, galley, id) => None,
 
-            Event::Key {
-                modifiers,
-                key,
-                pressed: true,
-                ..
-            } => check_for_mutating_key_press(os, &cursor_range, text, galley, modifiers, *key),
-
-            Event::Ime(ime_event) => match ime_event {
-                ImeEvent::Enabled => {
-                    state.ime_enabled = true;
-                    state.ime_cursor_range = cursor_range;
-                    None
-                }
-                ImeEvent::Preedit(text_mark) => {
-                    if text_mark == "\n" || text_mark == "\r" {
-                        None
-                    } else {
-                        // Empty prediction can be produced when user press backspace
-                        // or escape during IME, so we clear current text.
-                        let mut ccursor = text.delete_selected(&cursor_range);
-                        let start_cursor = ccursor;
-                        if !text_mark.is_empty() {
-                            text.insert_text_at(&mut ccursor, text_mark, char_limit);
+                    Event::Copy => {
+                        if cursor_range.is_empty() {
+                            None
+                        } else {
+                            copy_if_not_password(
+                                ui,
+                                cursor_range.slice_str(text.as_str()).to_owned(),
+                            );
+                            None
                         }
-                        state.ime_cursor_range = cursor_range;
-                        Some(CCursorRange::two(start_cursor, ccursor))
                     }
-                }
-                ImeEvent::Commit(prediction) => {
-                    if prediction == "\n" || prediction == "\r" {
-                        None
-                    } else {
-                        state.ime_enabled = false;
+                    Event::Cut => {
+                        if cursor_range.is_empty() {
+                            None
+                        } else {
+                            copy_if_not_password(
+                                ui,
+                                cursor_range.slice_str(text.as_str()).to_owned(),
+                            );
+                            Some(CCursorRange::one(text.delete_selected(&cursor_range)))
+                        }
+                    }
+                    Event::Paste(text_to_insert) => {
+                        if !text_to_insert.is_empty() {
+                            let mut ccursor = text.delete_selected(&cursor_range);
+
+                            text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
 
-                        if !prediction.is_empty()
-                            && cursor_range.secondary.index
-                                == state.ime_cursor_range.secondary.index
+                            Some(CCursorRange::one(ccursor))
+                        } else {
+                            None
+                        }
+                    }
+                    Event::Text(text_to_insert) => {
+                        // Newlines are handled by `Key::Enter`.
+                        if !text_to_insert.is_empty()
+                            && text_to_insert != "\n"
+                            && text_to_insert != "\r"
                         {
                             let mut ccursor = text.delete_selected(&cursor_range);
-                            text.insert_text_at(&mut ccursor, prediction, char_limit);
+
+                            text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+
                             Some(CCursorRange::one(ccursor))
                         } else {
-                            let ccursor = cursor_range.primary;
+                            None
+                        }
+                    }
+                    Event::Key {
+                        key: Key::Tab,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } if multiline => {
+                        let mut ccursor = text.delete_selected(&cursor_range);
+                        if modifiers.shift {
+                            // TODO(emilk): support removing indentation over a selection?
+                            text.decrease_indentation(&mut ccursor);
+                        } else {
+                            text.insert_text_at(&mut ccursor, "\t", char_limit);
+                        }
+                        Some(CCursorRange::one(ccursor))
+                    }
+                    Event::Key {
+                        key,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } if return_key.is_some_and(|return_key| {
+                        *key == return_key.logical_key
+                            && modifiers.matches_logically(return_key.modifiers)
+                    }) =>
+                    {
+                        if multiline {
+                            let mut ccursor = text.delete_selected(&cursor_range);
+                            text.insert_text_at(&mut ccursor, "\n", char_limit);
+                            // TODO(emilk): if code editor, auto-indent by same leading tabs, + one if the lines end on an opening bracket
                             Some(CCursorRange::one(ccursor))
+                        } else {
+                            ui.memory_mut(|mem| mem.surrender_focus(id)); // End input with enter
+                            break;
+                        }
+                    }
+
+                    Event::Key {
+                        key,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } if (modifiers.matches_logically(Modifiers::COMMAND) && *key == Key::Y)
+                        || (modifiers.matches_logically(Modifiers::SHIFT | Modifiers::COMMAND)
+                            && *key == Key::Z) =>
+                    {
+                        if let Some((redo_ccursor_range, redo_txt)) = state
+                            .undoer
+                            .lock()
+                            .redo(&(cursor_range, text.as_str().to_owned()))
+                        {
+                            text.replace_with(redo_txt);
+                            Some(*redo_ccursor_range)
+                        } else {
+                            None
                         }
                     }
-                }
-                ImeEvent::Disabled => {
-                    state.ime_enabled = false;
-                    None
-                }
-            },
 
-            _ => None,
+                    Event::Key {
+                        key: Key::Z,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } if modifiers.matches_logically(Modifiers::COMMAND) => {
+                        if let Some((undo_ccursor_range, undo_txt)) = state
+                            .undoer
+                            .lock()
+                            .undo(&(cursor_range, text.as_str().to_owned()))
+                        {
+                            text.replace_with(undo_txt);
+                            Some(*undo_ccursor_range)
+                        } else {
+                            None
+                        }
+                    }
+
+                    Event::Key {
+                        modifiers,
+                        key,
+                        pressed: true,
+                        ..
+                    } => check_for_mutating_key_press(
+                        os,
+                        &cursor_range,
+                        text,
+                        galley,
+                        modifiers,
+                        *key,
+                    ),
+
+                    Event::Ime(ime_event) => match ime_event {
+                        ImeEvent::Enabled => {
+                            state.ime_enabled = true;
+                            state.ime_cursor_range = cursor_range;
+                            None
+                        }
+                        ImeEvent::Preedit(text_mark) => {
+                            if text_mark == "\n" || text_mark == "\r" {
+                                None
+                            } else {
+                                // Empty prediction can be produced when user press backspace
+                                // or escape during IME, so we clear current text.
+                                let mut ccursor = text.delete_selected(&cursor_range);
+                                let start_cursor = ccursor;
+                                if !text_mark.is_empty() {
+                                    text.insert_text_at(&mut ccursor, text_mark, char_limit);
+                                }
+                                state.ime_cursor_range = cursor_range;
+                                Some(CCursorRange::two(start_cursor, ccursor))
+                            }
+                        }
+                        ImeEvent::Commit(prediction) => {
+                            if prediction == "\n" || prediction == "\r" {
+                                None
+                            } else {
+                                state.ime_enabled = false;
+
+                                if !prediction.is_empty()
+                                    && cursor_range.secondary.index
+                                        == state.ime_cursor_range.secondary.index
+                                {
+                                    let mut ccursor = text.delete_selected(&cursor_range);
+                                    text.insert_text_at(&mut ccursor, prediction, char_limit);
+                                    Some(CCursorRange::one(ccursor))
+                                } else {
+                                    let ccursor = cursor_range.primary;
+                                    Some(CCursorRange::one(ccursor))
+                                }
+                            }
+                        }
+                        ImeEvent::Disabled => {
+                            state.ime_enabled = false;
+                            None
+                        }
+                    },
+
+                    _ => None,
+                }
+            }
         };
 
         if let Some(new_ccursor_range) = did_mutate_text {
@@ -1107,6 +1398,7 @@ fn events(
     }
 
     state.cursor.set_char_range(Some(cursor_range));
+    state.extra_cursors = extra_cursors;
 
     state.undoer.lock().feed_state(
         ui.input(|i| i.time),
@@ -1212,3 +1504,238 @@ fn check_for_mutating_key_press(
         _ => None,
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// Handle an event that should be applied to all cursors at once.
+///
+/// Returns `None` if the event is not supported with multiple cursors,
+/// `Some(true)` if the text was mutated, and `Some(false)` otherwise.
+fn on_multi_cursor_event(
+    os: OperatingSystem,
+    event: &Event,
+    text: &mut dyn TextBuffer,
+    galley: &Galley,
+    char_limit: usize,
+    cursor_range: &mut CCursorRange,
+    extra_cursors: &mut Vec<CCursorRange>,
+) -> Option<bool> {
+    match event {
+        Event::Key {
+            key:
+                key @ (Key::ArrowLeft
+                | Key::ArrowRight
+                | Key::ArrowUp
+                | Key::ArrowDown
+                | Key::Home
+                | Key::End),
+            pressed: true,
+            modifiers,
+            ..
+        } => {
+            cursor_range.on_key_press(os, galley, modifiers, *key);
+            for extra_cursor in extra_cursors.iter_mut() {
+                extra_cursor.on_key_press(os, galley, modifiers, *key);
+            }
+            merge_overlapping_cursors(cursor_range, extra_cursors);
+            Some(false)
+        }
+
+        Event::Text(text_to_insert) => {
+            // Newlines are handled by `Key::Enter`.
+            if !text_to_insert.is_empty() && text_to_insert != "\n" && text_to_insert != "\r" {
+                edit_at_all_cursors(text, cursor_range, extra_cursors, |text, cursor_range| {
+                    let mut ccursor = text.delete_selected(cursor_range);
+                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+                    ccursor
+                });
+                Some(true)
+            } else {
+                Some(false)
+            }
+        }
+
+        Event::Paste(text_to_insert) => {
+            if text_to_insert.is_empty() {
+                Some(false)
+            } else {
+                edit_at_all_cursors(text, cursor_range, extra_cursors, |text, cursor_range| {
+                    let mut ccursor = text.delete_selected(cursor_range);
+                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+                    ccursor
+                });
+                Some(true)
+            }
+        }
+
+        Event::Key {
+            key: Key::Backspace,
+            pressed: true,
+            modifiers,
+            ..
+        } if !modifiers.mac_cmd => {
+            edit_at_all_cursors(text, cursor_range, extra_cursors, |text, cursor_range| {
+                if let Some(cursor) = cursor_range.single() {
+                    if modifiers.alt || modifiers.ctrl {
+                        // alt on mac, ctrl on windows
+                        text.delete_previous_word(cursor)
+                    } else {
+                        text.delete_previous_char(cursor)
+                    }
+                } else {
+                    text.delete_selected(cursor_range)
+                }
+            });
+            Some(true)
+        }
+
+        Event::Key {
+            key: Key::Delete,
+            pressed: true,
+            modifiers,
+            ..
+        } if !modifiers.mac_cmd && (!modifiers.shift || os != OperatingSystem::Windows) => {
+            edit_at_all_cursors(text, cursor_range, extra_cursors, |text, cursor_range| {
+                if let Some(cursor) = cursor_range.single() {
+                    if modifiers.alt || modifiers.ctrl {
+                        // alt on mac, ctrl on windows
+                        text.delete_next_word(cursor)
+                    } else {
+                        text.delete_next_char(cursor)
+                    }
+                } else {
+                    text.delete_selected(cursor_range)
+                }
+            });
+            Some(true)
+        }
+
+        _ => None,
+    }
+}
+
+/// Apply the same edit at the primary cursor and all extra cursors.
+///
+/// The edits are applied from first to last cursor,
+/// shifting the remaining cursors to account for changes in text length,
+/// so that edits at multiple points stay consistent.
+fn edit_at_all_cursors(
+    text: &mut dyn TextBuffer,
+    cursor_range: &mut CCursorRange,
+    extra_cursors: &mut Vec<CCursorRange>,
+    mut edit: impl FnMut(&mut dyn TextBuffer, &CCursorRange) -> CCursor,
+) {
+    merge_overlapping_cursors(cursor_range, extra_cursors);
+
+    let mut cursors: Vec<(CCursorRange, bool)> = std::mem::take(extra_cursors)
+        .into_iter()
+        .map(|range| (range, false))
+        .chain(std::iter::once((*cursor_range, true)))
+        .collect();
+    cursors.sort_by_key(|(range, _)| range.as_sorted_char_range().start);
+
+    let mut num_chars = text.as_str().chars().count();
+    let mut delta = 0_isize;
+    for (range, _) in &mut cursors {
+        let shift = |cursor: CCursor| CCursor {
+            index: cursor.index.saturating_add_signed(delta).min(num_chars),
+            ..cursor
+        };
+        let shifted = CCursorRange {
+            primary: shift(range.primary),
+            secondary: shift(range.secondary),
+            h_pos: range.h_pos,
+        };
+
+        let new_cursor = edit(text, &shifted);
+
+        let num_chars_after = text.as_str().chars().count();
+        delta += num_chars_after as isize - num_chars as isize;
+        num_chars = num_chars_after;
+
+        *range = CCursorRange::one(new_cursor);
+    }
+
+    for (range, is_primary) in cursors {
+        if is_primary {
+            *cursor_range = range;
+        } else {
+            extra_cursors.push(range);
+        }
+    }
+
+    merge_overlapping_cursors(cursor_range, extra_cursors);
+}
+
+/// Merge any cursors that overlap or touch, so that edits stay consistent.
+///
+/// Extra cursors that overlap the primary range are merged into it.
+fn merge_overlapping_cursors(primary: &mut CCursorRange, extra_cursors: &mut Vec<CCursorRange>) {
+    extra_cursors.retain(|extra| {
+        let p = primary.as_sorted_char_range();
+        let e = extra.as_sorted_char_range();
+        if p.start <= e.end && e.start <= p.end {
+            *primary = CCursorRange::two(
+                CCursor::new(p.start.min(e.start)),
+                CCursor::new(p.end.max(e.end)),
+            );
+            false
+        } else {
+            true
+        }
+    });
+
+    extra_cursors.sort_by_key(|range| range.as_sorted_char_range().start);
+    extra_cursors.dedup_by(|second, first| {
+        let f = first.as_sorted_char_range();
+        let s = second.as_sorted_char_range();
+        if s.start <= f.end {
+            *first = CCursorRange::two(CCursor::new(f.start), CCursor::new(f.end.max(s.end)));
+            true
+        } else {
+            false
+        }
+    });
+}
+
+/// Add the next occurrence of the currently selected text as a new cursor (ctrl+D).
+///
+/// The current selection becomes an extra cursor,
+/// and the next occurrence becomes the primary selection.
+/// The search wraps around at the end of the text.
+fn select_next_occurrence(
+    text: &str,
+    cursor_range: &mut CCursorRange,
+    extra_cursors: &mut Vec<CCursorRange>,
+) {
+    let selected = cursor_range.slice_str(text);
+    if selected.is_empty() {
+        return;
+    }
+
+    let same_selection =
+        |a: &CCursorRange, b: &CCursorRange| a.as_sorted_char_range() == b.as_sorted_char_range();
+
+    // Search after the current selection, wrapping around to the start:
+    let search_start = byte_index_from_char_index(text, cursor_range.sorted_cursors()[1].index);
+    let found_byte_index = text[search_start..]
+        .find(selected)
+        .map(|i| search_start + i)
+        .or_else(|| text[..search_start].find(selected));
+
+    if let Some(found_byte_index) = found_byte_index {
+        let start = char_index_from_byte_index(text, found_byte_index);
+        let new_range = CCursorRange::two(
+            CCursor::new(start),
+            CCursor::new(start + selected.chars().count()),
+        );
+        if !same_selection(&new_range, cursor_range)
+            && !extra_cursors
+                .iter()
+                .any(|extra| same_selection(extra, &new_range))
+        {
+            extra_cursors.push(*cursor_range);
+            *cursor_range = new_range;
+        }
+    }
+}
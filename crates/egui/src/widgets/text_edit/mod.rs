@@ -4,6 +4,9 @@ mod state;
 mod text_buffer;
 
 pub use {
-    crate::text_selection::TextCursorState, builder::TextEdit, output::TextEditOutput,
-    state::TextEditState, text_buffer::TextBuffer,
+    crate::text_selection::TextCursorState,
+    builder::{PasteMode, TextEdit},
+    output::TextEditOutput,
+    state::TextEditState,
+    text_buffer::TextBuffer,
 };
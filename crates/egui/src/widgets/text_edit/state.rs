@@ -37,6 +37,13 @@ pub struct TextEditState {
     /// Controls the text selection.
     pub cursor: TextCursorState,
 
+    /// Extra selection ranges when editing with multiple cursors,
+    /// created with ctrl+click (cmd+click on Mac) or ctrl+D.
+    ///
+    /// The primary cursor lives in [`Self::cursor`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub extra_cursors: Vec<CCursorRange>,
+
     /// Wrapped in Arc for cheaper clones.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) undoer: Arc<Mutex<TextEditUndoer>>,
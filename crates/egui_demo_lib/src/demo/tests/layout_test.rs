@@ -1,4 +1,4 @@
-use egui::{vec2, Align, Direction, Layout, Resize, Slider, Ui};
+use egui::{vec2, Align, Direction, Layout, MainWrapDistribute, Resize, Slider, Ui};
 
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
@@ -28,6 +28,7 @@ pub struct LayoutSettings {
     // Similar to the contents of `egui::Layout`
     main_dir: Direction,
     main_wrap: bool,
+    main_wrap_distribute: MainWrapDistribute,
     cross_align: Align,
     cross_justify: bool,
 }
@@ -43,6 +44,7 @@ impl LayoutSettings {
         Self {
             main_dir: Direction::TopDown,
             main_wrap: false,
+            main_wrap_distribute: MainWrapDistribute::None,
             cross_align: Align::Min,
             cross_justify: false,
         }
@@ -52,6 +54,7 @@ impl LayoutSettings {
         Self {
             main_dir: Direction::TopDown,
             main_wrap: false,
+            main_wrap_distribute: MainWrapDistribute::None,
             cross_align: Align::Center,
             cross_justify: true,
         }
@@ -61,6 +64,7 @@ impl LayoutSettings {
         Self {
             main_dir: Direction::LeftToRight,
             main_wrap: true,
+            main_wrap_distribute: MainWrapDistribute::None,
             cross_align: Align::Center,
             cross_justify: false,
         }
@@ -69,6 +73,7 @@ impl LayoutSettings {
     fn layout(&self) -> Layout {
         Layout::from_main_dir_and_cross_align(self.main_dir, self.cross_align)
             .with_main_wrap(self.main_wrap)
+            .with_main_wrap_distribute(self.main_wrap_distribute)
             .with_cross_justify(self.cross_justify)
     }
 }
@@ -163,6 +168,24 @@ impl LayoutTest {
             }
         });
 
+        if self.layout.main_wrap {
+            ui.horizontal(|ui| {
+                ui.label("Distribute leftover:");
+                for &mode in &[
+                    MainWrapDistribute::None,
+                    MainWrapDistribute::SpaceBetween,
+                    MainWrapDistribute::SpaceAround,
+                    MainWrapDistribute::Grow,
+                ] {
+                    ui.radio_value(
+                        &mut self.layout.main_wrap_distribute,
+                        mode,
+                        format!("{mode:?}"),
+                    );
+                }
+            });
+        }
+
         ui.horizontal(|ui| {
             ui.label("Cross Align:");
             for &align in &[Align::Min, Align::Center, Align::Max] {
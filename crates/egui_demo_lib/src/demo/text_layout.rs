@@ -5,6 +5,7 @@ pub struct TextLayoutDemo {
     break_anywhere: bool,
     max_rows: usize,
     overflow_character: Option<char>,
+    truncate: egui::TextTruncate,
     extra_letter_spacing_pixels: i32,
     line_height_pixels: u32,
     lorem_ipsum: bool,
@@ -16,6 +17,7 @@ impl Default for TextLayoutDemo {
             max_rows: 6,
             break_anywhere: true,
             overflow_character: Some('…'),
+            truncate: egui::TextTruncate::End,
             extra_letter_spacing_pixels: 0,
             line_height_pixels: 0,
             lorem_ipsum: true,
@@ -45,6 +47,7 @@ impl crate::View for TextLayoutDemo {
             break_anywhere,
             max_rows,
             overflow_character,
+            truncate,
             extra_letter_spacing_pixels,
             line_height_pixels,
             lorem_ipsum,
@@ -84,6 +87,16 @@ impl crate::View for TextLayoutDemo {
                 });
                 ui.end_row();
 
+                ui.label("Truncate at:");
+                ui.horizontal(|ui| {
+                    ui.selectable_value(truncate, egui::TextTruncate::Start, "Start");
+                    ui.selectable_value(truncate, egui::TextTruncate::Middle, "Middle");
+                    ui.selectable_value(truncate, egui::TextTruncate::End, "End");
+                })
+                .response
+                .on_hover_text("Only used when max rows is 1");
+                ui.end_row();
+
                 ui.label("Extra letter spacing:");
                 ui.add(egui::DragValue::new(extra_letter_spacing_pixels).suffix(" pixels"));
                 ui.end_row();
@@ -142,6 +155,7 @@ impl crate::View for TextLayoutDemo {
                     max_rows: *max_rows,
                     break_anywhere: *break_anywhere,
                     overflow_character: *overflow_character,
+                    truncate: *truncate,
                     ..Default::default()
                 };
 
@@ -1,5 +1,5 @@
 use super::popup::DatePickerPopup;
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use egui::{Area, Button, Frame, InnerResponse, Key, Order, RichText, Ui, Widget};
 
 #[derive(Default, Clone)]
@@ -8,9 +8,31 @@ pub(crate) struct DatePickerButtonState {
     pub picker_visible: bool,
 }
 
+/// What the date picker is bound to: just a date, or a date with a time of day.
+pub(crate) enum DatePickerSelection<'a> {
+    Date(&'a mut NaiveDate),
+    DateTime(&'a mut NaiveDateTime),
+}
+
+impl DatePickerSelection<'_> {
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            Self::Date(date) => **date,
+            Self::DateTime(datetime) => datetime.date(),
+        }
+    }
+
+    pub fn time(&self) -> NaiveTime {
+        match self {
+            Self::Date(_) => NaiveTime::default(),
+            Self::DateTime(datetime) => datetime.time(),
+        }
+    }
+}
+
 /// Shows a date, and will open a date picker popup when clicked.
 pub struct DatePickerButton<'a> {
-    selection: &'a mut NaiveDate,
+    selection: DatePickerSelection<'a>,
     id_salt: Option<&'a str>,
     combo_boxes: bool,
     arrows: bool,
@@ -19,10 +41,23 @@ pub struct DatePickerButton<'a> {
     show_icon: bool,
     format: String,
     highlight_weekends: bool,
+    with_time: bool,
+    twelve_hour_clock: bool,
 }
 
 impl<'a> DatePickerButton<'a> {
     pub fn new(selection: &'a mut NaiveDate) -> Self {
+        Self::from_selection(DatePickerSelection::Date(selection), "%Y-%m-%d")
+    }
+
+    /// Pick a date and a time of day, combined in a [`chrono::NaiveDateTime`].
+    ///
+    /// This shows a time section below the calendar (see [`Self::with_time`]).
+    pub fn new_datetime(selection: &'a mut NaiveDateTime) -> Self {
+        Self::from_selection(DatePickerSelection::DateTime(selection), "%Y-%m-%d %H:%M")
+    }
+
+    fn from_selection(selection: DatePickerSelection<'a>, format: &str) -> Self {
         Self {
             selection,
             id_salt: None,
@@ -31,8 +66,10 @@ impl<'a> DatePickerButton<'a> {
             calendar: true,
             calendar_week: true,
             show_icon: true,
-            format: "%Y-%m-%d".to_owned(),
+            format: format.to_owned(),
             highlight_weekends: true,
+            with_time: true,
+            twelve_hour_clock: false,
         }
     }
 
@@ -101,6 +138,25 @@ impl<'a> DatePickerButton<'a> {
         self.highlight_weekends = highlight_weekends;
         self
     }
+
+    /// Show an hour/minute section below the calendar. (Default: true)
+    ///
+    /// Only has an effect for buttons created with [`Self::new_datetime`];
+    /// date-only buttons never show a time section.
+    #[inline]
+    pub fn with_time(mut self, with_time: bool) -> Self {
+        self.with_time = with_time;
+        self
+    }
+
+    /// Use a 12-hour clock with an AM/PM toggle in the time section. (Default: false)
+    ///
+    /// If `false`, a 24-hour clock is used.
+    #[inline]
+    pub fn twelve_hour_clock(mut self, twelve_hour_clock: bool) -> Self {
+        self.twelve_hour_clock = twelve_hour_clock;
+        self
+    }
 }
 
 impl Widget for DatePickerButton<'_> {
@@ -110,10 +166,14 @@ impl Widget for DatePickerButton<'_> {
             .data_mut(|data| data.get_persisted::<DatePickerButtonState>(id))
             .unwrap_or_default();
 
+        let formatted = match &self.selection {
+            DatePickerSelection::Date(date) => date.format(&self.format).to_string(),
+            DatePickerSelection::DateTime(datetime) => datetime.format(&self.format).to_string(),
+        };
         let mut text = if self.show_icon {
-            RichText::new(format!("{} 📆", self.selection.format(&self.format)))
+            RichText::new(format!("{formatted} 📆"))
         } else {
-            RichText::new(format!("{}", self.selection.format(&self.format)))
+            RichText::new(formatted)
         };
         let visuals = ui.visuals().widgets.open;
         if button_state.picker_visible {
@@ -160,6 +220,8 @@ impl Widget for DatePickerButton<'_> {
                             ui.set_max_width(width);
 
                             DatePickerPopup {
+                                show_time: self.with_time
+                                    && matches!(self.selection, DatePickerSelection::DateTime(_)),
                                 selection: self.selection,
                                 button_id: id,
                                 combo_boxes: self.combo_boxes,
@@ -167,6 +229,7 @@ impl Widget for DatePickerButton<'_> {
                                 calendar: self.calendar,
                                 calendar_week: self.calendar_week,
                                 highlight_weekends: self.highlight_weekends,
+                                twelve_hour_clock: self.twelve_hour_clock,
                             }
                             .draw(ui)
                         })
@@ -1,8 +1,13 @@
-use chrono::{Datelike as _, NaiveDate, Weekday};
+use chrono::{Datelike as _, NaiveDate, Timelike as _, Weekday};
 
-use egui::{Align, Button, Color32, ComboBox, Direction, Id, Layout, RichText, Ui, Vec2};
+use egui::{
+    Align, Button, Color32, ComboBox, Direction, DragValue, Id, Layout, RichText, Ui, Vec2,
+};
 
-use super::{button::DatePickerButtonState, month_data};
+use super::{
+    button::{DatePickerButtonState, DatePickerSelection},
+    month_data,
+};
 
 use crate::{Column, Size, StripBuilder, TableBuilder};
 
@@ -12,6 +17,8 @@ struct DatePickerPopupState {
     year: i32,
     month: u32,
     day: u32,
+    hour: u32,
+    minute: u32,
     setup: bool,
 }
 
@@ -28,13 +35,15 @@ impl DatePickerPopupState {
 }
 
 pub(crate) struct DatePickerPopup<'a> {
-    pub selection: &'a mut NaiveDate,
+    pub selection: DatePickerSelection<'a>,
     pub button_id: Id,
     pub combo_boxes: bool,
     pub arrows: bool,
     pub calendar: bool,
     pub calendar_week: bool,
     pub highlight_weekends: bool,
+    pub show_time: bool,
+    pub twelve_hour_clock: bool,
 }
 
 impl DatePickerPopup<'_> {
@@ -46,9 +55,13 @@ impl DatePickerPopup<'_> {
             .data_mut(|data| data.get_persisted::<DatePickerPopupState>(id))
             .unwrap_or_default();
         if !popup_state.setup {
-            popup_state.year = self.selection.year();
-            popup_state.month = self.selection.month();
-            popup_state.day = self.selection.day();
+            let date = self.selection.date();
+            popup_state.year = date.year();
+            popup_state.month = date.month();
+            popup_state.day = date.day();
+            let time = self.selection.time();
+            popup_state.hour = time.hour();
+            popup_state.minute = time.minute();
             popup_state.setup = true;
             ui.data_mut(|data| data.insert_persisted(id, popup_state.clone()));
         }
@@ -75,6 +88,7 @@ impl DatePickerPopup<'_> {
                 Size::exact((spacing + height) * (weeks.len() + 1) as f32),
                 self.calendar as usize,
             )
+            .sizes(Size::exact(height), self.show_time as usize)
             .size(Size::exact(height))
             .vertical(|mut strip| {
                 if self.combo_boxes {
@@ -378,6 +392,60 @@ impl DatePickerPopup<'_> {
                     });
                 }
 
+                if self.show_time {
+                    strip.cell(|ui| {
+                        ui.horizontal(|ui| {
+                            let mut time_changed = false;
+
+                            if self.twelve_hour_clock {
+                                let pm = popup_state.hour >= 12;
+                                let mut hour12 = popup_state.hour % 12;
+                                if hour12 == 0 {
+                                    hour12 = 12;
+                                }
+                                if ui.add(DragValue::new(&mut hour12).range(1..=12)).changed() {
+                                    popup_state.hour = hour12 % 12 + if pm { 12 } else { 0 };
+                                    time_changed = true;
+                                }
+                                ui.label(":");
+                                time_changed |= ui
+                                    .add(
+                                        DragValue::new(&mut popup_state.minute)
+                                            .range(0..=59)
+                                            .custom_formatter(|n, _| format!("{n:02}")),
+                                    )
+                                    .changed();
+                                if ui.button(if pm { "PM" } else { "AM" }).clicked() {
+                                    popup_state.hour = (popup_state.hour + 12) % 24;
+                                    time_changed = true;
+                                }
+                            } else {
+                                time_changed |= ui
+                                    .add(
+                                        DragValue::new(&mut popup_state.hour)
+                                            .range(0..=23)
+                                            .custom_formatter(|n, _| format!("{n:02}")),
+                                    )
+                                    .changed();
+                                ui.label(":");
+                                time_changed |= ui
+                                    .add(
+                                        DragValue::new(&mut popup_state.minute)
+                                            .range(0..=59)
+                                            .custom_formatter(|n, _| format!("{n:02}")),
+                                    )
+                                    .changed();
+                            }
+
+                            if time_changed {
+                                ui.data_mut(|data| {
+                                    data.insert_persisted(id, popup_state.clone());
+                                });
+                            }
+                        });
+                    });
+                }
+
                 strip.strip(|builder| {
                     builder.sizes(Size::remainder(), 3).horizontal(|mut strip| {
                         strip.empty();
@@ -391,12 +459,26 @@ impl DatePickerPopup<'_> {
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                 if ui.button("Save").clicked() {
-                                    *self.selection = NaiveDate::from_ymd_opt(
+                                    let date = NaiveDate::from_ymd_opt(
                                         popup_state.year,
                                         popup_state.month,
                                         popup_state.day,
                                     )
                                     .expect("Could not create NaiveDate");
+                                    match &mut self.selection {
+                                        DatePickerSelection::Date(selection) => {
+                                            **selection = date;
+                                        }
+                                        DatePickerSelection::DateTime(selection) => {
+                                            **selection = date
+                                                .and_hms_opt(
+                                                    popup_state.hour,
+                                                    popup_state.minute,
+                                                    0,
+                                                )
+                                                .expect("Could not create NaiveDateTime");
+                                        }
+                                    }
                                     saved = true;
                                     close = true;
                                 }
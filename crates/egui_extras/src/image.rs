@@ -2,7 +2,7 @@
 
 use egui::{mutex::Mutex, TextureOptions};
 
-#[cfg(feature = "svg")]
+#[cfg(any(feature = "image", feature = "svg"))]
 use egui::SizeHint;
 
 /// An image to be shown in egui.
@@ -208,8 +208,30 @@ impl RetainedImage {
 /// On invalid image or unsupported image format.
 #[cfg(feature = "image")]
 pub fn load_image_bytes(image_bytes: &[u8]) -> Result<egui::ColorImage, egui::load::LoadError> {
+    load_image_bytes_with_size(image_bytes, None)
+}
+
+/// Load a (non-svg) image, downscaled to match the given [`SizeHint`].
+///
+/// Most image formats don't support decoding only a subset of the pixels,
+/// so the image is decoded at full resolution and then downscaled.
+/// This still saves a lot of memory for e.g. thumbnail galleries,
+/// since only the downscaled image is kept around.
+///
+/// The image is never upscaled.
+///
+/// Requires the "image" feature. You must also opt-in to the image formats you need
+/// with e.g. `image = { version = "0.25", features = ["jpeg", "png"] }`.
+///
+/// # Errors
+/// On invalid image or unsupported image format.
+#[cfg(feature = "image")]
+pub fn load_image_bytes_with_size(
+    image_bytes: &[u8],
+    size_hint: Option<SizeHint>,
+) -> Result<egui::ColorImage, egui::load::LoadError> {
     profiling::function_scope!();
-    let image = image::load_from_memory(image_bytes).map_err(|err| match err {
+    let mut image = image::load_from_memory(image_bytes).map_err(|err| match err {
         image::ImageError::Unsupported(err) => match err.kind() {
             image::error::UnsupportedErrorKind::Format(format) => {
                 egui::load::LoadError::FormatNotSupported {
@@ -220,6 +242,39 @@ pub fn load_image_bytes(image_bytes: &[u8]) -> Result<egui::ColorImage, egui::lo
         },
         err => egui::load::LoadError::Loading(err.to_string()),
     })?;
+
+    if let Some(size_hint) = size_hint {
+        image = match size_hint {
+            SizeHint::Scale(scale) => {
+                let scale = scale.into_inner();
+                if scale < 1.0 {
+                    image.thumbnail(
+                        (scale * image.width() as f32).round() as u32,
+                        (scale * image.height() as f32).round() as u32,
+                    )
+                } else {
+                    image
+                }
+            }
+            SizeHint::Width(width) if width < image.width() => image.thumbnail(width, u32::MAX),
+            SizeHint::Height(height) if height < image.height() => {
+                image.thumbnail(u32::MAX, height)
+            }
+            SizeHint::Size {
+                width,
+                height,
+                maintain_aspect_ratio,
+            } if width < image.width() || height < image.height() => {
+                if maintain_aspect_ratio {
+                    image.thumbnail(width, height)
+                } else {
+                    image.thumbnail_exact(width, height)
+                }
+            }
+            _ => image,
+        };
+    }
+
     let size = [image.width() as _, image.height() as _];
     let image_buffer = image.to_rgba8();
     let pixels = image_buffer.as_flat_samples();
@@ -263,6 +318,19 @@ pub fn load_svg_bytes_with_size(
 
     profiling::function_scope!();
 
+    // Monochrome icons commonly use `currentColor` and so have no color of their own.
+    // Rasterize those as white, so that they can be recolored at paint time
+    // with `egui::Image::tint` (e.g. for hover/active/disabled states),
+    // without re-decoding the SVG. SVGs with explicit colors are unaffected.
+    let recolored;
+    let svg_bytes = match std::str::from_utf8(svg_bytes) {
+        Ok(text) if text.contains("currentColor") => {
+            recolored = text.replace("currentColor", "white");
+            recolored.as_bytes()
+        }
+        _ => svg_bytes,
+    };
+
     let rtree = Tree::from_data(svg_bytes, options).map_err(|err| err.to_string())?;
 
     let original_size = Vec2::new(rtree.size().width(), rtree.size().height());
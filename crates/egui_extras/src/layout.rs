@@ -37,6 +37,12 @@ pub(crate) struct StripLayoutFlags {
 
     /// Used when we want to accruately measure the size of this cell.
     pub(crate) sizing_pass: bool,
+
+    /// If set, clip away everything to the left of this x coordinate.
+    ///
+    /// Used by [`crate::Table`] to keep horizontally scrolling cells
+    /// from painting under the pinned columns.
+    pub(crate) clip_left_x: Option<f32>,
 }
 
 /// Positions cells in [`CellDirection`] and starts a new line on [`StripLayout::end_line`]
@@ -126,8 +132,16 @@ impl<'l> StripLayout<'l> {
         let item_spacing = self.ui.spacing().item_spacing;
         let gapless_rect = max_rect.expand2(0.5 * item_spacing).round_ui();
 
+        let bg_painter = if let Some(clip_left_x) = flags.clip_left_x {
+            self.ui
+                .painter()
+                .with_clip_rect(Rect::everything_right_of(clip_left_x))
+        } else {
+            self.ui.painter().clone()
+        };
+
         if flags.striped {
-            self.ui.painter().rect_filled(
+            bg_painter.rect_filled(
                 gapless_rect,
                 egui::CornerRadius::ZERO,
                 self.ui.visuals().faint_bg_color,
@@ -135,7 +149,7 @@ impl<'l> StripLayout<'l> {
         }
 
         if flags.selected {
-            self.ui.painter().rect_filled(
+            bg_painter.rect_filled(
                 gapless_rect,
                 egui::CornerRadius::ZERO,
                 self.ui.visuals().selection.bg_fill,
@@ -143,7 +157,7 @@ impl<'l> StripLayout<'l> {
         }
 
         if flags.hovered && !flags.selected && self.sense.interactive() {
-            self.ui.painter().rect_filled(
+            bg_painter.rect_filled(
                 gapless_rect,
                 egui::CornerRadius::ZERO,
                 self.ui.visuals().widgets.hovered.bg_fill,
@@ -216,6 +230,10 @@ impl<'l> StripLayout<'l> {
 
         let mut child_ui = self.ui.new_child(ui_builder);
 
+        if let Some(clip_left_x) = flags.clip_left_x {
+            child_ui.shrink_clip_rect(Rect::everything_right_of(clip_left_x));
+        }
+
         if flags.clip {
             let margin = egui::Vec2::splat(self.ui.visuals().clip_rect_margin);
             let margin = margin.min(0.5 * self.ui.spacing().item_spacing);
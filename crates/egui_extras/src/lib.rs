@@ -19,8 +19,10 @@ pub mod image;
 mod layout;
 mod loaders;
 mod sizing;
+mod sparkline;
 mod strip;
 mod table;
+mod tree;
 
 #[cfg(feature = "chrono")]
 pub use crate::datepicker::DatePickerButton;
@@ -30,11 +32,16 @@ pub use crate::datepicker::DatePickerButton;
 pub use crate::image::RetainedImage;
 pub(crate) use crate::layout::StripLayout;
 pub use crate::sizing::Size;
+pub use crate::sparkline::Sparkline;
 pub use crate::strip::*;
 pub use crate::table::*;
+pub use crate::tree::*;
 
 pub use loaders::install_image_loaders;
 
+#[cfg(feature = "http")]
+pub use loaders::{EhttpLoader, RangeBytesLoadResult, RangeBytesPoll};
+
 // ---------------------------------------------------------------------------
 
 /// Panic in debug builds, log otherwise.
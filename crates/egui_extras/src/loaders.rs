@@ -113,6 +113,9 @@ mod file_loader;
 #[cfg(feature = "http")]
 mod ehttp_loader;
 
+#[cfg(feature = "http")]
+pub use ehttp_loader::{EhttpLoader, RangeBytesLoadResult, RangeBytesPoll};
+
 #[cfg(feature = "gif")]
 mod gif_loader;
 #[cfg(feature = "image")]
@@ -3,7 +3,7 @@ use egui::{
     load::{Bytes, BytesLoadResult, BytesLoader, BytesPoll, LoadError},
     mutex::Mutex,
 };
-use std::{sync::Arc, task::Poll};
+use std::{ops::Range, sync::Arc, task::Poll};
 
 #[derive(Clone)]
 struct File {
@@ -37,15 +37,174 @@ impl File {
     }
 }
 
+/// A partial file fetched with an HTTP range request, see [`EhttpLoader::load_range`].
+#[derive(Clone)]
+struct PartialFile {
+    file: File,
+    more_available: bool,
+}
+
+impl PartialFile {
+    fn from_response(
+        uri: &str,
+        response: ehttp::Response,
+        byte_range: &Range<u64>,
+    ) -> Result<Self, String> {
+        let more_available = if response.status == 206 {
+            // `Content-Range: bytes start-end/total`
+            match response
+                .headers
+                .get("content-range")
+                .and_then(|value| value.rsplit('/').next())
+            {
+                Some(total) => total
+                    .parse::<u64>()
+                    .map_or(true, |total| byte_range.end < total),
+                None => true, // we got a partial response, so assume there is more
+            }
+        } else {
+            // The server ignored the range request and sent us the whole file:
+            false
+        };
+
+        let file = File::from_response(uri, response)?;
+        Ok(Self {
+            file,
+            more_available,
+        })
+    }
+}
+
+/// The result of a call to [`EhttpLoader::load_range`].
+pub type RangeBytesLoadResult = Result<RangeBytesPoll, LoadError>;
+
+/// The state of a byte range fetched with [`EhttpLoader::load_range`].
+#[derive(Clone)]
+pub enum RangeBytesPoll {
+    /// The range is still being fetched.
+    Pending,
+
+    /// The range is done fetching.
+    Ready {
+        /// The fetched bytes.
+        ///
+        /// If the server doesn't support range requests,
+        /// this is the whole file.
+        bytes: Arc<[u8]>,
+
+        /// Mime type of the content, e.g. `image/png`.
+        mime: Option<String>,
+
+        /// Does the server have more bytes past the requested range?
+        ///
+        /// If `true`, callers can e.g. parse an image header from the prefix
+        /// before committing to downloading the rest of the file.
+        more_available: bool,
+    },
+}
+
 type Entry = Poll<Result<File, String>>;
+type RangeEntry = Poll<Result<PartialFile, String>>;
 
 #[derive(Default)]
 pub struct EhttpLoader {
     cache: Arc<Mutex<HashMap<String, Entry>>>,
+
+    /// Byte ranges fetched with [`Self::load_range`], keyed by URI and range.
+    range_cache: Arc<Mutex<HashMap<(String, Range<u64>), RangeEntry>>>,
 }
 
 impl EhttpLoader {
     pub const ID: &'static str = egui::generate_loader_id!(EhttpLoader);
+
+    /// Fetch only the given byte range of `uri` using an HTTP range request.
+    ///
+    /// This lets you fetch e.g. an image header to learn the image dimensions
+    /// before committing to downloading the whole file,
+    /// for progressive loading UIs with placeholders.
+    ///
+    /// If `byte_range` is `None` this behaves like a normal full load.
+    /// If the server doesn't support range requests the whole file is returned,
+    /// with [`RangeBytesPoll::Ready::more_available`] set to `false`.
+    ///
+    /// Results are cached per `(uri, byte_range)`,
+    /// and are evicted by [`BytesLoader::forget`]/[`BytesLoader::forget_all`]
+    /// together with the full downloads.
+    pub fn load_range(
+        &self,
+        ctx: &egui::Context,
+        uri: &str,
+        byte_range: Option<Range<u64>>,
+    ) -> RangeBytesLoadResult {
+        let Some(byte_range) = byte_range else {
+            return Ok(match self.load(ctx, uri)? {
+                BytesPoll::Pending { .. } => RangeBytesPoll::Pending,
+                BytesPoll::Ready { bytes, mime, .. } => RangeBytesPoll::Ready {
+                    bytes: match bytes {
+                        Bytes::Static(bytes) => bytes.into(),
+                        Bytes::Shared(bytes) => bytes,
+                    },
+                    mime,
+                    more_available: false,
+                },
+            });
+        };
+
+        if !starts_with_one_of(uri, PROTOCOLS) {
+            return Err(LoadError::NotSupported);
+        }
+
+        let key = (uri.to_owned(), byte_range.clone());
+        let mut range_cache = self.range_cache.lock();
+        if let Some(entry) = range_cache.get(&key).cloned() {
+            match entry {
+                Poll::Ready(Ok(partial_file)) => Ok(RangeBytesPoll::Ready {
+                    bytes: partial_file.file.bytes,
+                    mime: partial_file.file.mime,
+                    more_available: partial_file.more_available,
+                }),
+                Poll::Ready(Err(err)) => Err(LoadError::Loading(err)),
+                Poll::Pending => Ok(RangeBytesPoll::Pending),
+            }
+        } else {
+            log::trace!("started loading range {byte_range:?} of {uri:?}");
+
+            range_cache.insert(key.clone(), Poll::Pending);
+            drop(range_cache);
+
+            let mut request = ehttp::Request::get(uri);
+            // HTTP ranges are inclusive:
+            request.headers.insert(
+                "Range",
+                format!(
+                    "bytes={}-{}",
+                    byte_range.start,
+                    byte_range.end.saturating_sub(1)
+                ),
+            );
+
+            ehttp::fetch(request, {
+                let ctx = ctx.clone();
+                let range_cache = self.range_cache.clone();
+                let uri = uri.to_owned();
+                move |response| {
+                    let result = match response {
+                        Ok(response) => PartialFile::from_response(&uri, response, &key.1),
+                        Err(err) => {
+                            // Log details; return summary
+                            log::error!("Failed to load range {:?} of {uri:?}: {err}", key.1);
+                            Err(format!("Failed to load {uri:?}"))
+                        }
+                    };
+                    log::trace!("finished loading range {:?} of {uri:?}", key.1);
+                    range_cache.lock().insert(key, Poll::Ready(result));
+                    ctx.request_repaint();
+                }
+            });
+
+            Ok(RangeBytesPoll::Pending)
+        }
+    }
 }
 
 const PROTOCOLS: &[&str] = &["http://", "https://"];
@@ -106,14 +265,17 @@ impl BytesLoader for EhttpLoader {
 
     fn forget(&self, uri: &str) {
         let _ = self.cache.lock().remove(uri);
+        self.range_cache.lock().retain(|(u, _), _| u != uri);
     }
 
     fn forget_all(&self) {
         self.cache.lock().clear();
+        self.range_cache.lock().clear();
     }
 
     fn byte_size(&self) -> usize {
-        self.cache
+        let full_bytes: usize = self
+            .cache
             .lock()
             .values()
             .map(|entry| match entry {
@@ -123,10 +285,29 @@ impl BytesLoader for EhttpLoader {
                 Poll::Ready(Err(err)) => err.len(),
                 _ => 0,
             })
-            .sum()
+            .sum();
+        let range_bytes: usize = self
+            .range_cache
+            .lock()
+            .values()
+            .map(|entry| match entry {
+                Poll::Ready(Ok(partial_file)) => {
+                    partial_file.file.bytes.len()
+                        + partial_file.file.mime.as_ref().map_or(0, |m| m.len())
+                }
+                Poll::Ready(Err(err)) => err.len(),
+                _ => 0,
+            })
+            .sum();
+        full_bytes + range_bytes
     }
 
     fn has_pending(&self) -> bool {
         self.cache.lock().values().any(|entry| entry.is_pending())
+            || self
+                .range_cache
+                .lock()
+                .values()
+                .any(|entry| entry.is_pending())
     }
 }
@@ -6,16 +6,32 @@ use egui::{
     ColorImage,
 };
 use image::ImageFormat;
-use std::{mem::size_of, path::Path, sync::Arc, task::Poll};
+use std::{
+    mem::size_of,
+    path::Path,
+    sync::{
+        atomic::{AtomicU64, Ordering::Relaxed},
+        Arc,
+    },
+    task::Poll,
+};
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::thread;
 
-type Entry = Poll<Result<Arc<ColorImage>, String>>;
+struct Entry {
+    last_used: AtomicU64,
+    poll: Poll<Result<Arc<ColorImage>, String>>,
+}
+
+/// Images are decoded at the size they are shown at,
+/// so the same URI may have several decoded sizes.
+type Cache = HashMap<String, HashMap<SizeHint, Entry>>;
 
 #[derive(Default)]
 pub struct ImageCrateLoader {
-    cache: Arc<Mutex<HashMap<String, Entry>>>,
+    pass_index: AtomicU64,
+    cache: Arc<Mutex<Cache>>,
 }
 
 impl ImageCrateLoader {
@@ -60,7 +76,7 @@ impl ImageLoader for ImageCrateLoader {
         Self::ID
     }
 
-    fn load(&self, ctx: &egui::Context, uri: &str, _: SizeHint) -> ImageLoadResult {
+    fn load(&self, ctx: &egui::Context, uri: &str, size_hint: SizeHint) -> ImageLoadResult {
         // three stages of guessing if we support loading the image:
         // 1. URI extension (only done for files)
         // 2. Mime from `BytesPoll::Ready`
@@ -81,11 +97,19 @@ impl ImageLoader for ImageCrateLoader {
         fn load_image(
             ctx: &egui::Context,
             uri: &str,
-            cache: &Arc<Mutex<HashMap<String, Entry>>>,
+            size_hint: SizeHint,
+            pass_index: u64,
+            cache: &Arc<Mutex<Cache>>,
             bytes: &Bytes,
         ) -> ImageLoadResult {
             let uri = uri.to_owned();
-            cache.lock().insert(uri.clone(), Poll::Pending);
+            cache.lock().entry(uri.clone()).or_default().insert(
+                size_hint,
+                Entry {
+                    last_used: AtomicU64::new(pass_index),
+                    poll: Poll::Pending,
+                },
+            );
 
             // Do the image parsing on a bg thread
             thread::Builder::new()
@@ -98,14 +122,21 @@ impl ImageLoader for ImageCrateLoader {
                     let bytes = bytes.clone();
                     move || {
                         log::trace!("ImageLoader - started loading {uri:?}");
-                        let result = crate::image::load_image_bytes(&bytes)
-                            .map(Arc::new)
-                            .map_err(|err| err.to_string());
+                        let result =
+                            crate::image::load_image_bytes_with_size(&bytes, Some(size_hint))
+                                .map(Arc::new)
+                                .map_err(|err| err.to_string());
                         log::trace!("ImageLoader - finished loading {uri:?}");
-                        let prev = cache.lock().insert(uri, Poll::Ready(result));
+                        let prev = cache.lock().entry(uri).or_default().insert(
+                            size_hint,
+                            Entry {
+                                last_used: AtomicU64::new(pass_index),
+                                poll: Poll::Ready(result),
+                            },
+                        );
                         debug_assert!(
-                            matches!(prev, Some(Poll::Pending)),
-                            "Expected previous state to be Pending"
+                            prev.is_none_or(|prev| prev.poll.is_pending()),
+                            "Expected previous entry, if any, to be pending"
                         );
 
                         ctx.request_repaint();
@@ -120,23 +151,38 @@ impl ImageLoader for ImageCrateLoader {
         fn load_image(
             _ctx: &egui::Context,
             uri: &str,
-            cache: &Arc<Mutex<HashMap<String, Entry>>>,
+            size_hint: SizeHint,
+            pass_index: u64,
+            cache: &Arc<Mutex<Cache>>,
             bytes: &Bytes,
         ) -> ImageLoadResult {
             let mut cache_lock = cache.lock();
             log::trace!("started loading {uri:?}");
-            let result = crate::image::load_image_bytes(bytes)
+            let result = crate::image::load_image_bytes_with_size(bytes, Some(size_hint))
                 .map(Arc::new)
                 .map_err(|err| err.to_string());
             log::trace!("finished loading {uri:?}");
-            cache_lock.insert(uri.into(), std::task::Poll::Ready(result.clone()));
+            cache_lock.entry(uri.into()).or_default().insert(
+                size_hint,
+                Entry {
+                    last_used: AtomicU64::new(pass_index),
+                    poll: Poll::Ready(result.clone()),
+                },
+            );
             match result {
                 Ok(image) => Ok(ImagePoll::Ready { image }),
                 Err(err) => Err(LoadError::Loading(err)),
             }
         }
 
-        let entry = self.cache.lock().get(uri).cloned();
+        let entry = self.cache.lock().get(uri).and_then(|bucket| {
+            bucket.get(&size_hint).map(|entry| {
+                entry
+                    .last_used
+                    .store(self.pass_index.load(Relaxed), Relaxed);
+                entry.poll.clone()
+            })
+        });
         if let Some(entry) = entry {
             match entry {
                 Poll::Ready(Ok(image)) => Ok(ImagePoll::Ready { image }),
@@ -154,7 +200,14 @@ impl ImageLoader for ImageCrateLoader {
                             });
                         }
                     }
-                    load_image(ctx, uri, &self.cache, &bytes)
+                    load_image(
+                        ctx,
+                        uri,
+                        size_hint,
+                        self.pass_index.load(Relaxed),
+                        &self.cache,
+                        &bytes,
+                    )
                 }
                 Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
                 Err(err) => Err(err),
@@ -174,7 +227,8 @@ impl ImageLoader for ImageCrateLoader {
         self.cache
             .lock()
             .values()
-            .map(|result| match result {
+            .flat_map(|bucket| bucket.values())
+            .map(|entry| match &entry.poll {
                 Poll::Ready(Ok(image)) => image.pixels.len() * size_of::<egui::Color32>(),
                 Poll::Ready(Err(err)) => err.len(),
                 Poll::Pending => 0,
@@ -183,7 +237,25 @@ impl ImageLoader for ImageCrateLoader {
     }
 
     fn has_pending(&self) -> bool {
-        self.cache.lock().values().any(|result| result.is_pending())
+        self.cache
+            .lock()
+            .values()
+            .flat_map(|bucket| bucket.values())
+            .any(|entry| entry.poll.is_pending())
+    }
+
+    fn end_pass(&self, pass_index: u64) {
+        self.pass_index.store(pass_index, Relaxed);
+        let mut cache = self.cache.lock();
+        cache.retain(|_key, bucket| {
+            if 2 <= bucket.len() {
+                // The same image has been decoded at multiple sizes,
+                // e.g. as a thumbnail and in a full-size view.
+                // This could waste RAM, so we remove the ones that are not used in this frame.
+                bucket.retain(|_, entry| pass_index <= entry.last_used.load(Relaxed) + 1);
+            }
+            !bucket.is_empty()
+        });
     }
 }
 
@@ -0,0 +1,169 @@
+use egui::{
+    emath::format_with_minimum_decimals, epaint::Mesh, pos2, remap, remap_clamp, vec2, Color32,
+    NumExt as _, Pos2, Rangef, Response, Sense, Shape, Stroke, Ui, Vec2, Widget,
+};
+
+/// A tiny inline line chart of a series of values,
+/// without the overhead of a full plotting library.
+///
+/// The values are normalized to the min-max range of the data
+/// (or a fixed range set with [`Self::y_range`])
+/// and drawn as a single polyline, with an optional fill below it.
+/// Hovering the sparkline shows the value under the cursor.
+///
+/// ### Example
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::Sparkline;
+/// let samples = [1.0, 4.0, 2.0, 5.0, 3.0];
+/// ui.add(Sparkline::new(&samples));
+/// # });
+/// ```
+pub struct Sparkline<'a> {
+    values: &'a [f32],
+    size: Option<Vec2>,
+    stroke: Option<Stroke>,
+    fill: Option<Color32>,
+    highlight_last: bool,
+    y_range: Option<Rangef>,
+}
+
+impl<'a> Sparkline<'a> {
+    pub fn new(values: &'a [f32]) -> Self {
+        Self {
+            values,
+            size: None,
+            stroke: None,
+            fill: None,
+            highlight_last: true,
+            y_range: None,
+        }
+    }
+
+    /// The size to allocate for the sparkline.
+    ///
+    /// Default: twice [`egui::style::Spacing::interact_size`] wide and one high.
+    #[inline]
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// The stroke of the polyline.
+    ///
+    /// Default: the `fg_stroke` of the inactive widget visuals.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Fill the area below the polyline with this color.
+    #[inline]
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Highlight the last point with a small circle. (Default: true)
+    #[inline]
+    pub fn highlight_last(mut self, highlight_last: bool) -> Self {
+        self.highlight_last = highlight_last;
+        self
+    }
+
+    /// Normalize the values to this fixed range instead of
+    /// the min-max range of the data.
+    ///
+    /// Useful to keep several sparklines, or the same sparkline over time,
+    /// on a comparable scale. Values outside the range are clipped.
+    #[inline]
+    pub fn y_range(mut self, y_range: impl Into<Rangef>) -> Self {
+        self.y_range = Some(y_range.into());
+        self
+    }
+}
+
+impl Widget for Sparkline<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let desired_size = self.size.unwrap_or_else(|| {
+            let interact_size = ui.spacing().interact_size;
+            vec2(2.0 * interact_size.x, interact_size.y)
+        });
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        if !ui.is_rect_visible(rect) || self.values.len() < 2 {
+            return response;
+        }
+
+        let y_range = self.y_range.unwrap_or_else(|| {
+            let mut y_range = Rangef::NOTHING;
+            for &value in self.values {
+                y_range.min = y_range.min.min(value);
+                y_range.max = y_range.max.max(value);
+            }
+            y_range
+        });
+
+        let y_to_gui = |value: f32| {
+            if y_range.span() > 0.0 {
+                remap(value, y_range.min..=y_range.max, rect.bottom()..=rect.top())
+            } else {
+                rect.center().y // All values are the same - draw a flat line.
+            }
+        };
+
+        let last_index = self.values.len() - 1;
+        let points: Vec<Pos2> = self
+            .values
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| {
+                let x = remap(i as f32, 0.0..=last_index as f32, rect.x_range());
+                pos2(x, y_to_gui(value))
+            })
+            .collect();
+
+        let stroke = self
+            .stroke
+            .unwrap_or_else(|| ui.visuals().widgets.inactive.fg_stroke);
+
+        // Clip, both because a fixed `y_range` may put values outside the rect,
+        // and so the fill and highlight circle don't bleed into neighboring widgets:
+        let painter = ui.painter().with_clip_rect(rect);
+
+        if let Some(fill) = self.fill {
+            // The area below the polyline is usually not convex,
+            // so tessellate it ourselves with a quad per segment:
+            let mut mesh = Mesh::default();
+            for (i, point) in points.iter().enumerate() {
+                mesh.colored_vertex(*point, fill);
+                mesh.colored_vertex(pos2(point.x, rect.bottom()), fill);
+                if i < last_index {
+                    let i = 2 * i as u32;
+                    mesh.add_triangle(i, i + 1, i + 2);
+                    mesh.add_triangle(i + 1, i + 2, i + 3);
+                }
+            }
+            painter.add(Shape::mesh(mesh));
+        }
+
+        let radius = 1.5 * stroke.width.at_least(1.0);
+        let color = stroke.color;
+        painter.add(Shape::line(points.clone(), stroke));
+
+        if self.highlight_last {
+            painter.circle_filled(points[last_index], radius, color);
+        }
+
+        if let Some(pointer) = response.hover_pos() {
+            let index =
+                remap_clamp(pointer.x, rect.x_range(), 0.0..=last_index as f32).round() as usize;
+            painter.circle_filled(points[index], radius, color);
+            return response
+                .on_hover_text(format_with_minimum_decimals(self.values[index] as f64, 0));
+        }
+
+        response
+    }
+}
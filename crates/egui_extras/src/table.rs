@@ -5,7 +5,7 @@
 
 use egui::{
     scroll_area::{ScrollAreaOutput, ScrollBarVisibility, ScrollSource},
-    Align, Id, NumExt as _, Rangef, Rect, Response, ScrollArea, Ui, Vec2, Vec2b,
+    Align, Id, Modifiers, NumExt as _, Rangef, Rect, Response, ScrollArea, Ui, Vec2, Vec2b,
 };
 
 use crate::{
@@ -96,6 +96,8 @@ impl Column {
 
     /// Can this column be resized by dragging the column separator?
     ///
+    /// Double-clicking the separator will auto-size the column to its content.
+    ///
     /// If you don't call this, the fallback value of
     /// [`TableBuilder::resizable`] is used (which by default is `false`).
     #[inline]
@@ -250,9 +252,11 @@ pub struct TableBuilder<'a> {
     columns: Vec<Column>,
     striped: Option<bool>,
     resizable: bool,
+    pinned_columns: usize,
     cell_layout: egui::Layout,
     scroll_options: TableScrollOptions,
     sense: egui::Sense,
+    selection: Option<&'a mut TableSelection>,
 }
 
 impl<'a> TableBuilder<'a> {
@@ -264,9 +268,11 @@ impl<'a> TableBuilder<'a> {
             columns: Default::default(),
             striped: None,
             resizable: false,
+            pinned_columns: 0,
             cell_layout,
             scroll_options: Default::default(),
             sense: egui::Sense::hover(),
+            selection: None,
         }
     }
 
@@ -304,8 +310,27 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
+    /// Let the table manage a row selection for you.
+    ///
+    /// The selected rows are highlighted, and clicking a row updates the
+    /// selection (see [`TableSelection`] for the click behavior).
+    /// This makes cells sense clicks.
+    ///
+    /// This also resets [`TableSelection::changed`],
+    /// so that it reports only changes made by this table pass.
+    #[inline]
+    pub fn row_selection(mut self, selection: &'a mut TableSelection) -> Self {
+        selection.changed = false;
+        self.sense = self.sense.union(egui::Sense::click());
+        self.selection = Some(selection);
+        self
+    }
+
     /// Make the columns resizable by dragging.
     ///
+    /// The new widths are persisted in the table's [`Id`]-keyed state,
+    /// and double-clicking a separator auto-sizes that column to its content.
+    ///
     /// You can set this for individual columns with [`Column::resizable`].
     /// [`Self::resizable`] is used as a fallback for any column for which you don't call
     /// [`Column::resizable`].
@@ -320,6 +345,21 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
+    /// Keep the first `pinned_columns` columns visible when the table
+    /// is scrolled horizontally, e.g. when it is wrapped in a
+    /// horizontal [`ScrollArea`].
+    ///
+    /// The pinned columns stick to the left edge while the remaining
+    /// columns scroll underneath them.
+    /// Row striping and selection highlighting span both regions.
+    ///
+    /// Default: `0`.
+    #[inline]
+    pub fn pinned_columns(mut self, pinned_columns: usize) -> Self {
+        self.pinned_columns = pinned_columns;
+        self
+    }
+
     /// Enable vertical scrolling in body (default: `true`)
     #[inline]
     pub fn vscroll(mut self, vscroll: bool) -> Self {
@@ -465,9 +505,11 @@ impl<'a> TableBuilder<'a> {
             mut columns,
             striped,
             resizable,
+            pinned_columns,
             cell_layout,
             scroll_options,
             sense,
+            selection,
         } = self;
 
         for (i, column) in columns.iter_mut().enumerate() {
@@ -501,6 +543,7 @@ impl<'a> TableBuilder<'a> {
                 columns: &columns,
                 widths: &state.column_widths,
                 max_used_widths: &mut max_used_widths,
+                pinned_columns,
                 row_index: 0,
                 col_index: 0,
                 height,
@@ -523,10 +566,12 @@ impl<'a> TableBuilder<'a> {
             max_used_widths,
             is_sizing_pass,
             resizable,
+            pinned_columns,
             striped,
             cell_layout,
             scroll_options,
             sense,
+            selection,
         }
     }
 
@@ -543,9 +588,11 @@ impl<'a> TableBuilder<'a> {
             columns,
             striped,
             resizable,
+            pinned_columns,
             cell_layout,
             scroll_options,
             sense,
+            selection,
         } = self;
 
         let striped = striped.unwrap_or(ui.visuals().striped);
@@ -568,10 +615,12 @@ impl<'a> TableBuilder<'a> {
             max_used_widths,
             is_sizing_pass,
             resizable,
+            pinned_columns,
             striped,
             cell_layout,
             scroll_options,
             sense,
+            selection,
         }
         .body(add_body_contents)
     }
@@ -674,6 +723,163 @@ impl TableState {
 
 // ----------------------------------------------------------------------------
 
+/// How many rows of a [`Table`] can be selected at once?
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SelectionMode {
+    /// At most one row can be selected.
+    #[default]
+    Single,
+
+    /// Multiple rows can be selected:
+    /// ctrl/cmd-click toggles a row, and shift-click selects a range.
+    Multi,
+}
+
+/// A set of selected table rows, managed by [`Table`].
+///
+/// Store this in your app state and pass it to [`TableBuilder::row_selection`].
+/// The table will then highlight the selected rows and update the set when
+/// rows are clicked: a plain click selects a single row, and with
+/// [`SelectionMode::Multi`] ctrl/cmd-click toggles a row
+/// while shift-click selects a range.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{Column, TableBuilder, TableSelection};
+/// # let mut selection = TableSelection::multi();
+/// TableBuilder::new(ui)
+///     .column(Column::remainder())
+///     .row_selection(&mut selection)
+///     .body(|body| {
+///         body.rows(18.0, 100, |mut row| {
+///             let row_index = row.index();
+///             row.col(|ui| {
+///                 ui.label(row_index.to_string());
+///             });
+///         });
+///     });
+/// if selection.changed() {
+///     // React to the new selection…
+/// }
+/// # });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TableSelection {
+    mode: SelectionMode,
+
+    selected: std::collections::BTreeSet<usize>,
+
+    /// Where a shift-click range selection starts.
+    anchor: Option<usize>,
+
+    /// Did a click change the selection during the last table pass?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    changed: bool,
+}
+
+impl TableSelection {
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    /// A selection where at most one row can be selected.
+    pub fn single() -> Self {
+        Self::new(SelectionMode::Single)
+    }
+
+    /// A selection where multiple rows can be selected
+    /// with ctrl/cmd-click and shift-click.
+    pub fn multi() -> Self {
+        Self::new(SelectionMode::Multi)
+    }
+
+    /// The indices of the selected rows, in ascending order.
+    pub fn selected(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected.iter().copied()
+    }
+
+    /// Is the given row selected?
+    pub fn contains(&self, row_index: usize) -> bool {
+        self.selected.contains(&row_index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    /// Programmatically add a row to the selection.
+    ///
+    /// With [`SelectionMode::Single`] this replaces the previous selection.
+    pub fn select(&mut self, row_index: usize) {
+        if self.mode == SelectionMode::Single {
+            self.selected.clear();
+        }
+        self.selected.insert(row_index);
+        self.anchor = Some(row_index);
+    }
+
+    /// Deselect all rows.
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// Did a click change the selection during the last table pass?
+    ///
+    /// This is reset by [`TableBuilder::row_selection`].
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+
+    /// Handle a click on the given row.
+    fn click(&mut self, row_index: usize, modifiers: Modifiers) {
+        let previous = self.selected.clone();
+
+        match self.mode {
+            SelectionMode::Single => {
+                let was_selected = self.selected.contains(&row_index);
+                self.selected.clear();
+                if !(modifiers.command && was_selected) {
+                    self.selected.insert(row_index);
+                }
+                self.anchor = Some(row_index);
+            }
+            SelectionMode::Multi => {
+                if modifiers.shift {
+                    let anchor = self.anchor.unwrap_or(row_index);
+                    if !modifiers.command {
+                        self.selected.clear();
+                    }
+                    self.selected
+                        .extend(anchor.min(row_index)..=anchor.max(row_index));
+                } else if modifiers.command {
+                    if !self.selected.remove(&row_index) {
+                        self.selected.insert(row_index);
+                    }
+                    self.anchor = Some(row_index);
+                } else {
+                    self.selected.clear();
+                    self.selected.insert(row_index);
+                    self.anchor = Some(row_index);
+                }
+            }
+        }
+
+        self.changed |= self.selected != previous;
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Table struct which can construct a [`TableBody`].
 ///
 /// Is created by [`TableBuilder`] by either calling [`TableBuilder::body`] or after creating a header row with [`TableBuilder::header`].
@@ -691,12 +897,15 @@ pub struct Table<'a> {
     /// During the sizing pass we calculate the width of columns with [`Column::auto`].
     is_sizing_pass: bool,
     resizable: bool,
+    pinned_columns: usize,
     striped: bool,
     cell_layout: egui::Layout,
 
     scroll_options: TableScrollOptions,
 
     sense: egui::Sense,
+
+    selection: Option<&'a mut TableSelection>,
 }
 
 impl Table<'_> {
@@ -718,6 +927,7 @@ impl Table<'_> {
             state_id,
             columns,
             resizable,
+            pinned_columns,
             mut available_width,
             mut state,
             mut max_used_widths,
@@ -726,6 +936,7 @@ impl Table<'_> {
             cell_layout,
             scroll_options,
             sense,
+            selection,
         } = self;
 
         let TableScrollOptions {
@@ -785,6 +996,7 @@ impl Table<'_> {
                     columns: columns_ref,
                     widths: widths_ref,
                     max_used_widths: max_used_widths_ref,
+                    pinned_columns,
                     striped,
                     row_index: 0,
                     y_range: clip_rect.y_range(),
@@ -792,6 +1004,7 @@ impl Table<'_> {
                     scroll_to_y_range: &mut scroll_to_y_range,
                     hovered_row_index,
                     hovered_row_index_id,
+                    selection,
                 });
 
                 if scroll_to_row.is_some() && scroll_to_y_range.is_none() {
@@ -811,6 +1024,16 @@ impl Table<'_> {
         let bottom = ui.min_rect().bottom();
 
         let spacing_x = ui.spacing().item_spacing.x;
+
+        // How far the table is scrolled horizontally within an enclosing scroll area,
+        // i.e. how far the pinned columns have been translated to stay visible:
+        let pinned_offset_x = if pinned_columns == 0 {
+            0.0
+        } else {
+            (ui.clip_rect().left() + ui.visuals().clip_rect_margin - cursor_position.x)
+                .at_least(0.0)
+        };
+
         let mut x = cursor_position.x - spacing_x * 0.5;
         for (i, column_width) in state.column_widths.iter_mut().enumerate() {
             let column = &columns[i];
@@ -853,8 +1076,16 @@ impl Table<'_> {
             } else if column_is_resizable {
                 let column_resize_id = ui.id().with("resize_column").with(i);
 
-                let mut p0 = egui::pos2(x, table_top);
-                let mut p1 = egui::pos2(x, bottom);
+                // Pinned columns are drawn translated so they stay visible
+                // under horizontal scrolling; translate their separators too:
+                let separator_x = if i < pinned_columns {
+                    x + pinned_offset_x
+                } else {
+                    x
+                };
+
+                let mut p0 = egui::pos2(separator_x, table_top);
+                let mut p1 = egui::pos2(separator_x, bottom);
                 let line_rect = egui::Rect::from_min_max(p0, p1)
                     .expand(ui.style().interaction.resize_grab_radius_side);
 
@@ -866,7 +1097,7 @@ impl Table<'_> {
                     *column_width = width_range.clamp(max_used_widths[i]);
                 } else if resize_response.dragged() {
                     if let Some(pointer) = ui.ctx().pointer_latest_pos() {
-                        let mut new_width = *column_width + pointer.x - x;
+                        let mut new_width = *column_width + pointer.x - separator_x;
                         if !column.clip {
                             // Unless we clip we don't want to shrink below the
                             // size that was actually used.
@@ -880,7 +1111,7 @@ impl Table<'_> {
                         }
                         new_width = width_range.clamp(new_width);
 
-                        let x = x - *column_width + new_width;
+                        let x = separator_x - *column_width + new_width;
                         (p0.x, p1.x) = (x, x);
 
                         *column_width = new_width;
@@ -931,6 +1162,9 @@ pub struct TableBody<'a> {
     /// Accumulated maximum used widths for each column.
     max_used_widths: &'a mut [f32],
 
+    /// The first `pinned_columns` columns stay visible under horizontal scrolling.
+    pinned_columns: usize,
+
     striped: bool,
     row_index: usize,
     y_range: Rangef,
@@ -946,6 +1180,9 @@ pub struct TableBody<'a> {
 
     /// Used to store the hovered row index between frames.
     hovered_row_index_id: egui::Id,
+
+    /// If set, the table manages a row selection (see [`TableBuilder::row_selection`]).
+    selection: Option<&'a mut TableSelection>,
 }
 
 impl<'a> TableBody<'a> {
@@ -983,21 +1220,24 @@ impl<'a> TableBody<'a> {
     pub fn row(&mut self, height: f32, add_row_content: impl FnOnce(TableRow<'a, '_>)) {
         let mut response: Option<Response> = None;
         let top_y = self.layout.cursor.y;
+        let selected = self.is_row_selected(self.row_index);
         add_row_content(TableRow {
             layout: &mut self.layout,
             columns: self.columns,
             widths: self.widths,
             max_used_widths: self.max_used_widths,
+            pinned_columns: self.pinned_columns,
             row_index: self.row_index,
             col_index: 0,
             height,
             striped: self.striped && self.row_index % 2 == 0,
             hovered: self.hovered_row_index == Some(self.row_index),
-            selected: false,
+            selected,
             overline: false,
             response: &mut response,
         });
         self.capture_hover_state(&response, self.row_index);
+        self.handle_selection(&response, self.row_index);
         let bottom_y = self.layout.cursor.y;
 
         if Some(self.row_index) == self.scroll_to_row {
@@ -1065,21 +1305,24 @@ impl<'a> TableBody<'a> {
 
         for row_index in min_row..max_row {
             let mut response: Option<Response> = None;
+            let selected = self.is_row_selected(row_index);
             add_row_content(TableRow {
                 layout: &mut self.layout,
                 columns: self.columns,
                 widths: self.widths,
                 max_used_widths: self.max_used_widths,
+                pinned_columns: self.pinned_columns,
                 row_index,
                 col_index: 0,
                 height: row_height_sans_spacing,
                 striped: self.striped && (row_index + self.row_index) % 2 == 0,
                 hovered: self.hovered_row_index == Some(row_index),
-                selected: false,
+                selected,
                 overline: false,
                 response: &mut response,
             });
             self.capture_hover_state(&response, row_index);
+            self.handle_selection(&response, row_index);
         }
 
         if total_rows - max_row > 0 {
@@ -1147,21 +1390,24 @@ impl<'a> TableBody<'a> {
                 // This row is visible:
                 self.add_buffer(old_cursor_y as f32); // skip all the invisible rows
                 let mut response: Option<Response> = None;
+                let selected = self.is_row_selected(row_index);
                 add_row_content(TableRow {
                     layout: &mut self.layout,
                     columns: self.columns,
                     widths: self.widths,
                     max_used_widths: self.max_used_widths,
+                    pinned_columns: self.pinned_columns,
                     row_index,
                     col_index: 0,
                     height: row_height,
                     striped: self.striped && (row_index + self.row_index) % 2 == 0,
                     hovered: self.hovered_row_index == Some(row_index),
-                    selected: false,
+                    selected,
                     overline: false,
                     response: &mut response,
                 });
                 self.capture_hover_state(&response, row_index);
+                self.handle_selection(&response, row_index);
                 break;
             }
         }
@@ -1170,21 +1416,24 @@ impl<'a> TableBody<'a> {
         for (row_index, row_height) in &mut enumerated_heights {
             let top_y = cursor_y;
             let mut response: Option<Response> = None;
+            let selected = self.is_row_selected(row_index);
             add_row_content(TableRow {
                 layout: &mut self.layout,
                 columns: self.columns,
                 widths: self.widths,
                 max_used_widths: self.max_used_widths,
+                pinned_columns: self.pinned_columns,
                 row_index,
                 col_index: 0,
                 height: row_height,
                 striped: self.striped && (row_index + self.row_index) % 2 == 0,
                 hovered: self.hovered_row_index == Some(row_index),
                 overline: false,
-                selected: false,
+                selected,
                 response: &mut response,
             });
             self.capture_hover_state(&response, row_index);
+            self.handle_selection(&response, row_index);
             cursor_y += (row_height + spacing.y) as f64;
 
             if Some(row_index) == self.scroll_to_row {
@@ -1243,6 +1492,23 @@ impl<'a> TableBody<'a> {
                 .data_mut(|data| data.insert_temp(self.hovered_row_index_id, row_index));
         }
     }
+
+    fn is_row_selected(&self, row_index: usize) -> bool {
+        self.selection
+            .as_ref()
+            .is_some_and(|selection| selection.contains(row_index))
+    }
+
+    // Update the managed selection if the just created row was clicked.
+    fn handle_selection(&mut self, response: &Option<Response>, row_index: usize) {
+        let Some(selection) = &mut self.selection else {
+            return;
+        };
+        if response.as_ref().is_some_and(|r| r.clicked()) {
+            let modifiers = self.layout.ui.input(|i| i.modifiers);
+            selection.click(row_index, modifiers);
+        }
+    }
 }
 
 impl Drop for TableBody<'_> {
@@ -1261,6 +1527,9 @@ pub struct TableRow<'a, 'b> {
     /// grows during building with the maximum widths
     max_used_widths: &'b mut [f32],
 
+    /// The first `pinned_columns` columns stay visible under horizontal scrolling.
+    pinned_columns: usize,
+
     row_index: usize,
     col_index: usize,
     height: f32,
@@ -1301,6 +1570,16 @@ impl TableRow<'_, '_> {
         let width = CellSize::Absolute(width);
         let height = CellSize::Absolute(self.height);
 
+        // Pinned columns are translated so they stay visible even when the
+        // table is scrolled horizontally. The scrolling columns are clipped
+        // so they don't paint under the pinned ones.
+        let is_pinned = col_index < self.pinned_columns;
+        let pinned_offset_x = if self.pinned_columns == 0 {
+            0.0
+        } else {
+            self.x_scroll_offset()
+        };
+
         let flags = StripLayoutFlags {
             clip,
             striped: self.striped,
@@ -1308,8 +1587,14 @@ impl TableRow<'_, '_> {
             selected: self.selected,
             overline: self.overline,
             sizing_pass: auto_size_this_frame || self.layout.ui.is_sizing_pass(),
+            clip_left_x: (!is_pinned && 0.0 < pinned_offset_x)
+                .then(|| self.pinned_edge_x(pinned_offset_x)),
         };
 
+        if is_pinned {
+            self.layout.cursor.x += pinned_offset_x;
+        }
+
         let (used_rect, response) = self.layout.add(
             flags,
             width,
@@ -1318,6 +1603,10 @@ impl TableRow<'_, '_> {
             add_cell_contents,
         );
 
+        if is_pinned {
+            self.layout.cursor.x -= pinned_offset_x;
+        }
+
         if let Some(max_w) = self.max_used_widths.get_mut(col_index) {
             *max_w = max_w.max(used_rect.width());
         }
@@ -1331,6 +1620,22 @@ impl TableRow<'_, '_> {
         (used_rect, response)
     }
 
+    /// How far the table has been scrolled horizontally within an enclosing scroll area.
+    fn x_scroll_offset(&self) -> f32 {
+        let ui = &self.layout.ui;
+        (ui.clip_rect().left() + ui.visuals().clip_rect_margin - self.layout.rect.left())
+            .at_least(0.0)
+    }
+
+    /// The x coordinate of the right edge of the (translated) pinned columns.
+    fn pinned_edge_x(&self, pinned_offset_x: f32) -> f32 {
+        let spacing_x = self.layout.ui.spacing().item_spacing.x;
+        let num_pinned = self.pinned_columns.min(self.widths.len());
+        let pinned_width: f32 =
+            self.widths[..num_pinned].iter().sum::<f32>() + num_pinned as f32 * spacing_x;
+        self.layout.rect.left() + pinned_offset_x + pinned_width - 0.5 * spacing_x
+    }
+
     /// Set the selection highlight state for cells added after a call to this function.
     #[inline]
     pub fn set_selected(&mut self, selected: bool) {
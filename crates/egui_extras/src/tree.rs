@@ -0,0 +1,387 @@
+//! A virtualized tree view with expand/collapse state, indentation guides,
+//! and keyboard navigation.
+
+use egui::{
+    collapsing_header::paint_default_icon, pos2, vec2, Align, Id, Key, Layout, Modifiers, Rect,
+    ScrollArea, Sense, Ui, UiBuilder,
+};
+
+/// A node handed to [`Tree`] by the node provider callback.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNode<NodeId> {
+    /// Identifies the node. Must be unique within the tree.
+    pub id: NodeId,
+
+    /// Does this node have children, i.e. should it get an expand/collapse button?
+    ///
+    /// The children themselves are only requested once the node is expanded,
+    /// so this can be answered cheaply even for lazy/remote data.
+    pub has_children: bool,
+}
+
+impl<NodeId> TreeNode<NodeId> {
+    pub fn new(id: NodeId, has_children: bool) -> Self {
+        Self { id, has_children }
+    }
+
+    /// A node without children.
+    pub fn leaf(id: NodeId) -> Self {
+        Self {
+            id,
+            has_children: false,
+        }
+    }
+}
+
+/// What happened in the tree this frame?
+#[derive(Clone, Debug)]
+pub struct TreeResponse<NodeId> {
+    /// The currently selected node, if any (and if currently visible in the tree).
+    pub selected: Option<NodeId>,
+
+    /// A node that was clicked this frame.
+    pub clicked: Option<NodeId>,
+
+    /// A node that was double-clicked this frame.
+    pub double_clicked: Option<NodeId>,
+}
+
+/// Expand/collapse and selection state of a [`Tree`], stored in egui memory.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct TreeState {
+    /// [`Id`]s (derived from the user node ids) of the expanded nodes.
+    expanded: std::collections::HashSet<Id>,
+
+    /// Row id of the selected node, if any.
+    selected: Option<Id>,
+
+    /// Scroll so that this row is visible, then clear.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scroll_to: Option<Id>,
+}
+
+impl TreeState {
+    fn load(ui: &Ui, state_id: Id) -> Self {
+        #[cfg(feature = "serde")]
+        let state = ui.data_mut(|d| d.get_persisted::<Self>(state_id));
+        #[cfg(not(feature = "serde"))]
+        let state = ui.data_mut(|d| d.get_temp::<Self>(state_id));
+
+        state.unwrap_or_default()
+    }
+
+    fn store(self, ui: &Ui, state_id: Id) {
+        #[cfg(feature = "serde")]
+        ui.data_mut(|d| d.insert_persisted(state_id, self));
+        #[cfg(not(feature = "serde"))]
+        ui.data_mut(|d| d.insert_temp(state_id, self));
+    }
+}
+
+/// One visible row of the flattened tree.
+struct TreeRow<NodeId> {
+    node: TreeNode<NodeId>,
+    depth: usize,
+    expanded: bool,
+    row_id: Id,
+}
+
+/// A tree view for large hierarchical data (file trees, JSON, …).
+///
+/// In contrast to nested [`egui::CollapsingHeader`]s, only the rows that are
+/// actually visible in the enclosing scroll area are laid out, so the cost per
+/// frame is proportional to the height of the view, not the size of the tree.
+///
+/// Nodes come from a *node provider* callback which is asked for the children
+/// of a node only once that node is expanded, so the tree also works for
+/// lazily loaded or remote data.
+///
+/// Rows can be selected by clicking them. When a row has keyboard focus you
+/// can navigate with the arrow keys: Up/Down moves the selection,
+/// Right expands a node (or steps into it), Left collapses it (or steps to the parent).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{Tree, TreeNode};
+///
+/// // A tree of all numbers up to 1000, where each number has its divisors as children:
+/// Tree::new("numbers").show(
+///     ui,
+///     |parent: Option<&u64>| match parent {
+///         None => (1..=1000).map(|n| TreeNode::new(n, n > 1)).collect(),
+///         Some(&n) => (1..n)
+///             .filter(|d| n % d == 0)
+///             .map(|d| TreeNode::new(d, d > 1))
+///             .collect(),
+///     },
+///     |ui, n| {
+///         ui.label(n.to_string());
+///     },
+/// );
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Tree<NodeId> {
+    id_salt: Id,
+    row_height: Option<f32>,
+    indent: Option<f32>,
+    indent_guides: bool,
+    marker: std::marker::PhantomData<NodeId>,
+}
+
+impl<NodeId: Clone + std::hash::Hash> Tree<NodeId> {
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            row_height: None,
+            indent: None,
+            indent_guides: true,
+            marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Height of each row, in points.
+    ///
+    /// Default: [`egui::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Horizontal indentation per tree level, in points.
+    ///
+    /// Default: [`egui::style::Spacing::indent`].
+    #[inline]
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Paint vertical guide lines connecting the rows of each expanded node?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn indent_guides(mut self, indent_guides: bool) -> Self {
+        self.indent_guides = indent_guides;
+        self
+    }
+
+    /// Show the tree.
+    ///
+    /// `children` is the node provider: it is called with `None` for the root
+    /// nodes, and with `Some(id)` for the children of an expanded node.
+    /// `node_ui` adds the contents of a single row (e.g. an icon and a label).
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        mut children: impl FnMut(Option<&NodeId>) -> Vec<TreeNode<NodeId>>,
+        mut node_ui: impl FnMut(&mut Ui, &NodeId),
+    ) -> TreeResponse<NodeId> {
+        let state_id = ui.id().with(self.id_salt);
+        let mut state = TreeState::load(ui, state_id);
+
+        let row_height = self.row_height.unwrap_or(ui.spacing().interact_size.y);
+        let indent = self.indent.unwrap_or(ui.spacing().indent);
+
+        // Flatten the visible (i.e. not hidden by a collapsed ancestor) part
+        // of the tree. This walks ids only - no layout happens here.
+        let mut rows: Vec<TreeRow<NodeId>> = vec![];
+        let mut stack: Vec<(TreeNode<NodeId>, usize)> = children(None)
+            .into_iter()
+            .rev()
+            .map(|node| (node, 0))
+            .collect();
+        while let Some((node, depth)) = stack.pop() {
+            let row_id = state_id.with(&node.id);
+            let expanded = node.has_children && state.expanded.contains(&row_id);
+            if expanded {
+                for child in children(Some(&node.id)).into_iter().rev() {
+                    stack.push((child, depth + 1));
+                }
+            }
+            rows.push(TreeRow {
+                node,
+                depth,
+                expanded,
+                row_id,
+            });
+        }
+
+        self.handle_keyboard(ui, &rows, &mut state);
+
+        let mut response = TreeResponse {
+            selected: None,
+            clicked: None,
+            double_clicked: None,
+        };
+
+        let row_height_with_spacing = row_height + ui.spacing().item_spacing.y;
+
+        ScrollArea::vertical()
+            .auto_shrink([false, true])
+            .show_viewport(ui, |ui, viewport| {
+                ui.set_height(row_height_with_spacing * rows.len() as f32);
+
+                let mut first = (viewport.min.y / row_height_with_spacing).floor() as usize;
+                let mut last = (viewport.max.y / row_height_with_spacing).ceil() as usize;
+
+                // Make sure a row we want to scroll to is laid out,
+                // even if it is currently outside the viewport:
+                if let Some(scroll_to) = state.scroll_to {
+                    if let Some(index) = rows.iter().position(|row| row.row_id == scroll_to) {
+                        first = first.min(index);
+                        last = last.max(index + 1);
+                    }
+                }
+
+                let x_range = ui.max_rect().x_range();
+                let top = ui.max_rect().top();
+
+                for (index, row) in rows
+                    .iter()
+                    .enumerate()
+                    .take(last.min(rows.len()))
+                    .skip(first.min(rows.len()))
+                {
+                    let rect = Rect::from_min_size(
+                        pos2(x_range.min, top + index as f32 * row_height_with_spacing),
+                        vec2(x_range.span(), row_height),
+                    );
+
+                    let row_response = ui.interact(rect, row.row_id, Sense::click());
+
+                    if state.scroll_to == Some(row.row_id) {
+                        ui.scroll_to_rect(rect, None);
+                        state.scroll_to = None;
+                    }
+
+                    let selected = state.selected == Some(row.row_id);
+                    if selected {
+                        ui.painter()
+                            .rect_filled(rect, 0.0, ui.visuals().selection.bg_fill);
+                    } else if row_response.hovered() {
+                        ui.painter().rect_filled(
+                            rect,
+                            0.0,
+                            ui.visuals().widgets.hovered.weak_bg_fill,
+                        );
+                    }
+
+                    if self.indent_guides {
+                        let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+                        for level in 1..=row.depth {
+                            let x = rect.left() + (level as f32 - 0.5) * indent;
+                            ui.painter().vline(x, rect.y_range(), stroke);
+                        }
+                    }
+
+                    // Expand/collapse button:
+                    let icon_rect = Rect::from_min_size(
+                        pos2(rect.left() + row.depth as f32 * indent, rect.top()),
+                        vec2(indent, row_height),
+                    );
+                    if row.node.has_children {
+                        let icon_response =
+                            ui.interact(icon_rect, row.row_id.with("toggle"), Sense::click());
+                        let openness = ui
+                            .ctx()
+                            .animate_bool(row.row_id.with("openness"), row.expanded);
+                        paint_default_icon(ui, openness, &icon_response);
+                        if icon_response.clicked() || row_response.double_clicked() {
+                            toggle_expanded(&mut state, row);
+                        }
+                    }
+
+                    if row_response.clicked() {
+                        state.selected = Some(row.row_id);
+                        row_response.request_focus();
+                        response.clicked = Some(row.node.id.clone());
+                    }
+                    if row_response.double_clicked() {
+                        response.double_clicked = Some(row.node.id.clone());
+                    }
+
+                    let content_rect = rect.with_min_x(icon_rect.right());
+                    let mut content_ui = ui.new_child(
+                        UiBuilder::new()
+                            .max_rect(content_rect)
+                            .layout(Layout::left_to_right(Align::Center)),
+                    );
+                    node_ui(&mut content_ui, &row.node.id);
+                }
+            });
+
+        state.scroll_to = None; // Don't keep scrolling to a row that no longer exists.
+
+        if let Some(selected) = state.selected {
+            response.selected = rows
+                .iter()
+                .find(|row| row.row_id == selected)
+                .map(|row| row.node.id.clone());
+        }
+
+        state.store(ui, state_id);
+
+        response
+    }
+
+    /// Arrow-key navigation, active while a row has keyboard focus.
+    fn handle_keyboard(&self, ui: &Ui, rows: &[TreeRow<NodeId>], state: &mut TreeState) {
+        let Some(selected) = state.selected else {
+            return;
+        };
+        if !ui.memory(|m| m.has_focus(selected)) {
+            return;
+        }
+        let Some(index) = rows.iter().position(|row| row.row_id == selected) else {
+            return;
+        };
+        let row = &rows[index];
+
+        let mut new_index = None;
+
+        if ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowUp)) && 0 < index {
+            new_index = Some(index - 1);
+        }
+        if ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowDown))
+            && index + 1 < rows.len()
+        {
+            new_index = Some(index + 1);
+        }
+        if ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowRight)) {
+            if row.node.has_children && !row.expanded {
+                state.expanded.insert(row.row_id);
+            } else if row.expanded {
+                new_index = Some(index + 1); // Step into the first child
+            }
+        }
+        if ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowLeft)) {
+            if row.expanded {
+                state.expanded.remove(&row.row_id);
+            } else if 0 < row.depth {
+                // Step out to the parent:
+                new_index = rows[..index]
+                    .iter()
+                    .rposition(|candidate| candidate.depth + 1 == row.depth);
+            }
+        }
+
+        if let Some(new_index) = new_index {
+            if let Some(new_row) = rows.get(new_index) {
+                state.selected = Some(new_row.row_id);
+                state.scroll_to = Some(new_row.row_id);
+                ui.memory_mut(|m| m.request_focus(new_row.row_id));
+            }
+        }
+    }
+}
+
+fn toggle_expanded<NodeId>(state: &mut TreeState, row: &TreeRow<NodeId>) {
+    if row.expanded {
+        state.expanded.remove(&row.row_id);
+    } else {
+        state.expanded.insert(row.row_id);
+    }
+}
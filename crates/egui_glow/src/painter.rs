@@ -111,6 +111,24 @@ pub struct Painter {
 /// The callback is passed, the [`egui::PaintCallbackInfo`] and the [`Painter`] which can be used to
 /// access the OpenGL context.
 ///
+/// Callbacks are dispatched by [`Painter::paint_primitives`],
+/// which is also what the eframe web backend uses,
+/// so this works the same on native and on WebGL.
+///
+/// # GL state contract
+///
+/// When the callback runs:
+/// - The viewport is set to the callback's rect (in physical pixels),
+///   and the scissor test is enabled with egui's current clip rect.
+/// - Blending is enabled, cull face is disabled.
+///
+/// The callback may freely change GL state: the painter re-establishes
+/// everything it needs (program, buffers, blend and scissor state) right
+/// after the callback returns. The one exception is the bound framebuffer —
+/// if you render to an offscreen FBO, rebind
+/// `gl.bind_framebuffer(glow::FRAMEBUFFER, painter.intermediate_fbo())`
+/// before returning. Do not delete resources you don't own.
+///
 /// # Example
 ///
 /// See the [`custom3d_glow`](https://github.com/emilk/egui/blob/main/crates/egui_demo_app/src/apps/custom3d_wgpu.rs) demo source for a detailed usage example.
@@ -507,6 +525,13 @@ impl Painter {
 
     // ------------------------------------------------------------------------
 
+    /// Create or update the texture for the given id from an [`egui::epaint::ImageDelta`].
+    ///
+    /// If `delta.pos` is set, only that sub-rectangle is uploaded with
+    /// `glTexSubImage2D` — the texture is not reallocated, so streaming
+    /// updates (video frames, procedural canvases) don't re-upload the
+    /// whole texture. This applies on WebGL too.
+    /// A delta without `pos` (re)allocates the full texture.
     pub fn set_texture(&mut self, tex_id: egui::TextureId, delta: &egui::epaint::ImageDelta) {
         profiling::function_scope!();
 
@@ -702,6 +727,112 @@ impl Painter {
         }
     }
 
+    /// Paint the given primitives to an offscreen framebuffer and read the result back.
+    ///
+    /// This sets up a temporary FBO of the given size, clears it with `clear_color`,
+    /// paints with [`Self::paint_and_update_textures`], and returns the pixels
+    /// as a [`egui::ColorImage`] — without requiring any window or screen framebuffer.
+    ///
+    /// This is mainly useful for golden-image tests:
+    /// feed it the `ClippedPrimitive`s and [`egui::TexturesDelta`] from a [`egui::FullOutput`]
+    /// and compare the result against a committed image,
+    /// e.g. with the tolerance-based snapshot utilities in `egui_kittest`.
+    ///
+    /// The previously bound framebuffer is restored (see [`Self::intermediate_fbo`])
+    /// and the temporary GL objects are deleted before returning.
+    ///
+    /// # Errors
+    /// Returns an error if the offscreen framebuffer could not be created or is incomplete.
+    pub fn render_offscreen(
+        &mut self,
+        size_px: [u32; 2],
+        pixels_per_point: f32,
+        clear_color: [f32; 4],
+        clipped_primitives: &[egui::ClippedPrimitive],
+        textures_delta: &egui::TexturesDelta,
+    ) -> Result<egui::ColorImage, PainterError> {
+        profiling::function_scope!();
+        self.assert_not_destroyed();
+
+        let [w, h] = size_px;
+
+        let (fbo, color_texture) = unsafe {
+            let color_texture = self.gl.create_texture()?;
+            self.gl.bind_texture(glow::TEXTURE_2D, Some(color_texture));
+            self.gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::LINEAR as i32,
+            );
+            self.gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::LINEAR as i32,
+            );
+            let internal_format = if self.is_webgl_1 {
+                glow::RGBA
+            } else {
+                glow::RGBA8
+            };
+            self.gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                internal_format as _,
+                w as _,
+                h as _,
+                0,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelUnpackData::Slice(None),
+            );
+            self.gl.bind_texture(glow::TEXTURE_2D, None);
+
+            let fbo = self.gl.create_framebuffer()?;
+            self.gl.bind_framebuffer(glow::FRAMEBUFFER, Some(fbo));
+            self.gl.framebuffer_texture_2d(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::TEXTURE_2D,
+                Some(color_texture),
+                0,
+            );
+            check_for_gl_error!(&self.gl, "render_offscreen setup");
+
+            let status = self.gl.check_framebuffer_status(glow::FRAMEBUFFER);
+            if status != glow::FRAMEBUFFER_COMPLETE {
+                self.gl
+                    .bind_framebuffer(glow::FRAMEBUFFER, self.intermediate_fbo());
+                self.gl.delete_framebuffer(fbo);
+                self.gl.delete_texture(color_texture);
+                return Err(PainterError(format!(
+                    "Offscreen framebuffer is incomplete: 0x{status:x}"
+                )));
+            }
+
+            (fbo, color_texture)
+        };
+
+        clear(&self.gl, size_px, clear_color);
+        self.paint_and_update_textures(
+            size_px,
+            pixels_per_point,
+            clipped_primitives,
+            textures_delta,
+        );
+
+        let image = self.read_screen_rgba(size_px);
+
+        unsafe {
+            self.gl
+                .bind_framebuffer(glow::FRAMEBUFFER, self.intermediate_fbo());
+            self.gl.delete_framebuffer(fbo);
+            self.gl.delete_texture(color_texture);
+        }
+        check_for_gl_error!(&self.gl, "render_offscreen cleanup");
+
+        Ok(image)
+    }
+
     pub fn read_screen_rgb(&self, [w, h]: [u32; 2]) -> Vec<u8> {
         profiling::function_scope!();
         let mut pixels = vec![0_u8; (w * h * 3) as usize];
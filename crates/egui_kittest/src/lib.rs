@@ -15,6 +15,10 @@ use std::time::Duration;
 
 mod app_kind;
 mod renderer;
+#[cfg(any(feature = "wgpu", feature = "snapshot"))]
+mod software;
+#[cfg(any(feature = "wgpu", feature = "snapshot"))]
+pub use software::SoftwareTestRenderer;
 #[cfg(feature = "wgpu")]
 mod texture_to_image;
 #[cfg(feature = "wgpu")]
@@ -414,6 +418,36 @@ impl<'a, State> Harness<'a, State> {
         &mut self.state
     }
 
+    /// Click at the given screen position.
+    /// This will create a pointer-move event, a primary-button press event and a release event.
+    ///
+    /// Prefer clicking widgets via the accessibility tree (e.g. `get_by_label(…).click()`)
+    /// when possible — use this when you need to hit an exact position,
+    /// e.g. painted contents with no widget of their own.
+    pub fn click_at(&mut self, pos: Pos2) {
+        self.input.events.push(egui::Event::PointerMoved(pos));
+        self.input.events.push(egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed: true,
+            modifiers: self.input.modifiers,
+        });
+        self.input.events.push(egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed: false,
+            modifiers: self.input.modifiers,
+        });
+    }
+
+    /// Type some text, as if entered via the keyboard.
+    ///
+    /// The text goes to whatever widget currently has focus,
+    /// so you typically want to click a [`egui::TextEdit`] first.
+    pub fn type_text(&mut self, text: &str) {
+        self.input.events.push(egui::Event::Text(text.to_owned()));
+    }
+
     /// Press a key.
     /// This will create a key down event and a key up event.
     pub fn press_key(&mut self, key: egui::Key) {
@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use egui::{ColorImage, TextureId, TexturesDelta};
+use image::RgbaImage;
+
+/// A pure-CPU test renderer that rasterizes the tessellated meshes itself.
+///
+/// No GPU, window, or driver is required, which makes this suitable for
+/// headless CI environments where neither wgpu nor OpenGL can initialize.
+/// It is not fast, but it is deterministic:
+/// triangles are rasterized with bilinear texture sampling and the same
+/// gamma-space blending as the GPU backends, so the feathered (antialiased)
+/// edges produced by the tessellator come out looking the same.
+///
+/// [`egui::Shape::Callback`]s are skipped, since they require a GPU.
+///
+/// ```
+/// # use egui_kittest::{Harness, SoftwareTestRenderer};
+/// let mut harness = Harness::builder()
+///     .renderer(SoftwareTestRenderer::new())
+///     .build_ui(|ui| {
+///         ui.label("Hello, world!");
+///     });
+/// ```
+#[derive(Default)]
+pub struct SoftwareTestRenderer {
+    textures: HashMap<TextureId, ColorImage>,
+}
+
+impl SoftwareTestRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl crate::TestRenderer for SoftwareTestRenderer {
+    fn handle_delta(&mut self, delta: &TexturesDelta) {
+        for (id, image_delta) in &delta.set {
+            let source: ColorImage = match &image_delta.image {
+                egui::ImageData::Color(image) => (**image).clone(),
+                egui::ImageData::Font(image) => ColorImage {
+                    size: image.size,
+                    pixels: image.srgba_pixels(None).collect(),
+                },
+            };
+
+            if let Some([x, y]) = image_delta.pos {
+                // Partial update:
+                if let Some(texture) = self.textures.get_mut(id) {
+                    let [source_w, source_h] = source.size;
+                    let texture_w = texture.size[0];
+                    for dy in 0..source_h {
+                        for dx in 0..source_w {
+                            texture.pixels[(y + dy) * texture_w + (x + dx)] =
+                                source.pixels[dy * source_w + dx];
+                        }
+                    }
+                }
+            } else {
+                self.textures.insert(*id, source);
+            }
+        }
+
+        for id in &delta.free {
+            self.textures.remove(id);
+        }
+    }
+
+    fn render(
+        &mut self,
+        ctx: &egui::Context,
+        output: &egui::FullOutput,
+    ) -> Result<RgbaImage, String> {
+        let pixels_per_point = ctx.pixels_per_point();
+        let size = ctx.screen_rect().size() * pixels_per_point;
+        let width = size.x.round() as usize;
+        let height = size.y.round() as usize;
+
+        // Premultiplied gamma-space RGBA, cleared to transparent:
+        let mut framebuffer = vec![[0.0_f32; 4]; width * height];
+
+        let tessellated = ctx.tessellate(output.shapes.clone(), pixels_per_point);
+
+        for egui::ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } in &tessellated
+        {
+            match primitive {
+                egui::epaint::Primitive::Mesh(mesh) => {
+                    let texture = self
+                        .textures
+                        .get(&mesh.texture_id)
+                        .ok_or_else(|| format!("Missing texture {:?}", mesh.texture_id))?;
+                    rasterize_mesh(
+                        &mut framebuffer,
+                        [width, height],
+                        mesh,
+                        texture,
+                        *clip_rect,
+                        pixels_per_point,
+                    );
+                }
+                egui::epaint::Primitive::Callback(_) => {
+                    // Paint callbacks require a GPU - skipped.
+                }
+            }
+        }
+
+        let mut bytes = Vec::with_capacity(width * height * 4);
+        for pixel in &framebuffer {
+            for channel in pixel {
+                bytes.push((channel * 255.0 + 0.5).clamp(0.0, 255.0) as u8);
+            }
+        }
+        RgbaImage::from_raw(width as u32, height as u32, bytes)
+            .ok_or_else(|| "Failed to create output image".to_owned())
+    }
+}
+
+fn rasterize_mesh(
+    framebuffer: &mut [[f32; 4]],
+    [width, height]: [usize; 2],
+    mesh: &egui::epaint::Mesh,
+    texture: &ColorImage,
+    clip_rect: egui::Rect,
+    pixels_per_point: f32,
+) {
+    // Scissor rectangle in whole pixels, like the GPU backends:
+    let clip_min_x = (pixels_per_point * clip_rect.min.x).round().max(0.0) as usize;
+    let clip_min_y = (pixels_per_point * clip_rect.min.y).round().max(0.0) as usize;
+    let clip_max_x = ((pixels_per_point * clip_rect.max.x).round() as usize).min(width);
+    let clip_max_y = ((pixels_per_point * clip_rect.max.y).round() as usize).min(height);
+
+    for triangle in mesh.indices.chunks_exact(3) {
+        let v0 = &mesh.vertices[triangle[0] as usize];
+        let v1 = &mesh.vertices[triangle[1] as usize];
+        let v2 = &mesh.vertices[triangle[2] as usize];
+
+        let p0 = v0.pos * pixels_per_point;
+        let p1 = v1.pos * pixels_per_point;
+        let p2 = v2.pos * pixels_per_point;
+
+        let area = edge_function(p0, p1, p2);
+        if area == 0.0 {
+            continue; // Degenerate triangle
+        }
+        // Accept both windings, like the GPU backends (no backface culling):
+        let winding = area.signum();
+        let inv_area = 1.0 / area.abs();
+
+        let min_x = (p0.x.min(p1.x).min(p2.x).floor().max(0.0) as usize).max(clip_min_x);
+        let min_y = (p0.y.min(p1.y).min(p2.y).floor().max(0.0) as usize).max(clip_min_y);
+        let max_x =
+            ((p0.x.max(p1.x).max(p2.x).ceil().max(0.0) as usize).min(clip_max_x)).min(width);
+        let max_y =
+            ((p0.y.max(p1.y).max(p2.y).ceil().max(0.0) as usize).min(clip_max_y)).min(height);
+
+        let c0 = normalized_gamma(v0.color);
+        let c1 = normalized_gamma(v1.color);
+        let c2 = normalized_gamma(v2.color);
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let pixel_center = egui::pos2(x as f32 + 0.5, y as f32 + 0.5);
+
+                let w0 = winding * edge_function(p1, p2, pixel_center);
+                let w1 = winding * edge_function(p2, p0, pixel_center);
+                let w2 = winding * edge_function(p0, p1, pixel_center);
+                if w0 < 0.0 || w1 < 0.0 || w2 < 0.0 {
+                    continue; // Outside the triangle
+                }
+
+                let b0 = w0 * inv_area;
+                let b1 = w1 * inv_area;
+                let b2 = w2 * inv_area;
+
+                let uv = egui::pos2(
+                    b0 * v0.uv.x + b1 * v1.uv.x + b2 * v2.uv.x,
+                    b0 * v0.uv.y + b1 * v1.uv.y + b2 * v2.uv.y,
+                );
+                let texel = sample_bilinear(texture, uv);
+
+                // Same as the fragment shaders: vertex color times texel, in gamma space:
+                let mut src = [0.0; 4];
+                for i in 0..4 {
+                    src[i] = (b0 * c0[i] + b1 * c1[i] + b2 * c2[i]) * texel[i];
+                }
+
+                // Premultiplied alpha blending:
+                let dst = &mut framebuffer[y * width + x];
+                let one_minus_src_alpha = 1.0 - src[3];
+                for i in 0..4 {
+                    dst[i] = src[i] + dst[i] * one_minus_src_alpha;
+                }
+            }
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `(a, b, c)`.
+fn edge_function(a: egui::Pos2, b: egui::Pos2, c: egui::Pos2) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+fn normalized_gamma(color: egui::Color32) -> [f32; 4] {
+    let [r, g, b, a] = color.to_array();
+    [
+        r as f32 / 255.0,
+        g as f32 / 255.0,
+        b as f32 / 255.0,
+        a as f32 / 255.0,
+    ]
+}
+
+/// Bilinear sampling with clamp-to-edge, in normalized gamma space.
+fn sample_bilinear(texture: &ColorImage, uv: egui::Pos2) -> [f32; 4] {
+    let [w, h] = texture.size;
+    if w == 0 || h == 0 {
+        return [1.0; 4];
+    }
+
+    let x = (uv.x * w as f32 - 0.5).clamp(0.0, (w - 1) as f32);
+    let y = (uv.y * h as f32 - 0.5).clamp(0.0, (h - 1) as f32);
+
+    let x0 = x.floor() as usize;
+    let y0 = y.floor() as usize;
+    let x1 = (x0 + 1).min(w - 1);
+    let y1 = (y0 + 1).min(h - 1);
+    let tx = x - x0 as f32;
+    let ty = y - y0 as f32;
+
+    let texel = |x: usize, y: usize| normalized_gamma(texture.pixels[y * w + x]);
+
+    let top = lerp4(texel(x0, y0), texel(x1, y0), tx);
+    let bottom = lerp4(texel(x0, y1), texel(x1, y1), tx);
+    lerp4(top, bottom, ty)
+}
+
+fn lerp4(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    let mut result = [0.0; 4];
+    for i in 0..4 {
+        result[i] = a[i] + (b[i] - a[i]) * t;
+    }
+    result
+}
@@ -228,3 +228,143 @@ pub fn bounce_in_out(t: f32) -> f32 {
         0.5 * bounce_out(t * 2. - 1.) + 0.5
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// The easing functions in this module, as an enum.
+///
+/// Handy when you want to store an easing choice in a setting,
+/// or let the user pick one in a UI.
+/// Get the actual function with [`Self::function`],
+/// or apply it directly with [`Self::apply`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Easing {
+    /// [`linear`]
+    #[default]
+    Linear,
+
+    /// [`quadratic_in`]
+    QuadraticIn,
+
+    /// [`quadratic_out`]
+    QuadraticOut,
+
+    /// [`quadratic_in_out`]
+    QuadraticInOut,
+
+    /// [`cubic_in`]
+    CubicIn,
+
+    /// [`cubic_out`]
+    CubicOut,
+
+    /// [`cubic_in_out`]
+    CubicInOut,
+
+    /// [`sin_in`]
+    SinIn,
+
+    /// [`sin_out`]
+    SinOut,
+
+    /// [`sin_in_out`]
+    SinInOut,
+
+    /// [`circular_in`]
+    CircularIn,
+
+    /// [`circular_out`]
+    CircularOut,
+
+    /// [`circular_in_out`]
+    CircularInOut,
+
+    /// [`exponential_in`]
+    ExponentialIn,
+
+    /// [`exponential_out`]
+    ExponentialOut,
+
+    /// [`exponential_in_out`]
+    ExponentialInOut,
+
+    /// [`back_in`]
+    BackIn,
+
+    /// [`back_out`]
+    BackOut,
+
+    /// [`back_in_out`]
+    BackInOut,
+
+    /// [`bounce_in`]
+    BounceIn,
+
+    /// [`bounce_out`]
+    BounceOut,
+
+    /// [`bounce_in_out`]
+    BounceInOut,
+}
+
+impl Easing {
+    pub const ALL: [Self; 22] = [
+        Self::Linear,
+        Self::QuadraticIn,
+        Self::QuadraticOut,
+        Self::QuadraticInOut,
+        Self::CubicIn,
+        Self::CubicOut,
+        Self::CubicInOut,
+        Self::SinIn,
+        Self::SinOut,
+        Self::SinInOut,
+        Self::CircularIn,
+        Self::CircularOut,
+        Self::CircularInOut,
+        Self::ExponentialIn,
+        Self::ExponentialOut,
+        Self::ExponentialInOut,
+        Self::BackIn,
+        Self::BackOut,
+        Self::BackInOut,
+        Self::BounceIn,
+        Self::BounceOut,
+        Self::BounceInOut,
+    ];
+
+    /// The easing function this variant names.
+    pub fn function(self) -> fn(f32) -> f32 {
+        match self {
+            Self::Linear => linear,
+            Self::QuadraticIn => quadratic_in,
+            Self::QuadraticOut => quadratic_out,
+            Self::QuadraticInOut => quadratic_in_out,
+            Self::CubicIn => cubic_in,
+            Self::CubicOut => cubic_out,
+            Self::CubicInOut => cubic_in_out,
+            Self::SinIn => sin_in,
+            Self::SinOut => sin_out,
+            Self::SinInOut => sin_in_out,
+            Self::CircularIn => circular_in,
+            Self::CircularOut => circular_out,
+            Self::CircularInOut => circular_in_out,
+            Self::ExponentialIn => exponential_in,
+            Self::ExponentialOut => exponential_out,
+            Self::ExponentialInOut => exponential_in_out,
+            Self::BackIn => back_in,
+            Self::BackOut => back_out,
+            Self::BackInOut => back_in_out,
+            Self::BounceIn => bounce_in,
+            Self::BounceOut => bounce_out,
+            Self::BounceInOut => bounce_in_out,
+        }
+    }
+
+    /// Apply the easing function to a value in `[0, 1]`.
+    #[inline]
+    pub fn apply(self, t: f32) -> f32 {
+        (self.function())(t)
+    }
+}
@@ -247,6 +247,57 @@ impl ColorImage {
             pixels: output,
         }
     }
+
+    /// Shrink the image so that no side is larger than `max_side`, preserving the aspect ratio.
+    ///
+    /// Uses a box filter. Returns a clone if the image already fits.
+    ///
+    /// Useful to make sure an image fits within the painter's max texture side
+    /// (see [`crate::textures::TextureManager`]).
+    pub fn downscale_to_fit(&self, max_side: usize) -> Self {
+        let [w, h] = self.size;
+        if w <= max_side && h <= max_side {
+            return self.clone();
+        }
+
+        let scale = max_side as f32 / w.max(h) as f32;
+        let new_w = ((w as f32 * scale).round() as usize).clamp(1, max_side);
+        let new_h = ((h as f32 * scale).round() as usize).clamp(1, max_side);
+
+        let mut pixels = Vec::with_capacity(new_w * new_h);
+        for ny in 0..new_h {
+            let y0 = ny * h / new_h;
+            let y1 = ((ny + 1) * h / new_h).max(y0 + 1).min(h);
+            for nx in 0..new_w {
+                let x0 = nx * w / new_w;
+                let x1 = ((nx + 1) * w / new_w).max(x0 + 1).min(w);
+
+                // Average the source box (premultiplied alpha, so this is correct):
+                let mut sum = [0.0_f32; 4];
+                for y in y0..y1 {
+                    for x in x0..x1 {
+                        let [r, g, b, a] = self.pixels[y * w + x].to_array();
+                        sum[0] += r as f32;
+                        sum[1] += g as f32;
+                        sum[2] += b as f32;
+                        sum[3] += a as f32;
+                    }
+                }
+                let num_pixels = ((x1 - x0) * (y1 - y0)) as f32;
+                pixels.push(Color32::from_rgba_premultiplied(
+                    (sum[0] / num_pixels).round() as u8,
+                    (sum[1] / num_pixels).round() as u8,
+                    (sum[2] / num_pixels).round() as u8,
+                    (sum[3] / num_pixels).round() as u8,
+                ));
+            }
+        }
+
+        Self {
+            size: [new_w, new_h],
+            pixels,
+        }
+    }
 }
 
 impl std::ops::Index<(usize, usize)> for ColorImage {
@@ -299,6 +299,17 @@ impl Mesh {
         }
     }
 
+    /// A copy of this mesh, translated by this much.
+    ///
+    /// Only the vertex positions change; UVs and colors are preserved.
+    /// Useful for building a mesh once and stamping it at multiple positions.
+    #[must_use]
+    pub fn translated(&self, delta: Vec2) -> Self {
+        let mut mesh = self.clone();
+        mesh.translate(delta);
+        mesh
+    }
+
     /// Transform the mesh in-place with the given transform.
     pub fn transform(&mut self, transform: TSTransform) {
         for v in &mut self.vertices {
@@ -306,6 +317,17 @@ impl Mesh {
         }
     }
 
+    /// A copy of this mesh, transformed by the given transform.
+    ///
+    /// Only the vertex positions change; UVs and colors are preserved.
+    /// Useful for building a mesh once and stamping it at multiple positions and scales.
+    #[must_use]
+    pub fn transformed(&self, transform: TSTransform) -> Self {
+        let mut mesh = self.clone();
+        mesh.transform(transform);
+        mesh
+    }
+
     /// Rotate by some angle about an origin, in-place.
     ///
     /// Origin is a position in screen space.
@@ -5,6 +5,11 @@ use crate::{Color32, CornerRadius, MarginF32, Rect, RectShape, Vec2};
 /// Can be used for a rectangular shadow with a soft penumbra.
 ///
 /// Very similar to a box-shadow in CSS.
+///
+/// The shadow is tessellated as a rounded rectangle with a wide feathered edge
+/// (see [`RectShape::with_blur_width`]), so it is a plain mesh like any other shape:
+/// no per-pixel shaders are required, and it is clipped by whatever clip rectangle
+/// is active when it is painted.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Shadow {
@@ -43,6 +48,16 @@ impl Shadow {
         color: Color32::TRANSPARENT,
     };
 
+    /// A shadow moved by `offset`, with the given blur width, spread, and color.
+    pub fn new(offset: [i8; 2], blur: u8, spread: u8, color: Color32) -> Self {
+        Self {
+            offset,
+            blur,
+            spread,
+            color,
+        }
+    }
+
     /// The argument is the rectangle of the shadow caster.
     pub fn as_shape(&self, rect: Rect, corner_radius: impl Into<CornerRadius>) -> RectShape {
         // tessellator.clip_rect = clip_rect; // TODO(emilk): culling
@@ -185,6 +185,12 @@ impl Shape {
     }
 
     /// Turn a line into dashes with different dash/gap lengths and a start offset.
+    ///
+    /// The offset shifts the dash pattern along the path and wraps around the
+    /// total pattern length, so feeding it an ever-growing value (e.g. based on time)
+    /// produces a "marching ants" animation.
+    ///
+    /// For a closed polygon, repeat the first point at the end of `path`.
     pub fn dashed_line_with_offset(
         path: &[Pos2],
         stroke: impl Into<Stroke>,
@@ -315,7 +321,7 @@ impl Shape {
     /// Any non-placeholder color in the galley takes precedence over this fallback color.
     #[inline]
     pub fn galley(pos: Pos2, galley: Arc<Galley>, fallback_color: Color32) -> Self {
-        TextShape::new(pos, galley, fallback_color).into()
+        Self::with_color_glyphs(TextShape::new(pos, galley, fallback_color))
     }
 
     /// All text color in the [`Galley`] will be replaced with the given color.
@@ -325,9 +331,31 @@ impl Shape {
         galley: Arc<Galley>,
         text_color: Color32,
     ) -> Self {
-        TextShape::new(pos, galley, text_color)
-            .with_override_text_color(text_color)
-            .into()
+        Self::with_color_glyphs(
+            TextShape::new(pos, galley, text_color).with_override_text_color(text_color),
+        )
+    }
+
+    /// Composite the colored glyph images of the galley (if any) on top of the text.
+    ///
+    /// See [`crate::text::FontDefinitions::color_glyphs`].
+    fn with_color_glyphs(text_shape: TextShape) -> Self {
+        if text_shape.galley.color_glyphs.is_empty() {
+            text_shape.into()
+        } else {
+            let pos = text_shape.pos;
+            let galley = text_shape.galley.clone();
+            let mut shapes = vec![Self::from(text_shape)];
+            shapes.extend(galley.color_glyphs.iter().map(|placed| {
+                Self::image(
+                    placed.color_glyph.texture_id,
+                    placed.rect.translate(pos.to_vec2()),
+                    placed.color_glyph.uv,
+                    Color32::WHITE,
+                )
+            }));
+            Self::Vec(shapes)
+        }
     }
 
     #[inline]
@@ -520,10 +548,42 @@ fn dashes_from_line(
         dash_lengths.len(),
         gap_lengths.len()
     );
-    let mut position_on_segment = dash_offset;
+    let steps = dash_lengths.len();
+
+    let mut position_on_segment = 0.0;
     let mut drawing_dash = false;
     let mut step = 0;
-    let steps = dash_lengths.len();
+
+    let total_pattern_length: f32 =
+        dash_lengths.iter().sum::<f32>() + gap_lengths.iter().sum::<f32>();
+    if total_pattern_length > 0.0 && dash_offset != 0.0 {
+        // Wrap the offset around the total pattern length, so that an
+        // ever-growing (animated) offset keeps marching instead of pushing
+        // the whole pattern off the end of the path.
+        // We then walk the pattern to find which dash or gap the path starts in,
+        // so that a path can begin mid-dash.
+        let mut remaining = (-dash_offset).rem_euclid(total_pattern_length);
+        loop {
+            let dash_length = dash_lengths[step];
+            if remaining < dash_length {
+                // The path starts inside this dash:
+                drawing_dash = true;
+                position_on_segment = dash_length - remaining;
+                break;
+            }
+            remaining -= dash_length;
+
+            let gap_length = gap_lengths[step];
+            step = (step + 1) % steps;
+            if remaining < gap_length {
+                // The path starts inside this gap:
+                position_on_segment = gap_length - remaining;
+                break;
+            }
+            remaining -= gap_length;
+        }
+    }
+
     for window in path.windows(2) {
         let (start, end) = (window[0], window[1]);
         let vector = end - start;
@@ -112,6 +112,7 @@ impl TextShape {
             job: _,
             rows,
             elided: _,
+            color_glyphs,
             rect,
             mesh_bounds,
             num_vertices: _,
@@ -122,6 +123,10 @@ impl TextShape {
         *rect = transform.scaling * *rect;
         *mesh_bounds = transform.scaling * *mesh_bounds;
 
+        for color_glyph in color_glyphs {
+            color_glyph.rect = transform.scaling * color_glyph.rect;
+        }
+
         for text::PlacedRow { pos, row } in rows {
             *pos *= transform.scaling;
 
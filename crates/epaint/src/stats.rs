@@ -233,15 +233,20 @@ impl PaintStats {
         mut self,
         clipped_primitives: &[crate::ClippedPrimitive],
     ) -> Self {
-        self.clipped_primitives += AllocInfo::from_slice(clipped_primitives);
         for clipped_primitive in clipped_primitives {
-            if let Primitive::Mesh(mesh) = &clipped_primitive.primitive {
-                self.vertices += AllocInfo::from_slice(&mesh.vertices);
-                self.indices += AllocInfo::from_slice(&mesh.indices);
-            }
+            self.add_clipped_primitive(clipped_primitive);
         }
         self
     }
+
+    /// Add the stats of a single tessellated primitive.
+    pub fn add_clipped_primitive(&mut self, clipped_primitive: &crate::ClippedPrimitive) {
+        self.clipped_primitives += AllocInfo::from_slice(std::slice::from_ref(clipped_primitive));
+        if let Primitive::Mesh(mesh) = &clipped_primitive.primitive {
+            self.vertices += AllocInfo::from_slice(&mesh.vertices);
+            self.indices += AllocInfo::from_slice(&mesh.indices);
+        }
+    }
 }
 
 fn megabytes(size: usize) -> String {
@@ -148,6 +148,24 @@ impl PathStroke {
 
     /// Create a new `PathStroke` with a UV function
     ///
+    /// The callback is evaluated per stroke vertex, so a single path tessellates
+    /// into one mesh whose triangles carry interpolated vertex colors —
+    /// no need to split a line into many small solid-colored segments.
+    /// The antialiasing feathering uses the same callback, so soft edges keep the varying color.
+    ///
+    /// For example, a line with a gradient along the x-axis:
+    ///
+    /// ```
+    /// # use epaint::{Color32, PathStroke, Shape, Pos2};
+    /// let gradient = Shape::line(
+    ///     vec![Pos2::new(0.0, 0.0), Pos2::new(50.0, 20.0), Pos2::new(100.0, 0.0)],
+    ///     PathStroke::new_uv(2.0, |bounds, pos| {
+    ///         let t = (pos.x - bounds.left()) / bounds.width();
+    ///         Color32::BLUE.lerp_to_gamma(Color32::RED, t)
+    ///     }),
+    /// );
+    /// ```
+    ///
     /// The bounding box passed to the callback will have a margin of [`TessellationOptions::feathering_size_in_pixels`](`crate::tessellator::TessellationOptions::feathering_size_in_pixels`)
     #[inline]
     pub fn new_uv(
@@ -426,6 +426,74 @@ impl Path {
         }
     }
 
+    /// Like [`Self::add_open_points`], but inserts rounded join geometry
+    /// (a fan of points) at corners, instead of miter or cut-off joins.
+    ///
+    /// Shallow corners (spanning less than one fan step) still get miter joins,
+    /// so this only costs extra points where a round join is actually visible.
+    ///
+    /// `max_fan_step_angle` is the maximum angle (in radians) covered by each
+    /// segment of a join fan (see [`TessellationOptions::round_line_joins_fan_angle`]).
+    ///
+    /// See [`TessellationOptions::round_line_joins`].
+    pub fn add_open_points_round_joins(&mut self, points: &[Pos2], max_fan_step_angle: f32) {
+        let n = points.len();
+        assert!(n >= 2, "A path needs at least two points, but got {n}");
+
+        if n == 2 {
+            // Common case optimization:
+            self.add_line_segment([points[0], points[1]]);
+            return;
+        }
+
+        use std::f32::consts::{PI, TAU};
+
+        // Guard against degenerate values producing absurdly many points:
+        let max_fan_step_angle = max_fan_step_angle.max(0.01);
+
+        self.reserve(n);
+        self.add_point(points[0], (points[1] - points[0]).normalized().rot90());
+        let mut n0 = (points[1] - points[0]).normalized().rot90();
+        for i in 1..n - 1 {
+            let mut n1 = (points[i + 1] - points[i]).normalized().rot90();
+
+            // Handle duplicated points (but not triplicated…):
+            if n0 == Vec2::ZERO {
+                n0 = n1;
+            } else if n1 == Vec2::ZERO {
+                n1 = n0;
+            }
+
+            let mut turn_angle = n1.angle() - n0.angle();
+            if turn_angle > PI {
+                turn_angle -= TAU;
+            } else if turn_angle < -PI {
+                turn_angle += TAU;
+            }
+
+            let fan_steps = (turn_angle.abs() / max_fan_step_angle).ceil() as usize;
+            if fan_steps <= 1 {
+                // Shallow corner - a miter join is indistinguishable from a round one:
+                let normal = (n0 + n1) / 2.0;
+                self.add_point(points[i], normal / normal.length_sq());
+            } else {
+                // Insert a fan of unit normals rotating from `n0` to `n1`.
+                // The stroke extrusion turns this into an arc of radius half the stroke width.
+                self.reserve(fan_steps + 1);
+                for step in 0..=fan_steps {
+                    let normal = Rot2::from_angle(turn_angle * step as f32 / fan_steps as f32) * n0;
+                    self.add_point(points[i], normal);
+                }
+            }
+
+            n0 = n1;
+        }
+        self.add_point(
+            points[n - 1],
+            (points[n - 1] - points[n - 2]).normalized().rot90(),
+        );
+    }
+
     pub fn add_line_loop(&mut self, points: &[Pos2]) {
         let n = points.len();
         assert!(n >= 2, "A path needs at least two points, but got {n}");
@@ -689,6 +757,42 @@ pub struct TessellationOptions {
     /// This makes the line segments appear crisp on any display.
     pub round_line_segments_to_pixels: bool,
 
+    /// If `true`, insert rounded join geometry (arc fans) at the corners of
+    /// thick open polylines, instead of the default miter/cut-off joins.
+    ///
+    /// This removes the spikes and gaps that miter joins produce at sharp corners,
+    /// at the cost of a few extra vertices per corner.
+    ///
+    /// Only applies to strokes at least [`Self::round_line_joins_min_width`] wide,
+    /// so hairlines don't pay the extra tessellation cost.
+    ///
+    /// Default: `false`.
+    pub round_line_joins: bool,
+
+    /// Minimum stroke width (in points) for [`Self::round_line_joins`] to kick in.
+    ///
+    /// Default: `2.0`.
+    pub round_line_joins_min_width: f32,
+
+    /// Maximum angle (in radians) covered by each segment of a round-join fan.
+    ///
+    /// This doubles as the rounding threshold: corners that turn less than this
+    /// keep a plain miter join, and sharper corners get one fan point
+    /// per step of at most this angle.
+    /// Lower values give smoother joins, but more triangles.
+    ///
+    /// Default: `0.35` (about 20°).
+    pub round_line_joins_fan_angle: f32,
+
+    /// If `true`, also add round caps at the endpoints of the open polylines
+    /// rendered with [`Self::round_line_joins`].
+    ///
+    /// The caps are filled discs centered on the endpoints,
+    /// so with translucent strokes the overlap with the stroke will show.
+    ///
+    /// Default: `false`.
+    pub round_line_caps: bool,
+
     /// If `true` (default), align rectangles to the physical pixel grid.
     ///
     /// This makes the rectangle strokes more crisp,
@@ -731,6 +835,10 @@ impl Default for TessellationOptions {
             prerasterized_discs: true,
             round_text_to_pixels: true,
             round_line_segments_to_pixels: true,
+            round_line_joins: false,
+            round_line_joins_min_width: 2.0,
+            round_line_joins_fan_angle: 0.35,
+            round_line_caps: false,
             round_rects_to_pixels: true,
             debug_paint_text_rects: false,
             debug_paint_clip_rects: false,
@@ -1746,10 +1854,47 @@ impl Tessellator {
                 "You asked to fill a path that is not closed. That makes no sense."
             );
 
-            self.scratchpad_path.add_open_points(points);
+            let round_joins = self.options.round_line_joins
+                && self.options.round_line_joins_min_width <= stroke.width;
+            if round_joins {
+                self.scratchpad_path
+                    .add_open_points_round_joins(points, self.options.round_line_joins_fan_angle);
+            } else {
+                self.scratchpad_path.add_open_points(points);
+            }
 
             self.scratchpad_path
                 .stroke(self.feathering, PathType::Open, stroke, out);
+
+            if round_joins && self.options.round_line_caps {
+                self.add_round_line_caps(points, stroke, out);
+            }
+        }
+    }
+
+    /// Add round caps (filled discs) at the endpoints of an open stroke.
+    ///
+    /// See [`TessellationOptions::round_line_caps`].
+    fn add_round_line_caps(&mut self, points: &[Pos2], stroke: &PathStroke, out: &mut Mesh) {
+        let radius = 0.5 * stroke.width;
+        if radius <= 0.0 || points.is_empty() {
+            return;
+        }
+
+        let uv_bbox = Rect::from_points(points).expand((stroke.width / 2.0) + self.feathering);
+
+        for endpoint in [points[0], points[points.len() - 1]] {
+            let color = match &stroke.color {
+                ColorMode::Solid(color) => *color,
+                ColorMode::UV(callback) => callback(uv_bbox, endpoint),
+            };
+            if color == Color32::TRANSPARENT {
+                continue;
+            }
+
+            self.scratchpad_path.clear();
+            self.scratchpad_path.add_circle(endpoint, radius);
+            self.scratchpad_path.fill(self.feathering, color, out);
         }
     }
 
@@ -2186,10 +2331,21 @@ impl Tessellator {
                 "You asked to fill a bezier path that is not closed. That makes no sense."
             );
 
-            self.scratchpad_path.add_open_points(points);
+            let round_joins = self.options.round_line_joins
+                && self.options.round_line_joins_min_width <= stroke.width;
+            if round_joins {
+                self.scratchpad_path
+                    .add_open_points_round_joins(points, self.options.round_line_joins_fan_angle);
+            } else {
+                self.scratchpad_path.add_open_points(points);
+            }
 
             self.scratchpad_path
                 .stroke(self.feathering, PathType::Open, stroke, out);
+
+            if round_joins && self.options.round_line_caps {
+                self.add_round_line_caps(points, stroke, out);
+            }
         }
     }
 }
@@ -2222,8 +2378,32 @@ impl Tessellator {
     ///
     /// ## Returns
     /// A list of clip rectangles with matching [`Mesh`].
+    pub fn tessellate_shapes(&mut self, shapes: Vec<ClippedShape>) -> Vec<ClippedPrimitive> {
+        let mut clipped_primitives: Vec<ClippedPrimitive> = Vec::default();
+        self.tessellate_shapes_with(shapes, &mut |clipped_primitive| {
+            clipped_primitives.push(clipped_primitive);
+        });
+        clipped_primitives
+    }
+
+    /// Like [`Self::tessellate_shapes`], but streams each [`ClippedPrimitive`] to `emit`
+    /// as soon as it is finished, instead of collecting them all in a vector.
+    ///
+    /// This lets you overlap tessellation with other work,
+    /// e.g. uploading the finished meshes to the GPU.
+    ///
+    /// ## Ordering guarantees
+    /// * Primitives are emitted in paint order (back to front),
+    ///   in exactly the same order as [`Self::tessellate_shapes`] returns them.
+    /// * A primitive is emitted as soon as no later shape can be batched into it,
+    ///   which happens when a shape with a different clip rectangle or texture follows it.
+    /// * All remaining primitives are emitted before this function returns.
     #[allow(unused_mut, clippy::allow_attributes)]
-    pub fn tessellate_shapes(&mut self, mut shapes: Vec<ClippedShape>) -> Vec<ClippedPrimitive> {
+    pub fn tessellate_shapes_with(
+        &mut self,
+        mut shapes: Vec<ClippedShape>,
+        emit: &mut dyn FnMut(ClippedPrimitive),
+    ) {
         profiling::function_scope!();
 
         #[cfg(feature = "rayon")]
@@ -2231,40 +2411,70 @@ impl Tessellator {
             self.parallel_tessellation_of_large_shapes(&mut shapes);
         }
 
-        let mut clipped_primitives: Vec<ClippedPrimitive> = Vec::default();
+        let mut pending: Vec<ClippedPrimitive> = Vec::default();
 
         {
             profiling::scope!("tessellate");
             for clipped_shape in shapes {
-                self.tessellate_clipped_shape(clipped_shape, &mut clipped_primitives);
+                self.tessellate_clipped_shape(clipped_shape, &mut pending);
+
+                // New shapes are only ever batched into the last primitive,
+                // so everything before it is finished:
+                while 1 < pending.len() {
+                    let clipped_primitive = pending.remove(0);
+                    self.emit_finished_primitive(clipped_primitive, emit);
+                }
             }
         }
 
-        if self.options.debug_paint_clip_rects {
-            clipped_primitives = self.add_clip_rects(clipped_primitives);
+        for clipped_primitive in pending {
+            self.emit_finished_primitive(clipped_primitive, emit);
         }
+    }
+
+    /// Post-process a finished primitive and pass it on to `emit`.
+    fn emit_finished_primitive(
+        &mut self,
+        mut clipped_primitive: ClippedPrimitive,
+        emit: &mut dyn FnMut(ClippedPrimitive),
+    ) {
+        let clip_rect_mesh = self.options.debug_paint_clip_rects.then(|| {
+            let mut mesh = Mesh::default();
+            self.clip_rect = Rect::EVERYTHING;
+            self.tessellate_shape(
+                Shape::rect_stroke(
+                    clipped_primitive.clip_rect,
+                    0.0,
+                    Stroke::new(2.0, Color32::from_rgb(150, 255, 150)),
+                    StrokeKind::Outside,
+                ),
+                &mut mesh,
+            );
+            mesh
+        });
 
         if self.options.debug_ignore_clip_rects {
-            for clipped_primitive in &mut clipped_primitives {
-                clipped_primitive.clip_rect = Rect::EVERYTHING;
-            }
+            clipped_primitive.clip_rect = Rect::EVERYTHING;
         }
 
-        clipped_primitives.retain(|p| {
-            p.clip_rect.is_positive()
-                && match &p.primitive {
-                    Primitive::Mesh(mesh) => !mesh.is_empty(),
-                    Primitive::Callback(_) => true,
-                }
-        });
-
-        for clipped_primitive in &clipped_primitives {
+        let keep = clipped_primitive.clip_rect.is_positive()
+            && match &clipped_primitive.primitive {
+                Primitive::Mesh(mesh) => !mesh.is_empty(),
+                Primitive::Callback(_) => true,
+            };
+        if keep {
             if let Primitive::Mesh(mesh) = &clipped_primitive.primitive {
                 debug_assert!(mesh.is_valid(), "Tessellator generated invalid Mesh");
             }
+            emit(clipped_primitive);
         }
 
-        clipped_primitives
+        if let Some(clip_rect_mesh) = clip_rect_mesh {
+            emit(ClippedPrimitive {
+                clip_rect: Rect::EVERYTHING, // whatever
+                primitive: Primitive::Mesh(clip_rect_mesh),
+            });
+        }
     }
 
     /// Find large shapes and throw them on the rayon thread pool,
@@ -2315,38 +2525,6 @@ impl Tessellator {
             shapes[index].shape = Shape::Mesh(mesh.into());
         }
     }
-
-    fn add_clip_rects(
-        &mut self,
-        clipped_primitives: Vec<ClippedPrimitive>,
-    ) -> Vec<ClippedPrimitive> {
-        self.clip_rect = Rect::EVERYTHING;
-        let stroke = Stroke::new(2.0, Color32::from_rgb(150, 255, 150));
-
-        clipped_primitives
-            .into_iter()
-            .flat_map(|clipped_primitive| {
-                let mut clip_rect_mesh = Mesh::default();
-                self.tessellate_shape(
-                    Shape::rect_stroke(
-                        clipped_primitive.clip_rect,
-                        0.0,
-                        stroke,
-                        StrokeKind::Outside,
-                    ),
-                    &mut clip_rect_mesh,
-                );
-
-                [
-                    clipped_primitive,
-                    ClippedPrimitive {
-                        clip_rect: Rect::EVERYTHING, // whatever
-                        primitive: Primitive::Mesh(clip_rect_mesh),
-                    },
-                ]
-            })
-            .collect()
-    }
 }
 
 #[test]
@@ -2381,6 +2559,85 @@ fn test_tessellator() {
     assert_eq!(primitives.len(), 2);
 }
 
+#[test]
+fn textured_rect_with_rounded_corners() {
+    use crate::*;
+
+    // A textured rect with rounded corners (e.g. an `Image` with a corner radius)
+    // is tessellated as the rounded outline with per-vertex interpolated UVs,
+    // rather than being masked. Check that every produced vertex - including
+    // the ones along the rounded corners and the feathering fringe -
+    // gets the UV corresponding to its position.
+
+    let rect = Rect::from_min_max(pos2(10.0, 20.0), pos2(74.0, 52.0));
+    let uv = Rect::from_min_max(pos2(0.1, 0.2), pos2(0.9, 0.8));
+
+    let corner_radius = CornerRadius {
+        nw: 0,
+        ne: 4,
+        sw: 8,
+        se: 12,
+    };
+
+    let shape = RectShape::filled(rect, corner_radius, Color32::WHITE)
+        .with_texture(TextureId::Managed(1), uv);
+
+    let mut mesh = Mesh::default();
+    Tessellator::new(1.0, Default::default(), [1024, 1024], vec![])
+        .tessellate_rect(&shape, &mut mesh);
+
+    // The rounded corners produce more outline points than the four rect corners:
+    assert!(8 < mesh.vertices.len());
+    assert!(!mesh.indices.is_empty());
+
+    for vertex in &mesh.vertices {
+        let expected_uv = pos2(
+            emath::remap(vertex.pos.x, rect.x_range(), uv.x_range()),
+            emath::remap(vertex.pos.y, rect.y_range(), uv.y_range()),
+        );
+        assert!(
+            vertex.uv.distance(expected_uv) < 1e-4,
+            "Vertex at {:?} got uv {:?}, expected {expected_uv:?}",
+            vertex.pos,
+            vertex.uv
+        );
+    }
+}
+
+#[test]
+fn round_line_joins_point_count() {
+    // A polyline with one right-angle corner:
+    let points = [pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(10.0, 10.0)];
+
+    let fan_angle = TessellationOptions::default().round_line_joins_fan_angle;
+
+    let mut miter_path = Path::default();
+    miter_path.add_open_points(&points);
+
+    let mut round_path = Path::default();
+    round_path.add_open_points_round_joins(&points, fan_angle);
+
+    // Miter: one point per input point.
+    assert_eq!(miter_path.0.len(), 3);
+
+    // Round: the corner is replaced by a fan
+    // (90° at 0.35 rad per step = 5 steps = 6 fan points).
+    assert_eq!(round_path.0.len(), 8);
+
+    // A smaller fan angle gives a finer fan
+    // (90° at 0.2 rad per step = 8 steps = 9 fan points):
+    let mut fine_path = Path::default();
+    fine_path.add_open_points_round_joins(&points, 0.2);
+    assert_eq!(fine_path.0.len(), 11);
+
+    // A shallow corner is indistinguishable from a miter join, and should cost nothing:
+    let shallow = [pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(20.0, 1.0)];
+
+    let mut round_path = Path::default();
+    round_path.add_open_points_round_joins(&shallow, fan_angle);
+    assert_eq!(round_path.0.len(), 3);
+}
+
 #[test]
 fn path_bounding_box() {
     use crate::*;
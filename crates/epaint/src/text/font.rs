@@ -327,6 +327,11 @@ type FontIndex = usize;
 pub struct Font {
     fonts: Vec<Arc<FontImpl>>,
 
+    /// Colored glyph images (e.g. color emojis), used as a last fallback.
+    ///
+    /// See [`crate::text::FontDefinitions::color_glyphs`].
+    color_glyphs: Arc<BTreeMap<char, crate::text::ColorGlyph>>,
+
     /// Lazily calculated.
     characters: Option<BTreeMap<char, Vec<String>>>,
 
@@ -337,10 +342,14 @@ pub struct Font {
 }
 
 impl Font {
-    pub fn new(fonts: Vec<Arc<FontImpl>>) -> Self {
+    pub fn new(
+        fonts: Vec<Arc<FontImpl>>,
+        color_glyphs: Arc<BTreeMap<char, crate::text::ColorGlyph>>,
+    ) -> Self {
         if fonts.is_empty() {
             return Self {
                 fonts,
+                color_glyphs,
                 characters: None,
                 replacement_glyph: Default::default(),
                 pixels_per_point: 1.0,
@@ -354,6 +363,7 @@ impl Font {
 
         let mut slf = Self {
             fonts,
+            color_glyphs,
             characters: None,
             replacement_glyph: Default::default(),
             pixels_per_point,
@@ -451,7 +461,22 @@ impl Font {
         }
 
         let font_index_glyph_info = self.glyph_info_no_cache_or_fallback(c);
-        let font_index_glyph_info = font_index_glyph_info.unwrap_or(self.replacement_glyph);
+        let font_index_glyph_info = font_index_glyph_info.unwrap_or_else(|| {
+            if let Some(color_glyph) = self.color_glyphs.get(&c) {
+                // No font can render this character, but we have a colored glyph image for it.
+                // The empty `uv_rect` marks it for compositing in `Shape::galley`.
+                let advance_width = self.round_to_pixel(self.row_height * color_glyph.aspect_ratio);
+                (
+                    0,
+                    GlyphInfo {
+                        advance_width,
+                        ..Default::default()
+                    },
+                )
+            } else {
+                self.replacement_glyph
+            }
+        });
         self.glyph_info_cache.insert(c, font_index_glyph_info);
         font_index_glyph_info
     }
@@ -141,6 +141,17 @@ impl FontData {
     pub fn tweak(self, tweak: FontTweak) -> Self {
         Self { tweak, ..self }
     }
+
+    /// Check that this is a valid TTF/OTF file that we will be able to use.
+    ///
+    /// This is useful for validating untrusted font data (e.g. files loaded at runtime)
+    /// before installing it in a [`FontDefinitions`],
+    /// since malformed font data will otherwise lead to a panic when the font is first used.
+    pub fn validate(&self) -> Result<(), String> {
+        ab_glyph::FontRef::try_from_slice_and_index(&self.font, self.index)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
 }
 
 impl AsRef<[u8]> for FontData {
@@ -261,6 +272,40 @@ pub struct FontDefinitions {
     /// the first font and then move to the second, and so on.
     /// So the first font is the primary, and then comes a list of fallbacks in order of priority.
     pub families: BTreeMap<FontFamily, Vec<String>>,
+
+    /// Colored glyph images (e.g. color emojis), keyed by codepoint.
+    ///
+    /// These are used as a last fallback, for codepoints that none of the
+    /// fonts in [`Self::families`] can render.
+    /// See [`ColorGlyph`] for how to set them up.
+    pub color_glyphs: BTreeMap<char, ColorGlyph>,
+}
+
+/// A colored glyph image (e.g. a color emoji), rendered from a texture
+/// instead of from a font.
+///
+/// Register these in [`FontDefinitions::color_glyphs`], keyed by codepoint.
+/// They are only used as a fallback, for codepoints that none of the fonts
+/// can render.
+///
+/// The texture (e.g. a sprite sheet with one sub-rect per emoji) must be
+/// uploaded separately, e.g. with `egui::Context::load_texture`.
+///
+/// When painting, the glyph image is composited into the text as a textured
+/// quad by [`crate::Shape::galley`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ColorGlyph {
+    /// The texture containing the glyph image.
+    pub texture_id: crate::TextureId,
+
+    /// The part of the texture to use, in `0..=1` UV coordinates.
+    pub uv: emath::Rect,
+
+    /// Width of the glyph relative to the font height.
+    ///
+    /// `1.0` (the default for most emojis) means the glyph is square.
+    pub aspect_ratio: f32,
 }
 
 #[derive(Debug, Clone)]
@@ -372,6 +417,7 @@ impl Default for FontDefinitions {
         Self {
             font_data,
             families,
+            color_glyphs: Default::default(),
         }
     }
 }
@@ -386,6 +432,7 @@ impl FontDefinitions {
         Self {
             font_data: Default::default(),
             families,
+            color_glyphs: Default::default(),
         }
     }
 
@@ -460,7 +507,10 @@ impl Fonts {
 
             *fonts_and_cache = FontsAndCache {
                 fonts: FontsImpl::new(pixels_per_point, max_texture_side, definitions),
-                galley_cache: Default::default(),
+                galley_cache: GalleyCache {
+                    capacity: fonts_and_cache.galley_cache.capacity,
+                    ..Default::default()
+                },
             };
         }
 
@@ -559,6 +609,24 @@ impl Fonts {
         self.lock().galley_cache.num_galleys_in_cache()
     }
 
+    /// Limit how many galleys are kept in the layout cache.
+    ///
+    /// If `Some(n)`, the `n` most recently used galleys are kept,
+    /// evicting the least recently used ones beyond that.
+    /// If `None` (the default), galleys that went unused for a frame are evicted.
+    ///
+    /// Setting a capacity bounds the memory used by the cache in apps that
+    /// keep a lot of unique text on screen (e.g. logs or chat),
+    /// which is especially useful on embedded and wasm targets.
+    pub fn set_galley_cache_capacity(&self, capacity: Option<usize>) {
+        self.lock().galley_cache.capacity = capacity;
+    }
+
+    /// The current size of the galley cache.
+    pub fn galley_cache_stats(&self) -> GalleyCacheStats {
+        self.lock().galley_cache.stats()
+    }
+
     /// How full is the font atlas?
     ///
     /// This increases as new fonts and/or glyphs are used,
@@ -634,6 +702,9 @@ pub struct FontsImpl {
     atlas: Arc<Mutex<TextureAtlas>>,
     font_impl_cache: FontImplCache,
     sized_family: ahash::HashMap<(OrderedFloat<f32>, FontFamily), Font>,
+
+    /// Shared with each [`Font`] so they can fall back to colored glyph images.
+    color_glyphs: Arc<BTreeMap<char, ColorGlyph>>,
 }
 
 impl FontsImpl {
@@ -658,6 +729,8 @@ impl FontsImpl {
         let font_impl_cache =
             FontImplCache::new(atlas.clone(), pixels_per_point, &definitions.font_data);
 
+        let color_glyphs = Arc::new(definitions.color_glyphs.clone());
+
         Self {
             pixels_per_point,
             max_texture_side,
@@ -665,6 +738,7 @@ impl FontsImpl {
             atlas,
             font_impl_cache,
             sized_family: Default::default(),
+            color_glyphs,
         }
     }
 
@@ -695,10 +769,17 @@ impl FontsImpl {
                     .map(|font_name| self.font_impl_cache.font_impl(size, font_name))
                     .collect();
 
-                Font::new(fonts)
+                Font::new(fonts, self.color_glyphs.clone())
             })
     }
 
+    /// Colored glyph images (e.g. color emojis), keyed by codepoint.
+    ///
+    /// See [`FontDefinitions::color_glyphs`].
+    pub fn color_glyphs(&self) -> &BTreeMap<char, ColorGlyph> {
+        &self.color_glyphs
+    }
+
     /// Width of this character in points.
     fn glyph_width(&mut self, font_id: &FontId, c: char) -> f32 {
         self.font(font_id).glyph_width(c)
@@ -736,10 +817,25 @@ struct CachedGalley {
     galley: Arc<Galley>,
 }
 
+/// Size of the galley cache, returned by [`Fonts::galley_cache_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GalleyCacheStats {
+    /// Number of cached galleys.
+    pub num_galleys: usize,
+
+    /// Rough estimate of how many bytes of memory the cached galleys use.
+    pub estimated_bytes: usize,
+}
+
 #[derive(Default)]
 struct GalleyCache {
     /// Frame counter used to do garbage collection on the cache
     generation: u32,
+
+    /// If set, keep up to this many galleys, evicting the least recently used ones.
+    /// If `None`, evict all galleys that weren't used this frame.
+    capacity: Option<usize>,
+
     cache: nohash_hasher::IntMap<u64, CachedGalley>,
 }
 
@@ -953,12 +1049,43 @@ impl GalleyCache {
         self.cache.len()
     }
 
+    fn stats(&self) -> GalleyCacheStats {
+        GalleyCacheStats {
+            num_galleys: self.cache.len(),
+            estimated_bytes: self
+                .cache
+                .values()
+                .map(|cached| cached.galley.estimated_memory_usage())
+                .sum(),
+        }
+    }
+
     /// Must be called once per frame to clear the [`Galley`] cache.
     pub fn flush_cache(&mut self) {
         let current_generation = self.generation;
-        self.cache.retain(|_key, cached| {
-            cached.last_used == current_generation // only keep those that were used this frame
-        });
+        if let Some(capacity) = self.capacity {
+            if capacity == 0 {
+                self.cache.clear();
+            } else if capacity < self.cache.len() {
+                // Evict the least recently used galleys until we are within capacity.
+                // Galleys used the same frame are equally recent, so ties can make us
+                // keep slightly more than `capacity` entries.
+                let mut ages: Vec<u32> = self
+                    .cache
+                    .values()
+                    .map(|cached| current_generation.wrapping_sub(cached.last_used))
+                    .collect();
+                ages.sort_unstable();
+                let cutoff_age = ages[capacity - 1];
+                self.cache.retain(|_key, cached| {
+                    current_generation.wrapping_sub(cached.last_used) <= cutoff_age
+                });
+            }
+        } else {
+            self.cache.retain(|_key, cached| {
+                cached.last_used == current_generation // only keep those that were used this frame
+            });
+        }
         self.generation = self.generation.wrapping_add(1);
     }
 }
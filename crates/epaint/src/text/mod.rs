@@ -11,8 +11,8 @@ pub const TAB_SIZE: usize = 4;
 
 pub use {
     fonts::{
-        FontData, FontDefinitions, FontFamily, FontId, FontInsert, FontPriority, FontTweak, Fonts,
-        FontsImpl, InsertFontFamily,
+        ColorGlyph, FontData, FontDefinitions, FontFamily, FontId, FontInsert, FontPriority,
+        FontTweak, Fonts, FontsImpl, GalleyCacheStats, InsertFontFamily,
     },
     text_layout::*,
     text_layout_types::*,
@@ -4,7 +4,10 @@ use emath::{pos2, vec2, Align, GuiRounding as _, NumExt as _, Pos2, Rect, Vec2};
 
 use crate::{stroke::PathStroke, text::font::Font, Color32, Mesh, Stroke, Vertex};
 
-use super::{FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedRow, Row, RowVisuals};
+use super::{
+    FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedColorGlyph, PlacedRow, Row,
+    RowVisuals, TextTruncate,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -82,6 +85,7 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
             num_indices: 0,
             pixels_per_point: fonts.pixels_per_point(),
             elided: true,
+            color_glyphs: Default::default(),
         };
     }
 
@@ -94,9 +98,16 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
 
     let point_scale = PointScale::new(fonts.pixels_per_point());
 
+    let mut elided_start_or_middle = false;
+    if job.wrap.max_rows == 1 && job.wrap.truncate != TextTruncate::End {
+        if let Some(paragraph) = paragraphs.first_mut() {
+            elided_start_or_middle = truncate_paragraph_start_or_middle(fonts, &job, paragraph);
+        }
+    }
+
     let mut elided = false;
     let mut rows = rows_from_paragraphs(paragraphs, &job, &mut elided);
-    if elided {
+    if elided && !elided_start_or_middle {
         if let Some(last_placed) = rows.last_mut() {
             let last_row = Arc::make_mut(&mut last_placed.row);
             replace_last_glyph_with_overflow_character(fonts, &job, last_row);
@@ -105,6 +116,7 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
             }
         }
     }
+    let elided = elided || elided_start_or_middle;
 
     let justify = job.justify && job.wrap.max_width.is_finite();
 
@@ -124,7 +136,42 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
     }
 
     // Calculate the Y positions and tessellate the text:
-    galley_from_rows(point_scale, job, rows, elided)
+    let mut galley = galley_from_rows(point_scale, job, rows, elided);
+    galley.color_glyphs = place_color_glyphs(fonts, &galley);
+    galley
+}
+
+/// Find the glyphs that no font could render, but for which we have a colored glyph image.
+///
+/// These glyphs were given an empty `uv_rect` and the aspect-corrected advance width
+/// in [`Font::glyph_info`], so here we only need to position them.
+fn place_color_glyphs(fonts: &FontsImpl, galley: &Galley) -> Vec<PlacedColorGlyph> {
+    let color_glyphs = fonts.color_glyphs();
+    if color_glyphs.is_empty() {
+        return Default::default();
+    }
+
+    let mut placed = vec![];
+    for placed_row in &galley.rows {
+        for glyph in &placed_row.row.glyphs {
+            if glyph.uv_rect.is_nothing() {
+                if let Some(color_glyph) = color_glyphs.get(&glyph.chr) {
+                    if 0.0 < color_glyph.aspect_ratio {
+                        let height = glyph.advance_width / color_glyph.aspect_ratio;
+                        let baseline = placed_row.pos + glyph.pos.to_vec2();
+                        placed.push(PlacedColorGlyph {
+                            rect: Rect::from_min_size(
+                                pos2(baseline.x, baseline.y - height),
+                                vec2(glyph.advance_width, height),
+                            ),
+                            color_glyph: *color_glyph,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    placed
 }
 
 // Ignores the Y coordinate.
@@ -358,6 +405,141 @@ fn line_break(
     }
 }
 
+/// Elides a too-wide paragraph by removing glyphs from the start or middle
+/// and inserting [`TextWrapping::overflow_character`] in their place.
+///
+/// Used for [`TextTruncate::Start`] and [`TextTruncate::Middle`]
+/// when [`TextWrapping::max_rows`] is `1`.
+/// The trimming is based on the actual glyph advance widths, not character counts.
+///
+/// Returns `true` if the paragraph was elided.
+///
+/// Called before line-wrapping, and before we have any Y coordinates.
+///
+/// [`TextWrapping::overflow_character`]: super::TextWrapping::overflow_character
+/// [`TextWrapping::max_rows`]: super::TextWrapping::max_rows
+fn truncate_paragraph_start_or_middle(
+    fonts: &mut FontsImpl,
+    job: &LayoutJob,
+    paragraph: &mut Paragraph,
+) -> bool {
+    let max_width = job.wrap.max_width;
+    if !max_width.is_finite() {
+        return false;
+    }
+    let Some(last_glyph) = paragraph.glyphs.last() else {
+        return false;
+    };
+    if last_glyph.max_x() <= max_width {
+        return false; // Everything fits.
+    }
+
+    let glyphs = std::mem::take(&mut paragraph.glyphs);
+    let total_width = glyphs.last().map_or(0.0, |glyph| glyph.max_x());
+
+    // Measure the overflow character using the format of the first glyph.
+    // Not always correct for multi-section jobs, but the common case by far:
+    let section_index = glyphs[0].section_index;
+    let section = &job.sections[section_index as usize];
+    let font = fonts.font(&section.format.font_id);
+    let line_height = section
+        .format
+        .line_height
+        .unwrap_or_else(|| font.row_height());
+    let font_height = font.row_height();
+    let font_ascent = font.ascent();
+
+    let (overflow_width, overflow_glyph) = if let Some(chr) = job.wrap.overflow_character {
+        let (font_impl, glyph_info) = font.font_impl_and_glyph_info(chr);
+        let glyph = Glyph {
+            chr,
+            pos: pos2(f32::NAN, f32::NAN),
+            advance_width: glyph_info.advance_width,
+            line_height,
+            font_impl_height: font_impl.map_or(0.0, |f| f.row_height()),
+            font_impl_ascent: font_impl.map_or(0.0, |f| f.ascent()),
+            font_height,
+            font_ascent,
+            uv_rect: glyph_info.uv_rect,
+            section_index,
+        };
+        (
+            section.format.extra_letter_spacing + glyph_info.advance_width,
+            Some(glyph),
+        )
+    } else {
+        (0.0, None)
+    };
+
+    // How much glyph width we can keep:
+    let budget = (max_width - overflow_width).at_least(0.0);
+
+    // Number of glyphs to keep at the start:
+    let num_start = match job.wrap.truncate {
+        TextTruncate::Start => 0,
+        TextTruncate::Middle => {
+            // Leave at least half the budget for the trailing text:
+            let half_budget = 0.5 * budget;
+            glyphs
+                .iter()
+                .take_while(|glyph| glyph.max_x() <= half_budget)
+                .count()
+        }
+        TextTruncate::End => {
+            // Handled by `replace_last_glyph_with_overflow_character`.
+            paragraph.glyphs = glyphs;
+            return false;
+        }
+    };
+    let start_width = if num_start == 0 {
+        0.0
+    } else {
+        glyphs[num_start - 1].max_x()
+    };
+
+    // Greedily keep glyphs from the end, as long as they fit:
+    let mut num_end = 0;
+    while num_start + num_end < glyphs.len() {
+        let glyph = &glyphs[glyphs.len() - 1 - num_end];
+        if total_width - glyph.pos.x <= budget - start_width {
+            num_end += 1;
+        } else {
+            break;
+        }
+    }
+
+    let mut new_glyphs = Vec::with_capacity(num_start + 1 + num_end);
+    new_glyphs.extend_from_slice(&glyphs[..num_start]);
+
+    let mut x = if num_start == 0 {
+        glyphs[0].pos.x // Keep any leading space intact.
+    } else {
+        start_width
+    };
+
+    if let Some(mut glyph) = overflow_glyph {
+        x += section.format.extra_letter_spacing;
+        glyph.pos.x = x;
+        x += glyph.advance_width;
+        new_glyphs.push(glyph);
+    }
+
+    let trailing = &glyphs[glyphs.len() - num_end..];
+    if let Some(first) = trailing.first() {
+        let shift = x - first.pos.x;
+        new_glyphs.extend(trailing.iter().map(|glyph| {
+            let mut glyph = *glyph;
+            glyph.pos.x += shift;
+            glyph
+        }));
+    }
+
+    paragraph.cursor_x = new_glyphs.last().map_or(0.0, |glyph| glyph.max_x());
+    paragraph.glyphs = new_glyphs;
+
+    true
+}
+
 /// Trims the last glyphs in the row and replaces it with an overflow character (e.g. `…`).
 ///
 /// Called before we have any Y coordinates.
@@ -680,6 +862,7 @@ fn galley_from_rows(
         num_vertices,
         num_indices,
         pixels_per_point: point_scale.pixels_per_point,
+        color_glyphs: Default::default(),
     };
 
     if galley.job.round_output_to_gui {
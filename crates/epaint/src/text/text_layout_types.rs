@@ -389,6 +389,25 @@ pub enum TextWrapMode {
     Truncate,
 }
 
+/// Where to remove text when eliding it with [`TextWrapping`].
+///
+/// Only used when [`TextWrapping::max_rows`] is `1`.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TextTruncate {
+    /// Remove text from the start: "…ers/some/file.txt".
+    Start,
+
+    /// Remove text from the middle: "C:/Us…/file.txt".
+    ///
+    /// Useful for file paths, where the start and end are usually the most interesting parts.
+    Middle,
+
+    /// Remove text from the end: "C:/Users/some/fi…".
+    #[default]
+    End,
+}
+
 /// Controls the text wrapping and elision of a [`LayoutJob`].
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -434,6 +453,15 @@ pub struct TextWrapping {
     ///
     /// If not set, no character will be used (but the text will still be elided).
     pub overflow_character: Option<char>,
+
+    /// Where to remove text when eliding a single row.
+    ///
+    /// Only used when [`Self::max_rows`] is `1`.
+    /// When eliding because of [`Self::max_rows`] with more rows allowed,
+    /// text is always removed from the end.
+    ///
+    /// Default: [`TextTruncate::End`].
+    pub truncate: TextTruncate,
 }
 
 impl std::hash::Hash for TextWrapping {
@@ -444,11 +472,13 @@ impl std::hash::Hash for TextWrapping {
             max_rows,
             break_anywhere,
             overflow_character,
+            truncate,
         } = self;
         emath::OrderedFloat(*max_width).hash(state);
         max_rows.hash(state);
         break_anywhere.hash(state);
         overflow_character.hash(state);
+        truncate.hash(state);
     }
 }
 
@@ -459,6 +489,7 @@ impl Default for TextWrapping {
             max_rows: usize::MAX,
             break_anywhere: false,
             overflow_character: Some('…'),
+            truncate: TextTruncate::End,
         }
     }
 }
@@ -535,6 +566,14 @@ pub struct Galley {
     /// Set to true the text was truncated due to [`TextWrapping::max_rows`].
     pub elided: bool,
 
+    /// Colored glyph images for characters that no font could render.
+    ///
+    /// These are composited on top of the text as textured quads by [`crate::Shape::galley`].
+    /// A raw [`crate::TextShape`] ignores them.
+    ///
+    /// See [`crate::text::FontDefinitions::color_glyphs`].
+    pub color_glyphs: Vec<PlacedColorGlyph>,
+
     /// Bounding rect.
     ///
     /// `rect.top()` is always 0.0.
@@ -562,6 +601,17 @@ pub struct Galley {
     pub pixels_per_point: f32,
 }
 
+/// A colored glyph image positioned within a [`Galley`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PlacedColorGlyph {
+    /// Where to paint the image, relative to the galley.
+    pub rect: Rect,
+
+    /// What to paint there.
+    pub color_glyph: crate::text::ColorGlyph,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct PlacedRow {
@@ -795,6 +845,21 @@ impl Galley {
         self.rect.size()
     }
 
+    /// Approximately how many bytes of memory this galley uses.
+    ///
+    /// This is a rough estimate that ignores allocator overhead,
+    /// useful for e.g. monitoring the size of the galley cache.
+    pub fn estimated_memory_usage(&self) -> usize {
+        let num_glyphs: usize = self.rows.iter().map(|row| row.glyphs.len()).sum();
+        std::mem::size_of::<Self>()
+            + self.job.text.len()
+            + self.job.sections.len() * std::mem::size_of::<LayoutSection>()
+            + self.rows.len() * (std::mem::size_of::<PlacedRow>() + std::mem::size_of::<Row>())
+            + num_glyphs * std::mem::size_of::<Glyph>()
+            + self.num_vertices * std::mem::size_of::<crate::Vertex>()
+            + self.num_indices * std::mem::size_of::<u32>()
+    }
+
     pub(crate) fn round_output_to_gui(&mut self) {
         for placed_row in &mut self.rows {
             // Optimization: only call `make_mut` if necessary (can cause a deep clone)
@@ -831,6 +896,7 @@ impl Galley {
             job,
             rows: Vec::new(),
             elided: false,
+            color_glyphs: Vec::new(),
             rect: Rect::ZERO,
             mesh_bounds: Rect::NOTHING,
             num_vertices: 0,
@@ -841,6 +907,13 @@ impl Galley {
         for (i, galley) in galleys.iter().enumerate() {
             let current_y_offset = merged_galley.rect.height();
 
+            merged_galley
+                .color_glyphs
+                .extend(galley.color_glyphs.iter().map(|placed| PlacedColorGlyph {
+                    rect: placed.rect.translate(current_y_offset * Vec2::Y),
+                    color_glyph: placed.color_glyph,
+                }));
+
             let mut rows = galley.rows.iter();
             // As documented in `Row::ends_with_newline`, a '\n' will always create a
             // new `Row` immediately below the current one. Here it doesn't make sense
@@ -116,6 +116,15 @@ impl TextureManager {
     pub fn num_allocated(&self) -> usize {
         self.metas.len()
     }
+
+    /// Estimated total number of bytes used by all allocated textures.
+    ///
+    /// Useful for spotting texture leaks,
+    /// e.g. when forgetting to drop a [`crate::TextureHandle`].
+    /// See also [`TextureMeta::bytes_used`] for per-texture estimates.
+    pub fn bytes_used(&self) -> usize {
+        self.metas.values().map(TextureMeta::bytes_used).sum()
+    }
 }
 
 /// Meta-data about an allocated texture.